@@ -0,0 +1,144 @@
+use crate::output::OutputFormat;
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand};
+use ratefy_lib::money::{CurrencyAlpha3, PriceRule};
+use rust_decimal::Decimal;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Financial, economic and exchange rate calculations.
+///
+/// Without a subcommand the interactive menu is shown.
+#[derive(Debug, Parser)]
+#[command(name = "ratefy", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Output format of the subcommands
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Plain)]
+    pub format: OutputFormat,
+}
+
+/// Non-interactive commands, printing their result to stdout.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Apply a percentage rate to an amount (e.g. `apply 200 15 EUR` → 230.00 EUR)
+    #[command(allow_negative_numbers = true)]
+    Apply {
+        amount: Decimal,
+        /// Percentage, negative for a discount
+        rate: Decimal,
+        #[arg(value_parser = parse_currency)]
+        currency: CurrencyAlpha3,
+        /// Price rule applied to the result (.99, .95 down, 5, cash)
+        #[arg(long, value_parser = parse_price_rule)]
+        rule: Option<PriceRule>,
+    },
+    /// Recover the amount a percentage rate was applied to (e.g. `revert 230 15 EUR` → 200.00 EUR)
+    #[command(allow_negative_numbers = true)]
+    Revert {
+        amount: Decimal,
+        /// Percentage that was applied
+        rate: Decimal,
+        #[arg(value_parser = parse_currency)]
+        currency: CurrencyAlpha3,
+    },
+    /// Convert an amount with the rates file (RATEFY_RATES or rates.csv)
+    #[command(allow_negative_numbers = true)]
+    Convert {
+        amount: Decimal,
+        #[arg(value_parser = parse_currency)]
+        from: CurrencyAlpha3,
+        #[arg(value_parser = parse_currency)]
+        to: CurrencyAlpha3,
+        /// Use the latest rate on or before this date (YYYY-MM-DD) instead of the latest rate
+        #[arg(long, value_parser = parse_date)]
+        date: Option<NaiveDate>,
+        /// CSV file of dated rates (date,pair,rate)
+        #[arg(long)]
+        rates: Option<PathBuf>,
+    },
+    /// List the currencies of a group (G3, G10, G20, EM, LATAM, Nordics, APAC, Fiat, All or custom)
+    Currencies {
+        #[arg(long, default_value = "All")]
+        group: String,
+    },
+}
+
+fn parse_currency(value: &str) -> Result<CurrencyAlpha3, String> {
+    CurrencyAlpha3::from_str(value).map_err(|_| format!("unknown currency '{}'", value))
+}
+
+fn parse_price_rule(value: &str) -> Result<PriceRule, String> {
+    PriceRule::from_str(value).map_err(|_| format!("unknown price rule '{}'", value))
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("invalid date '{}', expected YYYY-MM-DD", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_subcommand_runs_the_menu() {
+        let cli = Cli::try_parse_from(["ratefy"]).unwrap();
+        assert!(cli.command.is_none());
+    }
+
+    #[test]
+    fn test_negative_rates_are_not_flags() {
+        let cli = Cli::try_parse_from(["ratefy", "apply", "200", "-15", "eur"]).unwrap();
+        match cli.command {
+            Some(Command::Apply {
+                amount,
+                rate,
+                currency,
+                rule,
+            }) => {
+                assert_eq!((amount, rate), (Decimal::from(200), Decimal::from(-15)));
+                assert_eq!(currency.code(), "EUR");
+                assert!(rule.is_none());
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_format_before_or_after_the_subcommand() {
+        for args in [
+            ["ratefy", "--format", "json", "currencies"],
+            ["ratefy", "currencies", "--format", "json"],
+        ] {
+            assert_eq!(
+                Cli::try_parse_from(args).unwrap().format,
+                OutputFormat::Json
+            );
+        }
+        let cli = Cli::try_parse_from(["ratefy", "currencies"]).unwrap();
+        assert_eq!(cli.format, OutputFormat::Plain);
+        assert!(Cli::try_parse_from(["ratefy", "currencies", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_arguments_are_rejected() {
+        for args in [
+            vec!["ratefy", "apply", "200", "15", "XXZ"],
+            vec!["ratefy", "apply", "abc", "15", "EUR"],
+            vec!["ratefy", "apply", "200", "15", "EUR", "--rule", "up"],
+            vec![
+                "ratefy",
+                "convert",
+                "1",
+                "USD",
+                "EUR",
+                "--date",
+                "03/01/2024",
+            ],
+        ] {
+            assert!(Cli::try_parse_from(&args).is_err(), "{:?} parsed", args);
+        }
+    }
+}
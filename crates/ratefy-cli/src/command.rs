@@ -0,0 +1,309 @@
+use crate::cli::Command;
+use crate::config;
+use crate::output::{Output, OutputFormat, render};
+use ratefy_lib::fx::{FxError, RateStore};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use std::fmt;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+/// Exit status when the arguments are valid but cannot be used (e.g. unknown group).
+pub const EXIT_INVALID_INPUT: u8 = 2;
+
+/// Exit status when required data is missing (no rates file, no rate for the pair).
+pub const EXIT_MISSING_DATA: u8 = 3;
+
+/// Failure of a non-interactive command.
+#[derive(Debug)]
+pub enum CommandError {
+    /// The command cannot run (e.g. an unreadable configuration file or a malformed rates file).
+    Failed(String),
+    InvalidInput(String),
+    MissingData(String),
+}
+
+impl CommandError {
+    /// Returns the process exit status for the error.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            CommandError::Failed(_) => ExitCode::FAILURE,
+            CommandError::InvalidInput(_) => ExitCode::from(EXIT_INVALID_INPUT),
+            CommandError::MissingData(_) => ExitCode::from(EXIT_MISSING_DATA),
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Failed(message)
+            | CommandError::InvalidInput(message)
+            | CommandError::MissingData(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// Runs a command, printing its result to stdout in `format` or the error to stderr.
+pub fn run(command: Command, format: OutputFormat) -> ExitCode {
+    match execute(command) {
+        Ok(output) => {
+            // A closed pipe (e.g. `| head`) is not an error of the command.
+            let _ = io::stdout()
+                .lock()
+                .write_all(render(&output, format).as_bytes());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("ratefy: {}", error);
+            error.exit_code()
+        }
+    }
+}
+
+/// Returns the result of a command.
+fn execute(command: Command) -> Result<Output, CommandError> {
+    match command {
+        Command::Apply {
+            amount,
+            rate,
+            currency,
+            rule,
+        } => {
+            let out_of_range =
+                || CommandError::InvalidInput(format!("{} at {}% is out of range", amount, rate));
+            let money = Money::new(amount, currency)
+                .checked_apply_rate(rate)
+                .ok_or_else(out_of_range)?;
+            let rounded = match rule {
+                Some(rule) => rule.checked_apply(&money).ok_or_else(out_of_range)?,
+                None => money.rounded(),
+            };
+            Ok(Output::Money {
+                money,
+                rounded,
+                fx: None,
+            })
+        }
+        Command::Revert {
+            amount,
+            rate,
+            currency,
+        } => {
+            let money = revert(amount, rate, currency)?;
+            Ok(Output::Money {
+                rounded: money.rounded(),
+                money,
+                fx: None,
+            })
+        }
+        Command::Convert {
+            amount,
+            from,
+            to,
+            date,
+            rates,
+        } => {
+            let path = rates.or_else(config::rates_path).ok_or_else(|| {
+                CommandError::MissingData(format!(
+                    "no exchange rates file; set {} or create rates.csv",
+                    config::RATES_ENV
+                ))
+            })?;
+            // A file that exists but does not parse is an error, not missing data.
+            let store = RateStore::from_path(&path).map_err(|e| {
+                let message = format!("{}: {}", path.display(), e);
+                match e {
+                    FxError::Io(_) => CommandError::MissingData(message),
+                    _ => CommandError::Failed(message),
+                }
+            })?;
+            let money = Money::new(amount, from).with_source(&path.display().to_string());
+            // No rate is needed (or reported) when the amount is already in `to`.
+            let (converted, fx) = if money.currency() == &to {
+                (money, None)
+            } else {
+                let rate = store
+                    .rate(money.currency(), &to, date)
+                    .map_err(|e| CommandError::MissingData(e.to_string()))?;
+                let converted = rate
+                    .convert(&money)
+                    .map_err(|e| CommandError::InvalidInput(e.to_string()))?;
+                (converted, Some(rate))
+            };
+            Ok(Output::Money {
+                rounded: converted.rounded(),
+                money: converted,
+                fx,
+            })
+        }
+        Command::Currencies { group } => {
+            let groups =
+                config::currency_groups().map_err(|e| CommandError::Failed(e.to_string()))?;
+            let Some(found) = groups
+                .iter()
+                .find(|g| g.name().eq_ignore_ascii_case(&group))
+            else {
+                let names: Vec<&str> = groups.iter().map(|g| g.name()).collect();
+                return Err(CommandError::InvalidInput(format!(
+                    "unknown currency group '{}' (available: {})",
+                    group,
+                    names.join(", ")
+                )));
+            };
+            Ok(Output::Currencies(found.currencies().cloned().collect()))
+        }
+    }
+}
+
+/// The amount `rate` percent was applied to in order to obtain `amount`.
+fn revert(amount: Decimal, rate: Decimal, currency: CurrencyAlpha3) -> Result<Money, CommandError> {
+    let money = Money::new(amount, currency);
+    if rate.is_zero() {
+        return Ok(money);
+    }
+    money.with_rate(rate).revert_rate().ok_or_else(|| {
+        CommandError::InvalidInput(format!("a rate of {}% cannot be reverted", rate))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    fn currency(code: &str) -> CurrencyAlpha3 {
+        CurrencyAlpha3::from_str(code).unwrap()
+    }
+
+    /// Writes `contents` to a rates file unique to the test.
+    fn rates_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("ratefy-{}-{}.csv", name, std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn convert(amount: Decimal, to: &str, rates: PathBuf) -> Command {
+        Command::Convert {
+            amount,
+            from: currency("EUR"),
+            to: currency(to),
+            date: None,
+            rates: Some(rates),
+        }
+    }
+
+    fn exit_code(result: Result<Output, CommandError>) -> ExitCode {
+        result
+            .map(|_| ExitCode::SUCCESS)
+            .unwrap_or_else(|e| e.exit_code())
+    }
+
+    #[test]
+    fn test_convert_reports_the_exchange_rate() {
+        let path = rates_file("convert", "date,pair,rate\n2024-03-01,EUR/USD,1.08\n");
+        let result = execute(convert(Decimal::from(100), "USD", path.clone()));
+        let same = execute(convert(Decimal::from(100), "EUR", path.clone()));
+        std::fs::remove_file(&path).unwrap();
+
+        let Ok(Output::Money { rounded, fx, .. }) = result else {
+            panic!("unexpected {:?}", result);
+        };
+        assert_eq!(rounded.amount(), Decimal::from(108));
+        assert_eq!(rounded.currency().code(), "USD");
+        assert_eq!(rounded.source(), Some(path.display().to_string().as_str()));
+        let fx = fx.unwrap();
+        assert_eq!(fx.pair().to_string(), "EUR/USD");
+        assert_eq!(fx.rate(), Decimal::new(108, 2));
+
+        let Ok(Output::Money { rounded, fx, .. }) = same else {
+            panic!("unexpected {:?}", same);
+        };
+        assert_eq!(rounded.amount(), Decimal::from(100));
+        assert!(fx.is_none());
+    }
+
+    #[test]
+    fn test_revert() {
+        let command = Command::Revert {
+            amount: Decimal::new(23000, 2),
+            rate: Decimal::from(15),
+            currency: currency("EUR"),
+        };
+        let output = execute(command).unwrap();
+        assert_eq!(render(&output, OutputFormat::Plain), "200.00 EUR\n");
+        let Output::Money { money, fx, .. } = output else {
+            panic!("unexpected {:?}", output);
+        };
+        assert_eq!(money.amount(), Decimal::from(200));
+        assert!(money.rate().is_none());
+        assert!(fx.is_none());
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let code = |error: CommandError| error.exit_code();
+        assert_eq!(code(CommandError::Failed(String::new())), ExitCode::FAILURE);
+        assert_eq!(
+            code(CommandError::InvalidInput(String::new())),
+            ExitCode::from(EXIT_INVALID_INPUT)
+        );
+        assert_eq!(
+            code(CommandError::MissingData(String::new())),
+            ExitCode::from(EXIT_MISSING_DATA)
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_is_invalid_input() {
+        let apply = Command::Apply {
+            amount: Decimal::MAX,
+            rate: Decimal::from(50),
+            currency: currency("EUR"),
+            rule: None,
+        };
+        assert_eq!(
+            exit_code(execute(apply)),
+            ExitCode::from(EXIT_INVALID_INPUT)
+        );
+
+        let revert = Command::Revert {
+            amount: Decimal::from(100),
+            rate: Decimal::from(-100),
+            currency: currency("EUR"),
+        };
+        assert_eq!(
+            exit_code(execute(revert)),
+            ExitCode::from(EXIT_INVALID_INPUT)
+        );
+
+        let path = rates_file("overflow", "date,pair,rate\n2024-03-01,EUR/USD,1000\n");
+        let result = execute(convert(Decimal::MAX, "USD", path.clone()));
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(exit_code(result), ExitCode::from(EXIT_INVALID_INPUT));
+    }
+
+    #[test]
+    fn test_missing_rates_are_missing_data() {
+        let missing = PathBuf::from("does/not/exist.csv");
+        assert_eq!(
+            exit_code(execute(convert(Decimal::ONE, "USD", missing))),
+            ExitCode::from(EXIT_MISSING_DATA)
+        );
+
+        let path = rates_file("pair", "date,pair,rate\n2024-03-01,EUR/USD,1.08\n");
+        let result = execute(convert(Decimal::ONE, "JPY", path.clone()));
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(exit_code(result), ExitCode::from(EXIT_MISSING_DATA));
+    }
+
+    #[test]
+    fn test_malformed_rates_file_fails() {
+        let path = rates_file("malformed", "date,pair,rate\n2024-03-01,EUR/USD,abc\n");
+        let result = execute(convert(Decimal::ONE, "USD", path.clone()));
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(result, Err(CommandError::Failed(ref m)) if m.contains("line 2")));
+        assert_eq!(exit_code(result), ExitCode::FAILURE);
+    }
+}
@@ -0,0 +1,75 @@
+use ratefy_lib::money::{
+    AssetError, CurrencyGroup, CurrencyGroupError, register_assets_from_path, registered_assets,
+};
+use std::env;
+use std::path::PathBuf;
+
+/// Environment variable pointing to a TOML file with non-ISO assets.
+pub const ASSETS_ENV: &str = "RATEFY_ASSETS";
+
+/// File looked up in the working directory when `RATEFY_ASSETS` is not set.
+const DEFAULT_ASSETS_FILE: &str = "assets.toml";
+
+/// Environment variable pointing to a TOML file with user-defined currency groups.
+pub const CURRENCY_GROUPS_ENV: &str = "RATEFY_CURRENCY_GROUPS";
+
+/// File looked up in the working directory when the environment variable is not set.
+const DEFAULT_CURRENCY_GROUPS_FILE: &str = "currency_groups.toml";
+
+/// Returns the file named by `env`, or `default` if it exists in the working directory.
+fn config_path(env: &str, default: &str) -> Option<PathBuf> {
+    match env::var_os(env) {
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(PathBuf::from(default)).filter(|p| p.exists()),
+    }
+}
+
+/// Registers the user-defined non-ISO assets, on top of the built-in BTC, ETH and USDT.
+///
+/// A file named by `RATEFY_ASSETS` must exist; the default `assets.toml` is optional.
+/// Must run before [`currency_groups`] so that custom groups can refer to these assets.
+pub fn register_assets() -> Result<(), AssetError> {
+    if let Some(path) = config_path(ASSETS_ENV, DEFAULT_ASSETS_FILE) {
+        register_assets_from_path(path)?;
+    }
+    Ok(())
+}
+
+/// Returns the built-in currency groups, an "Assets" group with every registered
+/// non-ISO asset, then the user-defined groups.
+///
+/// A file named by `RATEFY_CURRENCY_GROUPS` must exist; the default
+/// `currency_groups.toml` is optional.
+pub fn currency_groups() -> Result<Vec<CurrencyGroup>, CurrencyGroupError> {
+    let mut groups = CurrencyGroup::builtin();
+    let assets: Vec<&str> = registered_assets().iter().map(|a| a.code()).collect();
+    if !assets.is_empty() {
+        groups.push(CurrencyGroup::custom("Assets", &assets)?);
+    }
+    if let Some(path) = config_path(CURRENCY_GROUPS_ENV, DEFAULT_CURRENCY_GROUPS_FILE) {
+        groups.extend(CurrencyGroup::load_custom(path)?);
+    }
+    Ok(groups)
+}
+
+/// Environment variable pointing to a CSV file of dated exchange rates.
+pub const RATES_ENV: &str = "RATEFY_RATES";
+
+/// File looked up in the working directory when `RATEFY_RATES` is not set.
+const DEFAULT_RATES_FILE: &str = "rates.csv";
+
+/// Returns the exchange rates file named by `RATEFY_RATES`, or `rates.csv` if present.
+pub fn rates_path() -> Option<PathBuf> {
+    config_path(RATES_ENV, DEFAULT_RATES_FILE)
+}
+
+/// Environment variable pointing to a TOML file of bracket schedules.
+pub const BRACKETS_ENV: &str = "RATEFY_BRACKETS";
+
+/// File looked up in the working directory when `RATEFY_BRACKETS` is not set.
+const DEFAULT_BRACKETS_FILE: &str = "brackets.toml";
+
+/// Returns the bracket schedules file named by `RATEFY_BRACKETS`, or `brackets.toml` if present.
+pub fn brackets_path() -> Option<PathBuf> {
+    config_path(BRACKETS_ENV, DEFAULT_BRACKETS_FILE)
+}
@@ -1,14 +1,28 @@
+use clap::Parser;
 use crossterm::{
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io;
+use std::process::ExitCode;
 
 use crate::view::apply_percentage::apply_percentage_view;
+use crate::view::brackets::brackets_view;
+use crate::view::cash_flows::cash_flows_view;
+use crate::view::depreciation::depreciation_view;
+use crate::view::forward::forward_view;
+use crate::view::inflation::inflation_view;
+use crate::view::position::position_view;
+use crate::view::remittance::remittance_view;
+use cli::Cli;
 use ratefy_menu::{MenuItem, run_menu};
 use types::layout::{HorizontalAlign, VerticalAlign};
 
+mod cli;
+mod command;
+mod config;
+mod output;
 mod types;
 mod view;
 
@@ -16,10 +30,40 @@ mod view;
 enum Screen {
     MainMenu,
     CalculatePercentage,
+    CashFlows,
+    Inflation,
+    Remittance,
+    Forward,
+    Position,
+    Brackets,
+    Depreciation,
     Exit,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    // Registered assets must be known before currency arguments are parsed.
+    if let Err(error) = config::register_assets() {
+        eprintln!("ratefy: {}", error);
+        return ExitCode::FAILURE;
+    }
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Some(command) => return command::run(command, cli.format),
+        None => run_menu_loop(),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("ratefy: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the interactive menu in the alternate screen until Exit is chosen.
+fn run_menu_loop() -> Result<(), Box<dyn std::error::Error>> {
+    let currency_groups = config::currency_groups()?;
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -38,7 +82,49 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Some("→ base * rate / 100"),
                         Screen::CalculatePercentage,
                     ),
-                    MenuItem::new("Exit", 2, None, Screen::Exit),
+                    MenuItem::new(
+                        "Cash Flow Analysis",
+                        2,
+                        Some("→ NPV, IRR and XIRR from CSV"),
+                        Screen::CashFlows,
+                    ),
+                    MenuItem::new(
+                        "Inflation Adjustment",
+                        3,
+                        Some("→ value in another date's purchasing power"),
+                        Screen::Inflation,
+                    ),
+                    MenuItem::new(
+                        "Remittance Comparison",
+                        4,
+                        Some("→ rank providers by amount received"),
+                        Screen::Remittance,
+                    ),
+                    MenuItem::new(
+                        "FX Forward",
+                        5,
+                        Some("→ outright and points from interest rates"),
+                        Screen::Forward,
+                    ),
+                    MenuItem::new(
+                        "Position Sizing",
+                        6,
+                        Some("→ pip value, lot size and P&L"),
+                        Screen::Position,
+                    ),
+                    MenuItem::new(
+                        "Progressive Brackets",
+                        7,
+                        Some("→ marginal rates over thresholds"),
+                        Screen::Brackets,
+                    ),
+                    MenuItem::new(
+                        "Depreciation Schedule",
+                        8,
+                        Some("→ SL, DB, DDB, SYD and units of production"),
+                        Screen::Depreciation,
+                    ),
+                    MenuItem::new("Exit", 9, None, Screen::Exit),
                 ];
                 current_screen = run_menu(&mut terminal, "Ratefy Menu", &items)?;
             }
@@ -48,6 +134,71 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     HorizontalAlign::Left,
                     VerticalAlign::Full,
                     true,
+                    &currency_groups,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::CashFlows => {
+                cash_flows_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::Inflation => {
+                inflation_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::Remittance => {
+                remittance_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::Forward => {
+                forward_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::Position => {
+                position_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
+                    &currency_groups,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::Brackets => {
+                brackets_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
+                )?;
+                current_screen = Screen::MainMenu;
+            }
+            Screen::Depreciation => {
+                depreciation_view(
+                    &mut terminal,
+                    HorizontalAlign::Left,
+                    VerticalAlign::Full,
+                    true,
                 )?;
                 current_screen = Screen::MainMenu;
             }
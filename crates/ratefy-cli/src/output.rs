@@ -0,0 +1,343 @@
+use clap::ValueEnum;
+use ratefy_lib::fx::ExchangeRate;
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use serde::Serialize;
+
+/// How non-interactive commands print their result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// The rounded amount and currency, or one currency per line
+    #[default]
+    Plain,
+    /// Aligned columns with a header
+    Table,
+    /// One JSON value on a single line
+    Json,
+    /// A header row followed by the records
+    Csv,
+}
+
+/// Result of a non-interactive command.
+// A command produces a single output, so its size does not matter.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    /// A calculated amount, before and after rounding (or a price rule), and
+    /// the exchange rate used by a conversion.
+    Money {
+        money: Money,
+        rounded: Money,
+        fx: Option<ExchangeRate>,
+    },
+    Currencies(Vec<CurrencyAlpha3>),
+}
+
+/// A `Money` as emitted by the `json`, `csv` and `table` formats.
+///
+/// Amounts and rates are decimal strings so that no precision is lost.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct MoneyRecord {
+    currency: String,
+    amount: String,
+    rounded: String,
+    rate: Option<String>,
+    source: Option<String>,
+    timestamp: Option<String>,
+    tags: Vec<String>,
+    fx_rate: Option<String>,
+    fx_pair: Option<String>,
+}
+
+const MONEY_COLUMNS: [&str; 9] = [
+    "currency",
+    "amount",
+    "rounded",
+    "rate",
+    "source",
+    "timestamp",
+    "tags",
+    "fx_rate",
+    "fx_pair",
+];
+
+impl MoneyRecord {
+    fn new(money: &Money, rounded: &Money, fx: Option<&ExchangeRate>) -> Self {
+        Self {
+            currency: money.currency().code().to_string(),
+            amount: money.amount().to_string(),
+            rounded: with_minor_units(rounded),
+            rate: money.rate().map(|r| r.to_string()),
+            source: money.source().map(str::to_string),
+            timestamp: money.timestamp().map(|d| d.format("%Y-%m-%d").to_string()),
+            tags: money.tags().to_vec(),
+            fx_rate: fx.map(|r| r.rate().to_string()),
+            fx_pair: fx.map(|r| r.pair().to_string()),
+        }
+    }
+
+    /// Fields in `MONEY_COLUMNS` order; missing values are empty, tags joined by `;`.
+    fn fields(&self) -> [String; 9] {
+        [
+            self.currency.clone(),
+            self.amount.clone(),
+            self.rounded.clone(),
+            self.rate.clone().unwrap_or_default(),
+            self.source.clone().unwrap_or_default(),
+            self.timestamp.clone().unwrap_or_default(),
+            self.tags.join(";"),
+            self.fx_rate.clone().unwrap_or_default(),
+            self.fx_pair.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+/// A currency as emitted by the `json`, `csv` and `table` formats.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct CurrencyRecord {
+    code: String,
+    name: String,
+    kind: String,
+    minor_units: Option<u16>,
+}
+
+const CURRENCY_COLUMNS: [&str; 4] = ["code", "name", "kind", "minor_units"];
+
+impl CurrencyRecord {
+    fn new(currency: &CurrencyAlpha3) -> Self {
+        Self {
+            code: currency.code().to_string(),
+            name: currency.name().to_string(),
+            kind: currency.kind().to_string(),
+            minor_units: currency.exponent(),
+        }
+    }
+
+    fn fields(&self) -> [String; 4] {
+        [
+            self.code.clone(),
+            self.name.clone(),
+            self.kind.clone(),
+            self.minor_units.map_or(String::new(), |u| u.to_string()),
+        ]
+    }
+}
+
+/// Formats an amount with all the minor units of its currency (e.g. "200.00").
+fn with_minor_units(money: &Money) -> String {
+    let mut amount = money.amount();
+    if let Some(exponent) = money.currency().exponent() {
+        amount.rescale(exponent.into());
+    }
+    amount.to_string()
+}
+
+/// Renders `output` in `format`, ending with a newline unless empty.
+pub fn render(output: &Output, format: OutputFormat) -> String {
+    match (output, format) {
+        (Output::Money { rounded, .. }, OutputFormat::Plain) => {
+            format!("{} {}\n", with_minor_units(rounded), rounded.currency())
+        }
+        (Output::Money { money, rounded, fx }, format) => {
+            let record = MoneyRecord::new(money, rounded, fx.as_ref());
+            match format {
+                OutputFormat::Json => json(&record),
+                OutputFormat::Csv => csv(&MONEY_COLUMNS, &[record.fields()]),
+                _ => table(&MONEY_COLUMNS, &[record.fields()]),
+            }
+        }
+        (Output::Currencies(currencies), OutputFormat::Plain) => currencies
+            .iter()
+            .map(|c| format!("{}\t{}\n", c.code(), c.name()))
+            .collect(),
+        (Output::Currencies(currencies), format) => {
+            let records: Vec<CurrencyRecord> = currencies.iter().map(CurrencyRecord::new).collect();
+            let rows: Vec<[String; 4]> = records.iter().map(CurrencyRecord::fields).collect();
+            match format {
+                OutputFormat::Json => json(&records),
+                OutputFormat::Csv => csv(&CURRENCY_COLUMNS, &rows),
+                _ => table(&CURRENCY_COLUMNS, &rows),
+            }
+        }
+    }
+}
+
+fn json<T: Serialize>(value: &T) -> String {
+    // Records only hold strings, numbers and options, which always serialize.
+    let mut text = serde_json::to_string(value).expect("records serialize to JSON");
+    text.push('\n');
+    text
+}
+
+fn csv<const N: usize>(columns: &[&str; N], rows: &[[String; N]]) -> String {
+    let mut writer = ::csv::Writer::from_writer(Vec::new());
+    let written = writer
+        .write_record(columns)
+        .and_then(|_| rows.iter().try_for_each(|row| writer.write_record(row)));
+    written.expect("writing CSV to memory cannot fail");
+    let bytes = writer
+        .into_inner()
+        .expect("writing CSV to memory cannot fail");
+    String::from_utf8(bytes).expect("CSV of UTF-8 fields is UTF-8")
+}
+
+/// Left-aligned columns separated by two spaces; missing values shown as `-`.
+fn table<const N: usize>(columns: &[&str; N], rows: &[[String; N]]) -> String {
+    let cell = |value: &str| {
+        if value.is_empty() {
+            "-".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    let mut lines: Vec<Vec<String>> = vec![columns.iter().map(|c| c.to_string()).collect()];
+    lines.extend(rows.iter().map(|row| row.iter().map(|v| cell(v)).collect()));
+    let widths: Vec<usize> = (0..N)
+        .map(|i| {
+            lines
+                .iter()
+                .map(|l| l[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    lines
+        .iter()
+        .map(|line| {
+            let padded: Vec<String> = line
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use ratefy_lib::fx::CurrencyPair;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    fn applied() -> Output {
+        let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+        let money = Money::new(Decimal::new(1740, 2), eur)
+            .apply_rate(Decimal::from(15))
+            .with_source("rates.csv")
+            .with_timestamp(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+            .with_tag("retail");
+        let rounded = money.rounded();
+        Output::Money {
+            money,
+            rounded,
+            fx: None,
+        }
+    }
+
+    fn converted() -> Output {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let pair = CurrencyPair::from_str("EUR/USD").unwrap();
+        let rate = ExchangeRate::new(pair, Decimal::new(108, 2), date).unwrap();
+        let eur = Money::new(Decimal::from(100), CurrencyAlpha3::from_str("EUR").unwrap());
+        let money = rate.convert(&eur).unwrap();
+        Output::Money {
+            rounded: money.rounded(),
+            money,
+            fx: Some(rate),
+        }
+    }
+
+    fn currencies() -> Output {
+        let codes = ["CHF", "JPY"];
+        Output::Currencies(
+            codes
+                .iter()
+                .map(|c| CurrencyAlpha3::from_str(c).unwrap())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_money_formats() {
+        assert_eq!(render(&applied(), OutputFormat::Plain), "20.01 EUR\n");
+        assert_eq!(
+            render(&applied(), OutputFormat::Json),
+            "{\"currency\":\"EUR\",\"amount\":\"20.0100\",\"rounded\":\"20.01\",\"rate\":\"15\",\
+             \"source\":\"rates.csv\",\"timestamp\":\"2024-03-01\",\"tags\":[\"retail\"],\"fx_rate\":null,\"fx_pair\":null}\n"
+        );
+        assert_eq!(
+            render(&applied(), OutputFormat::Csv),
+            "currency,amount,rounded,rate,source,timestamp,tags,fx_rate,fx_pair\n\
+             EUR,20.0100,20.01,15,rates.csv,2024-03-01,retail,,\n"
+        );
+        assert_eq!(
+            render(&applied(), OutputFormat::Table),
+            "currency  amount   rounded  rate  source     timestamp   tags    fx_rate  fx_pair\n\
+             EUR       20.0100  20.01    15    rates.csv  2024-03-01  retail  -        -\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_metadata() {
+        let usd = CurrencyAlpha3::from_str("USD").unwrap();
+        let money = Money::new(Decimal::from(200), usd);
+        let output = Output::Money {
+            rounded: money.rounded(),
+            money,
+            fx: None,
+        };
+        assert_eq!(
+            render(&output, OutputFormat::Json),
+            "{\"currency\":\"USD\",\"amount\":\"200\",\"rounded\":\"200.00\",\"rate\":null,\
+             \"source\":null,\"timestamp\":null,\"tags\":[],\"fx_rate\":null,\"fx_pair\":null}\n"
+        );
+        assert_eq!(
+            render(&output, OutputFormat::Csv).lines().nth(1),
+            Some("USD,200,200.00,,,,,,")
+        );
+        assert_eq!(
+            render(&output, OutputFormat::Table).lines().nth(1),
+            Some("USD       200     200.00   -     -       -          -     -        -")
+        );
+    }
+
+    #[test]
+    fn test_exchange_rate_fields() {
+        assert_eq!(render(&converted(), OutputFormat::Plain), "108.00 USD\n");
+        assert_eq!(
+            render(&converted(), OutputFormat::Json),
+            "{\"currency\":\"USD\",\"amount\":\"108.00\",\"rounded\":\"108.00\",\"rate\":null,\
+             \"source\":null,\"timestamp\":\"2024-03-01\",\"tags\":[],\
+             \"fx_rate\":\"1.08\",\"fx_pair\":\"EUR/USD\"}\n"
+        );
+        assert_eq!(
+            render(&converted(), OutputFormat::Csv).lines().nth(1),
+            Some("USD,108.00,108.00,,,2024-03-01,,1.08,EUR/USD")
+        );
+    }
+
+    #[test]
+    fn test_currency_formats() {
+        assert_eq!(
+            render(&currencies(), OutputFormat::Plain),
+            "CHF\tSwiss franc\nJPY\tJapanese yen\n"
+        );
+        assert_eq!(
+            render(&currencies(), OutputFormat::Json),
+            "[{\"code\":\"CHF\",\"name\":\"Swiss franc\",\"kind\":\"fiat\",\"minor_units\":2},\
+             {\"code\":\"JPY\",\"name\":\"Japanese yen\",\"kind\":\"fiat\",\"minor_units\":0}]\n"
+        );
+        assert_eq!(
+            render(&currencies(), OutputFormat::Csv),
+            "code,name,kind,minor_units\nCHF,Swiss franc,fiat,2\nJPY,Japanese yen,fiat,0\n"
+        );
+        assert_eq!(
+            render(&currencies(), OutputFormat::Table),
+            "code  name          kind  minor_units\n\
+             CHF   Swiss franc   fiat  2\n\
+             JPY   Japanese yen  fiat  0\n"
+        );
+    }
+}
@@ -17,6 +17,7 @@ pub enum VerticalAlign {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
 
@@ -1,4 +1,5 @@
 use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
 use crossterm::event::{self, Event, KeyCode};
 use ratatui::{
     Terminal,
@@ -6,22 +7,114 @@ use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Modifier, Style},
     text::Text,
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
 };
-use ratefy_lib::apply_percentage_str;
-use ratefy_lib::money::CurrencyGroup;
+use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup, Money, PriceRule};
 use rust_decimal::Decimal;
 use std::io;
-use std::time::{Duration, Instant};
+use std::str::FromStr;
+
+/// Number of input steps: base, rate, currency and price rule
+const STEPS: usize = 4;
+
+/// Calculated amount, its currency, and the price after the optional price rule
+type Calculation = (Decimal, String, Option<Decimal>);
 
 /// Helper to calculate result and store error message if any
 fn calculate_result(
     base: &str,
     rate: &str,
     currency_code: &str,
-) -> Result<(Decimal, String), String> {
-    apply_percentage_str(base, rate, currency_code)
-        .ok_or_else(|| "Could not calculate percentage".to_string())
+    price_rule: &str,
+) -> Result<Calculation, String> {
+    let invalid = || "Could not calculate percentage".to_string();
+    let currency = CurrencyAlpha3::from_str(currency_code.trim()).map_err(|_| invalid())?;
+    let base_money = Money::from_str(base.trim(), currency).ok_or_else(invalid)?;
+    let rate = Decimal::from_str(rate.trim()).map_err(|_| invalid())?;
+    let money = base_money
+        .checked_apply_rate(rate)
+        .ok_or_else(|| format!("{} at {}% is out of range", base.trim(), rate))?;
+    let (amount, currency) = (money.amount(), money.currency().to_string());
+    if price_rule.trim().is_empty() {
+        return Ok((amount, currency, None));
+    }
+    let rule = PriceRule::from_str(price_rule)
+        .map_err(|_| format!("unknown price rule '{}'", price_rule.trim()))?;
+    let price = rule.checked_apply(&money).ok_or("price out of range")?;
+    Ok((amount, currency, Some(price.amount())))
+}
+
+/// Whether the fiat filter applies to `group`: groups without any fiat
+/// currency, such as "Assets", are shown in full
+fn filters_fiat(group: &CurrencyGroup, fiat_only: bool) -> bool {
+    fiat_only && group.currencies().any(|c| c.is_fiat())
+}
+
+/// Currencies of `group` whose code contains `query` (case-insensitive),
+/// optionally restricted to fiat money
+fn filter_currencies(group: &CurrencyGroup, query: &str, fiat_only: bool) -> Vec<&'static str> {
+    let query = query.to_uppercase();
+    let fiat_only = filters_fiat(group, fiat_only);
+    group
+        .currencies()
+        .filter(|c| !fiat_only || c.is_fiat())
+        .map(|c| c.code())
+        .filter(|code| code.contains(&query))
+        .collect()
+}
+
+/// Describes a currency: name, codes, symbol, minor units and using countries
+fn currency_details(code: &str) -> String {
+    let Ok(currency) = CurrencyAlpha3::from_str(code) else {
+        return format!("{}: no details available", code);
+    };
+    let minor_units = currency
+        .exponent()
+        .map_or("none".to_string(), |e| e.to_string());
+    let countries: Vec<&str> = currency.countries().iter().map(|c| c.name()).collect();
+    let mut details = format!(
+        "{}\nCode: {}{}\nKind: {}\nSymbol: {}\nMinor units: {}\nUsed in: {}",
+        currency.name(),
+        currency.code(),
+        currency
+            .numeric()
+            .map_or(String::new(), |n| format!(" ({})", n)),
+        currency.kind(),
+        currency.symbol(),
+        minor_units,
+        if countries.is_empty() {
+            "-".to_string()
+        } else {
+            countries.join(", ")
+        }
+    );
+    if let Some(history) = currency.history() {
+        let bound =
+            |date: Option<chrono::NaiveDate>| date.map_or("…".to_string(), |d| d.to_string());
+        details.push_str(&format!(
+            "\nValid: {} – {}",
+            bound(history.introduced()),
+            bound(history.withdrawn())
+        ));
+        if let Some(r) = history.successor() {
+            details.push_str(&format!(
+                "\nReplaced by {} on {} at {} {} = 1 {}",
+                r.successor,
+                r.effective,
+                r.factor,
+                currency.code(),
+                r.successor
+            ));
+        }
+    }
+    details
+}
+
+/// Title of the currency popup tab, with arrows pointing to the neighbouring groups
+fn group_title(groups: &[CurrencyGroup], index: usize) -> String {
+    let left = if index > 0 { "◀ " } else { "" };
+    let right = if index + 1 < groups.len() { " ▶" } else { "" };
+    format!("{}{}{}", left, groups[index].name(), right)
 }
 
 /// Handles the percentage calculation screen
@@ -30,64 +123,33 @@ pub fn apply_percentage_view(
     h_align: HorizontalAlign,
     v_align: VerticalAlign,
     show_border: bool,
+    groups: &[CurrencyGroup],
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut input_base = String::new();
     let mut input_rate = String::new();
+    let mut input_rule = String::new();
     let mut selected_currency = "EUR".to_string();
     let mut step = 0;
-    let mut result: Option<Result<(Decimal, String), String>> = None;
+    let mut result: Option<Result<Calculation, String>> = None;
 
     let mut show_currency_popup = false;
-    let mut currency_group_index = 0; // 0 = G3, 1 = G10, 2 = All
+    let mut currency_group_index = 0; // index into `groups`
     let mut currency_list_state = ListState::default();
     let mut search_query = String::new();
+    let mut fiat_only = true;
+    // Recomputed only when the group or the search query changes
+    let mut filtered_list =
+        filter_currencies(&groups[currency_group_index], &search_query, fiat_only);
 
-    let mut cursor_visible = true;
-    let mut last_cursor_toggle = Instant::now();
+    let mut cursor = CursorBlink::new();
 
     loop {
-        if last_cursor_toggle.elapsed() >= Duration::from_millis(500) {
-            cursor_visible = !cursor_visible;
-            last_cursor_toggle = Instant::now();
-        }
+        cursor.tick();
         terminal.draw(|f| {
             let outer = f.size();
 
-            let width = match h_align {
-                HorizontalAlign::Full => outer.width,
-                _ => 80,
-            };
-
-            let height = match v_align {
-                VerticalAlign::Full => outer.height,
-                _ => 20,
-            };
-
-            let x = match h_align {
-                HorizontalAlign::Left | HorizontalAlign::Full => outer.x,
-                HorizontalAlign::Center => outer.x + (outer.width.saturating_sub(width)) / 2,
-                HorizontalAlign::Right => outer.x + outer.width.saturating_sub(width),
-            };
-
-            let y = match v_align {
-                VerticalAlign::Top | VerticalAlign::Full => outer.y,
-                VerticalAlign::Middle => outer.y + (outer.height.saturating_sub(height)) / 2,
-                VerticalAlign::Bottom => outer.y + outer.height.saturating_sub(height),
-            };
-
-            let viewport = ratatui::layout::Rect {
-                x,
-                y,
-                width,
-                height,
-            };
-            if show_border {
-                let frame_block = Block::default()
-                    .borders(Borders::ALL)
-                    .title("Apply Percentage")
-                    .style(Style::default().bg(ratatui::style::Color::Blue));
-                f.render_widget(frame_block, viewport);
-            }
+            let viewport = viewport(outer, &h_align, &v_align);
+            render_frame(f, viewport, "Apply Percentage", show_border);
 
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -96,54 +158,25 @@ pub fn apply_percentage_view(
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(3),
-                    Constraint::Length(1),
                     Constraint::Length(3),
+                    Constraint::Length(1),
+                    Constraint::Length(4),
                     Constraint::Length(4),
                 ])
                 .split(viewport);
 
             // Input base
-            let base_style = if step == 0 {
-                Style::default()
-                    .fg(ratatui::style::Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(ratatui::style::Color::White)
-            };
-            let base_display = if step == 0 && cursor_visible {
-                format!("{}▌", input_base)
-            } else {
-                input_base.clone()
-            };
-            let base_input = Paragraph::new(Text::from(base_display))
-                .block(
-                    Block::default()
-                        .title("Enter base value")
-                        .borders(Borders::ALL),
-                )
-                .style(base_style);
+            let base_input =
+                input_field("Enter base value", &input_base, step == 0, cursor.visible);
             f.render_widget(base_input, chunks[0]);
 
             // Input rate
-            let rate_style = if step == 1 {
-                Style::default()
-                    .fg(ratatui::style::Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(ratatui::style::Color::White)
-            };
-            let rate_display = if step == 1 && cursor_visible {
-                format!("{}▌", input_rate)
-            } else {
-                input_rate.clone()
-            };
-            let rate_input = Paragraph::new(Text::from(rate_display))
-                .block(
-                    Block::default()
-                        .title("Enter percentage rate")
-                        .borders(Borders::ALL),
-                )
-                .style(rate_style);
+            let rate_input = input_field(
+                "Enter percentage rate",
+                &input_rate,
+                step == 1,
+                cursor.visible,
+            );
             f.render_widget(rate_input, chunks[1]);
 
             // Currency selection display (replaces List with stylized Paragraph)
@@ -161,52 +194,50 @@ pub fn apply_percentage_view(
                 .block(Block::default().borders(Borders::ALL));
             f.render_widget(currency_block, chunks[2]);
 
+            // Input price rule
+            let rule_input = input_field(
+                "Price rule (.99, .95 down, 5, cash) – optional",
+                &input_rule,
+                step == 3,
+                cursor.visible,
+            );
+            f.render_widget(rule_input, chunks[3]);
+
             // Spacing
             let blank = Paragraph::new("");
-            f.render_widget(blank, chunks[3]);
+            f.render_widget(blank, chunks[4]);
 
             // Result
             let msg = match &result {
-                Some(Ok((amount, currency))) => format!("Result: {:.2} {}", amount, currency),
+                Some(Ok((amount, currency, None))) => format!("Result: {:.2} {}", amount, currency),
+                Some(Ok((amount, currency, Some(price)))) => format!(
+                    "Result: {:.2} {}\nPrice:  {:.2} {}",
+                    amount, currency, price, currency
+                ),
                 Some(Err(err_msg)) => format!("Error: {}", err_msg),
                 None => "Result will appear here.".to_string(),
             };
             let result_paragraph = Paragraph::new(Text::from(msg))
                 .style(Style::default().fg(ratatui::style::Color::White))
                 .block(Block::default().title("Output").borders(Borders::ALL));
-            f.render_widget(result_paragraph, chunks[4]);
+            f.render_widget(result_paragraph, chunks[5]);
 
             // Legend
             let legend_text =
-                "TAB: next | Shift+TAB: prev | ↑↓: navigate | Enter: confirm | ESC: exit";
+                "TAB: next (fiat/all in list) | Shift+TAB: prev | ↑↓: navigate | Enter: confirm | ESC: exit";
             let legend_paragraph = Paragraph::new(Text::from(legend_text))
+                .wrap(Wrap { trim: true })
                 .style(Style::default().fg(ratatui::style::Color::White));
-            f.render_widget(legend_paragraph, chunks[5]);
+            f.render_widget(legend_paragraph, chunks[6]);
 
             if show_currency_popup {
                 use ratatui::widgets::Clear;
-                let groups = [&CurrencyGroup::G3, &CurrencyGroup::G10, &CurrencyGroup::All];
-                // let currencies = groups[currency_group_index].list();
-                let full_list = groups[currency_group_index].list();
-                let filtered_list: Vec<String> = if search_query.is_empty() {
-                    full_list.clone()
-                } else {
-                    full_list
-                        .iter()
-                        .filter(|c| c.to_lowercase().contains(&search_query.to_lowercase()))
-                        .cloned()
-                        .collect()
-                };
                 let popup_area = ratatui::layout::Rect {
                     x: viewport.x + 5,
                     y: viewport.y + 5,
                     width: 30,
                     height: 10,
                 };
-                // let items: Vec<ListItem> = currencies
-                //     .iter()
-                //     .map(|c| ListItem::new(c.to_string()))
-                //     .collect();
                 let items: Vec<ListItem> = if filtered_list.is_empty() {
                     vec![ListItem::new("No results")]
                 } else {
@@ -216,7 +247,6 @@ pub fn apply_percentage_view(
                         .collect()
                 };
                 f.render_widget(Clear, popup_area);
-                let group_titles = ["G3 ▶", "◀ G10 ▶", "◀ ALL"];
                 let selected = currency_list_state.selected().unwrap_or(0);
                 let list_len = filtered_list.len();
                 let mut scroll_hint = String::new();
@@ -226,10 +256,16 @@ pub fn apply_percentage_view(
                 if selected + 1 < list_len {
                     scroll_hint.push('↓');
                 }
-                // let title = format!("{} {}", group_titles[currency_group_index], scroll_hint);
                 let title = format!(
-                    "{} {} [{}]",
-                    group_titles[currency_group_index], scroll_hint, search_query
+                    "{} {} [{}]{}",
+                    group_title(groups, currency_group_index),
+                    scroll_hint,
+                    search_query,
+                    if filters_fiat(&groups[currency_group_index], fiat_only) {
+                        " fiat"
+                    } else {
+                        ""
+                    }
                 );
                 let list = List::new(items)
                     .block(Block::default().title(title).borders(Borders::ALL))
@@ -239,220 +275,183 @@ pub fn apply_percentage_view(
                             .add_modifier(Modifier::BOLD),
                     );
                 f.render_stateful_widget(list, popup_area, &mut currency_list_state);
+
+                // Details of the highlighted currency
+                if let Some(code) = filtered_list.get(selected) {
+                    let details_area = ratatui::layout::Rect {
+                        x: popup_area.x + popup_area.width + 1,
+                        y: popup_area.y,
+                        width: 44.min(viewport.width.saturating_sub(popup_area.width + 7)),
+                        height: popup_area.height,
+                    };
+                    let details = Paragraph::new(Text::from(currency_details(code)))
+                        .wrap(Wrap { trim: true })
+                        .block(Block::default().title("Details").borders(Borders::ALL));
+                    f.render_widget(Clear, details_area);
+                    f.render_widget(details, details_area);
+                }
             }
         })?;
 
-        if event::poll(std::time::Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Esc => {
-                        if show_currency_popup {
-                            show_currency_popup = false;
-                            search_query.clear();
-                        } else {
-                            break;
-                        }
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => {
+                    if show_currency_popup {
+                        show_currency_popup = false;
+                        search_query.clear();
+                    } else {
+                        break;
                     }
-                    KeyCode::Backspace => {
-                        if show_currency_popup {
-                            search_query.pop();
-                            currency_list_state.select(Some(0));
-                        } else {
-                            match step {
-                                0 => {
-                                    input_base.pop();
-                                    let _ = calculate_result(
-                                        &input_base,
-                                        &input_rate,
-                                        &selected_currency,
-                                    );
-                                }
-                                1 => {
-                                    input_rate.pop();
-                                    let _ = calculate_result(
-                                        &input_base,
-                                        &input_rate,
-                                        &selected_currency,
-                                    );
-                                }
-                                _ => {}
+                }
+                KeyCode::Backspace => {
+                    if show_currency_popup {
+                        search_query.pop();
+                        filtered_list = filter_currencies(
+                            &groups[currency_group_index],
+                            &search_query,
+                            fiat_only,
+                        );
+                        currency_list_state.select(Some(0));
+                    } else {
+                        match step {
+                            0 => {
+                                input_base.pop();
                             }
-                        }
-                    }
-                    KeyCode::Tab => {
-                        step = (step + 1) % 3;
-                    }
-                    KeyCode::BackTab => {
-                        step = if step == 0 { 2 } else { step - 1 };
-                    }
-                    KeyCode::Up => {
-                        if show_currency_popup {
-                            if let Some(selected) = currency_list_state.selected() {
-                                if selected > 0 {
-                                    currency_list_state.select(Some(selected - 1));
-                                }
+                            1 => {
+                                input_rate.pop();
                             }
-                        } else if step != 0 {
-                            step -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if show_currency_popup {
-                            let selected = currency_list_state.selected().unwrap_or(0);
-                            let list_len = match currency_group_index {
-                                0 => {
-                                    let groups = [
-                                        &CurrencyGroup::G3,
-                                        &CurrencyGroup::G10,
-                                        &CurrencyGroup::All,
-                                    ];
-                                    let full_list = groups[currency_group_index].list();
-                                    let filtered_list: Vec<String> = if search_query.is_empty() {
-                                        full_list.clone()
-                                    } else {
-                                        full_list
-                                            .iter()
-                                            .filter(|c| {
-                                                c.to_lowercase()
-                                                    .contains(&search_query.to_lowercase())
-                                            })
-                                            .cloned()
-                                            .collect()
-                                    };
-                                    filtered_list.len()
-                                }
-                                1 => {
-                                    let groups = [
-                                        &CurrencyGroup::G3,
-                                        &CurrencyGroup::G10,
-                                        &CurrencyGroup::All,
-                                    ];
-                                    let full_list = groups[currency_group_index].list();
-                                    let filtered_list: Vec<String> = if search_query.is_empty() {
-                                        full_list.clone()
-                                    } else {
-                                        full_list
-                                            .iter()
-                                            .filter(|c| {
-                                                c.to_lowercase()
-                                                    .contains(&search_query.to_lowercase())
-                                            })
-                                            .cloned()
-                                            .collect()
-                                    };
-                                    filtered_list.len()
-                                }
-                                _ => {
-                                    let groups = [
-                                        &CurrencyGroup::G3,
-                                        &CurrencyGroup::G10,
-                                        &CurrencyGroup::All,
-                                    ];
-                                    let full_list = groups[currency_group_index].list();
-                                    let filtered_list: Vec<String> = if search_query.is_empty() {
-                                        full_list.clone()
-                                    } else {
-                                        full_list
-                                            .iter()
-                                            .filter(|c| {
-                                                c.to_lowercase()
-                                                    .contains(&search_query.to_lowercase())
-                                            })
-                                            .cloned()
-                                            .collect()
-                                    };
-                                    filtered_list.len()
-                                }
-                            };
-                            if selected + 1 < list_len {
-                                currency_list_state.select(Some(selected + 1));
+                            3 => {
+                                input_rule.pop();
                             }
-                        } else {
-                            step = (step + 1) % 3;
+                            _ => {}
                         }
+                        result = Some(calculate_result(
+                            &input_base,
+                            &input_rate,
+                            &selected_currency,
+                            &input_rule,
+                        ));
                     }
-                    KeyCode::Right => {
-                        if show_currency_popup {
-                            currency_group_index = (currency_group_index + 1) % 3;
-                            currency_list_state.select(Some(0));
-                            search_query.clear();
+                }
+                KeyCode::Tab if show_currency_popup => {
+                    fiat_only = !fiat_only;
+                    currency_list_state.select(Some(0));
+                    filtered_list =
+                        filter_currencies(&groups[currency_group_index], &search_query, fiat_only);
+                }
+                KeyCode::Tab => {
+                    step = (step + 1) % STEPS;
+                }
+                KeyCode::BackTab => {
+                    step = (step + STEPS - 1) % STEPS;
+                }
+                KeyCode::Up => {
+                    if show_currency_popup {
+                        if let Some(selected) = currency_list_state.selected()
+                            && selected > 0
+                        {
+                            currency_list_state.select(Some(selected - 1));
                         }
+                    } else {
+                        step = step.saturating_sub(1);
                     }
-                    KeyCode::Left => {
-                        if show_currency_popup && currency_group_index > 0 {
-                            currency_group_index -= 1;
-                            currency_list_state.select(Some(0));
-                            search_query.clear();
+                }
+                KeyCode::Down => {
+                    if show_currency_popup {
+                        let selected = currency_list_state.selected().unwrap_or(0);
+                        if selected + 1 < filtered_list.len() {
+                            currency_list_state.select(Some(selected + 1));
                         }
+                    } else {
+                        step = (step + 1) % STEPS;
                     }
-                    KeyCode::Enter => {
-                        if step == 2 && !show_currency_popup {
-                            show_currency_popup = true;
-                            currency_group_index = 0;
-                            currency_list_state.select(Some(0));
-                            search_query.clear();
-                        } else if show_currency_popup {
-                            let groups =
-                                [&CurrencyGroup::G3, &CurrencyGroup::G10, &CurrencyGroup::All];
-                            let full_list = groups[currency_group_index].list();
-                            let filtered_list: Vec<String> = if search_query.is_empty() {
-                                full_list.clone()
-                            } else {
-                                full_list
-                                    .iter()
-                                    .filter(|c| {
-                                        c.to_lowercase().contains(&search_query.to_lowercase())
-                                    })
-                                    .cloned()
-                                    .collect()
-                            };
-                            if let Some(selected) = currency_list_state.selected() {
-                                if !filtered_list.is_empty() {
-                                    selected_currency = filtered_list[selected].clone();
-                                }
-                                result = Some(calculate_result(
-                                    &input_base,
-                                    &input_rate,
-                                    &selected_currency,
-                                ));
-                                search_query.clear();
-                                show_currency_popup = false;
+                }
+                KeyCode::Right if show_currency_popup => {
+                    currency_group_index = (currency_group_index + 1) % groups.len();
+                    currency_list_state.select(Some(0));
+                    search_query.clear();
+                    filtered_list =
+                        filter_currencies(&groups[currency_group_index], &search_query, fiat_only);
+                }
+                KeyCode::Left if show_currency_popup && currency_group_index > 0 => {
+                    currency_group_index -= 1;
+                    currency_list_state.select(Some(0));
+                    search_query.clear();
+                    filtered_list =
+                        filter_currencies(&groups[currency_group_index], &search_query, fiat_only);
+                }
+                KeyCode::Enter => {
+                    if step == 2 && !show_currency_popup {
+                        show_currency_popup = true;
+                        currency_group_index = 0;
+                        currency_list_state.select(Some(0));
+                        search_query.clear();
+                        filtered_list = filter_currencies(
+                            &groups[currency_group_index],
+                            &search_query,
+                            fiat_only,
+                        );
+                    } else if show_currency_popup {
+                        if let Some(selected) = currency_list_state.selected() {
+                            if !filtered_list.is_empty() {
+                                selected_currency = filtered_list[selected].to_string();
                             }
-                        } else {
-                            step = (step + 1) % 3;
+                            result = Some(calculate_result(
+                                &input_base,
+                                &input_rate,
+                                &selected_currency,
+                                &input_rule,
+                            ));
+                            search_query.clear();
+                            show_currency_popup = false;
                         }
+                    } else {
+                        step = (step + 1) % STEPS;
                     }
-                    KeyCode::Char(c)
-                        if (c.is_ascii_digit() || c == '.') && (step == 0 || step == 1) =>
-                    {
-                        match step {
-                            0 => {
-                                input_base.push(c);
-                                result = Some(calculate_result(
-                                    &input_base,
-                                    &input_rate,
-                                    &selected_currency,
-                                ));
-                            }
-                            1 => {
-                                input_rate.push(c);
-                                result = Some(calculate_result(
-                                    &input_base,
-                                    &input_rate,
-                                    &selected_currency,
-                                ));
-                            }
-                            _ => {}
+                }
+                KeyCode::Char(c) if step == 3 && !show_currency_popup => {
+                    input_rule.push(c);
+                    result = Some(calculate_result(
+                        &input_base,
+                        &input_rate,
+                        &selected_currency,
+                        &input_rule,
+                    ));
+                }
+                KeyCode::Char(c)
+                    if (c.is_ascii_digit() || c == '.') && (step == 0 || step == 1) =>
+                {
+                    match step {
+                        0 => {
+                            input_base.push(c);
+                            result = Some(calculate_result(
+                                &input_base,
+                                &input_rate,
+                                &selected_currency,
+                                &input_rule,
+                            ));
                         }
-                    }
-                    KeyCode::Char(c) => {
-                        if show_currency_popup {
-                            search_query.push(c);
-                            currency_list_state.select(Some(0));
+                        1 => {
+                            input_rate.push(c);
+                            result = Some(calculate_result(
+                                &input_base,
+                                &input_rate,
+                                &selected_currency,
+                                &input_rule,
+                            ));
                         }
+                        _ => {}
                     }
-                    _ => {}
                 }
+                KeyCode::Char(c) if show_currency_popup => {
+                    search_query.push(c);
+                    filtered_list =
+                        filter_currencies(&groups[currency_group_index], &search_query, fiat_only);
+                    currency_list_state.select(Some(0));
+                }
+                _ => {}
             }
         }
     }
@@ -0,0 +1,157 @@
+use crate::config;
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+};
+use ratefy_lib::brackets::BracketSchedule;
+use ratefy_lib::money::Money;
+use rust_decimal::Decimal;
+use std::io;
+use std::str::FromStr;
+
+const FIELDS: [&str; 3] = [
+    "Brackets file (TOML)",
+    "Schedule (name, empty = first)",
+    "Amount",
+];
+
+/// Applies the selected schedule to the entered amount and lays out the breakdown.
+fn calculate(inputs: &[String; 3]) -> Result<String, String> {
+    let path = inputs[0].trim();
+    if path.is_empty() {
+        return Err(format!(
+            "no brackets file; set {} or create brackets.toml",
+            config::BRACKETS_ENV
+        ));
+    }
+    let schedules = BracketSchedule::load(path).map_err(|e| e.to_string())?;
+    let wanted = inputs[1].trim();
+    let schedule = if wanted.is_empty() {
+        schedules.first()
+    } else {
+        schedules
+            .iter()
+            .find(|s| s.name().eq_ignore_ascii_case(wanted))
+    };
+    let names: Vec<&str> = schedules.iter().map(|s| s.name()).collect();
+    let schedule =
+        schedule.ok_or_else(|| format!("no schedule '{}' (have: {})", wanted, names.join(", ")))?;
+    let value = Decimal::from_str(inputs[2].trim())
+        .map_err(|_| format!("invalid amount '{}'", inputs[2].trim()))?;
+    let result = schedule
+        .apply(&Money::new(value, schedule.currency().clone()))
+        .map_err(|e| e.to_string())?;
+
+    let amount = |m: &Money| format!("{:.2}", m.rounded().amount());
+    let mut lines = vec![
+        format!(
+            "{} ({}) – schedules: {}",
+            schedule.name(),
+            schedule.currency(),
+            names.join(", ")
+        ),
+        String::new(),
+        format!(
+            "{:>14} {:>14} {:>8} {:>14} {:>14}",
+            "From", "To", "Rate %", "Base", "Amount"
+        ),
+    ];
+    for line in &result.lines {
+        lines.push(format!(
+            "{:>14} {:>14} {:>8} {:>14} {:>14}",
+            amount(&line.from),
+            line.to.as_ref().map_or("–".to_string(), amount),
+            line.rate,
+            amount(&line.base),
+            amount(&line.amount)
+        ));
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "Total: {} {} | Effective rate: {:.2}% | Marginal rate: {}%",
+        amount(&result.total),
+        result.total.currency(),
+        result.effective_rate,
+        result.marginal_rate
+    ));
+    Ok(lines.join("\n"))
+}
+
+/// Handles the bracket calculator screen
+pub fn brackets_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inputs: [String; 3] = Default::default();
+    if let Some(path) = config::brackets_path() {
+        inputs[0] = path.display().to_string();
+    }
+    let mut step = 2;
+    let mut result: Option<Result<String, String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "Progressive Brackets", show_border);
+
+            let mut constraints = vec![Constraint::Length(3); FIELDS.len()];
+            constraints.push(Constraint::Min(5));
+            constraints.push(Constraint::Length(1));
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints(constraints)
+                .split(viewport);
+
+            for (index, title) in FIELDS.iter().enumerate() {
+                let field = input_field(title, &inputs[index], step == index, cursor.visible);
+                f.render_widget(field, chunks[index]);
+            }
+
+            let msg = match &result {
+                Some(Ok(report)) => report.clone(),
+                Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                None => "Press Enter to apply the brackets.".to_string(),
+            };
+            let result_paragraph = Paragraph::new(Text::from(msg))
+                .style(Style::default().fg(Color::White))
+                .block(Block::default().title("Output").borders(Borders::ALL));
+            f.render_widget(result_paragraph, chunks[FIELDS.len()]);
+
+            let legend = Paragraph::new("TAB: next | Shift+TAB: prev | Enter: compute | ESC: exit")
+                .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[FIELDS.len() + 1]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::Down => step = (step + 1) % FIELDS.len(),
+                KeyCode::BackTab | KeyCode::Up => {
+                    step = (step + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Enter => result = Some(calculate(&inputs)),
+                KeyCode::Backspace => {
+                    inputs[step].pop();
+                }
+                KeyCode::Char(c) => inputs[step].push(c),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
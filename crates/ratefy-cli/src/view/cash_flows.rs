@@ -0,0 +1,136 @@
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+};
+use ratefy_lib::cashflow::{CashFlow, import, irr, npv, xirr, xnpv};
+use rust_decimal::Decimal;
+use std::io;
+use std::str::FromStr;
+
+/// Loads the CSV file and renders the flows together with NPV, IRR and XIRR.
+fn analyse(path: &str, rate: &str) -> Result<String, String> {
+    let flows: Vec<CashFlow> = import::from_path(path.trim()).map_err(|e| e.to_string())?;
+    let mut lines: Vec<String> = flows
+        .iter()
+        .map(|f| format!("{}  {:>14.2} {}", f.date(), f.amount(), f.currency()))
+        .collect();
+    lines.push(String::new());
+
+    match Decimal::from_str(rate.trim()) {
+        Ok(rate) => {
+            lines.push(describe(
+                "NPV",
+                npv(&flows, rate).map(|m| format!("{:.2} {}", m.amount(), m.currency())),
+            ));
+            lines.push(describe(
+                "XNPV",
+                xnpv(&flows, rate).map(|m| format!("{:.2} {}", m.amount(), m.currency())),
+            ));
+        }
+        Err(_) => lines.push("NPV / XNPV: enter a discount rate".to_string()),
+    }
+    lines.push(describe("IRR", irr(&flows).map(|r| format!("{:.4}%", r))));
+    lines.push(describe("XIRR", xirr(&flows).map(|r| format!("{:.4}%", r))));
+    Ok(lines.join("\n"))
+}
+
+fn describe<E: std::fmt::Display>(label: &str, value: Result<String, E>) -> String {
+    match value {
+        Ok(v) => format!("{}: {}", label, v),
+        Err(e) => format!("{}: error – {}", label, e),
+    }
+}
+
+/// Handles the cash flow analysis screen
+pub fn cash_flows_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut input_path = String::new();
+    let mut input_rate = String::new();
+    let mut step = 0;
+    let mut result: Option<Result<String, String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "Cash Flow Analysis", show_border);
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(5),
+                    Constraint::Length(1),
+                ])
+                .split(viewport);
+
+            let path_input = input_field(
+                "CSV file (date,amount,currency)",
+                &input_path,
+                step == 0,
+                cursor.visible,
+            );
+            f.render_widget(path_input, chunks[0]);
+
+            let rate_input =
+                input_field("Discount rate (%)", &input_rate, step == 1, cursor.visible);
+            f.render_widget(rate_input, chunks[1]);
+
+            let msg = match &result {
+                Some(Ok(report)) => report.clone(),
+                Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                None => "Press Enter to load the file and compute the results.".to_string(),
+            };
+            let result_paragraph = Paragraph::new(Text::from(msg))
+                .style(Style::default().fg(Color::White))
+                .block(Block::default().title("Output").borders(Borders::ALL));
+            f.render_widget(result_paragraph, chunks[2]);
+
+            let legend = Paragraph::new("TAB: next field | Enter: compute | ESC: exit")
+                .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[3]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                    step = (step + 1) % 2;
+                }
+                KeyCode::Enter => {
+                    result = Some(analyse(&input_path, &input_rate));
+                }
+                KeyCode::Backspace => {
+                    if step == 0 {
+                        input_path.pop();
+                    } else {
+                        input_rate.pop();
+                    }
+                }
+                KeyCode::Char(c) if step == 0 => input_path.push(c),
+                KeyCode::Char(c) if c.is_ascii_digit() || c == '.' || c == '-' => {
+                    input_rate.push(c);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+};
+use std::time::{Duration, Instant};
+
+/// Computes the area a view draws into, given the terminal area and alignment.
+pub fn viewport(outer: Rect, h_align: &HorizontalAlign, v_align: &VerticalAlign) -> Rect {
+    let width = match h_align {
+        HorizontalAlign::Full => outer.width,
+        _ => 80.min(outer.width),
+    };
+
+    let height = match v_align {
+        VerticalAlign::Full => outer.height,
+        _ => 20.min(outer.height),
+    };
+
+    let x = match h_align {
+        HorizontalAlign::Left | HorizontalAlign::Full => outer.x,
+        HorizontalAlign::Center => outer.x + (outer.width.saturating_sub(width)) / 2,
+        HorizontalAlign::Right => outer.x + outer.width.saturating_sub(width),
+    };
+
+    let y = match v_align {
+        VerticalAlign::Top | VerticalAlign::Full => outer.y,
+        VerticalAlign::Middle => outer.y + (outer.height.saturating_sub(height)) / 2,
+        VerticalAlign::Bottom => outer.y + outer.height.saturating_sub(height),
+    };
+
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Draws the titled background frame of a view when borders are enabled.
+pub fn render_frame(f: &mut Frame, viewport: Rect, title: &str, show_border: bool) {
+    if show_border {
+        let frame_block = Block::default()
+            .borders(Borders::ALL)
+            .title(title.to_string())
+            .style(Style::default().bg(Color::Blue));
+        f.render_widget(frame_block, viewport);
+    }
+}
+
+/// Style of an input field, highlighted when it has the focus.
+pub fn field_style(active: bool) -> Style {
+    if active {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    }
+}
+
+/// Builds a bordered input field, showing a blinking cursor when focused.
+pub fn input_field<'a>(
+    title: &'a str,
+    value: &str,
+    active: bool,
+    cursor_visible: bool,
+) -> Paragraph<'a> {
+    let display = if active && cursor_visible {
+        format!("{}▌", value)
+    } else {
+        value.to_string()
+    };
+    Paragraph::new(Text::from(display))
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .style(field_style(active))
+}
+
+/// Toggles the input cursor visibility every 500ms.
+pub struct CursorBlink {
+    pub visible: bool,
+    last_toggle: Instant,
+}
+
+impl CursorBlink {
+    pub fn new() -> Self {
+        CursorBlink {
+            visible: true,
+            last_toggle: Instant::now(),
+        }
+    }
+
+    /// Flips the cursor visibility if the blink interval has elapsed.
+    pub fn tick(&mut self) {
+        if self.last_toggle.elapsed() >= Duration::from_millis(500) {
+            self.visible = !self.visible;
+            self.last_toggle = Instant::now();
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::Text,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+use ratefy_lib::depreciation::{DepreciationMethod, DepreciationSchedule};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use std::fs::File;
+use std::io;
+use std::str::FromStr;
+
+const FIELDS: [&str; 7] = [
+    "Cost",
+    "Salvage value",
+    "Currency",
+    "Useful life (periods)",
+    "Method (SL, DB <rate %>, DDB, SYD, UOP)",
+    "Units (UOP: total, then one figure per period, comma-separated)",
+    "Export to CSV file (empty = no export)",
+];
+
+/// Parses the method field, reading the units field for units of production.
+fn method(text: &str, units: &str) -> Result<DepreciationMethod, String> {
+    let decimal = |value: &str| {
+        Decimal::from_str(value.trim()).map_err(|_| format!("invalid number '{}'", value.trim()))
+    };
+    let upper = text.trim().to_uppercase();
+    let mut words = upper.split_whitespace();
+    match (words.next(), words.next()) {
+        (Some("SL"), None) => Ok(DepreciationMethod::StraightLine),
+        (Some("DB"), Some(rate)) => Ok(DepreciationMethod::DecliningBalance {
+            rate: decimal(rate)?,
+        }),
+        (Some("DDB"), None) => Ok(DepreciationMethod::DoubleDeclining),
+        (Some("SYD"), None) => Ok(DepreciationMethod::SumOfYearsDigits),
+        (Some("UOP"), None) => {
+            let figures = units
+                .split(',')
+                .map(decimal)
+                .collect::<Result<Vec<_>, _>>()?;
+            let (total_units, units) = figures
+                .split_first()
+                .ok_or("units of production need the total units")?;
+            Ok(DepreciationMethod::UnitsOfProduction {
+                total_units: *total_units,
+                units: units.to_vec(),
+            })
+        }
+        _ => Err(format!("unknown method '{}'", text.trim())),
+    }
+}
+
+/// Builds the schedule and writes it to the export file if one is given.
+fn build(inputs: &[String; 7]) -> Result<(DepreciationSchedule, Option<String>), String> {
+    let currency = CurrencyAlpha3::from_str(inputs[2].trim())
+        .map_err(|_| format!("unknown currency '{}'", inputs[2].trim()))?;
+    let cost = Money::from_str(&inputs[0], currency.clone()).ok_or("invalid cost")?;
+    let salvage = Money::from_str(&inputs[1], currency).ok_or("invalid salvage value")?;
+    let life = inputs[3]
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| format!("invalid life '{}'", inputs[3].trim()))?;
+    let schedule =
+        DepreciationSchedule::new(&cost, &salvage, life, method(&inputs[4], &inputs[5])?)
+            .map_err(|e| e.to_string())?;
+
+    let path = inputs[6].trim();
+    if path.is_empty() {
+        return Ok((schedule, None));
+    }
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    schedule.write_csv(file).map_err(|e| e.to_string())?;
+    Ok((schedule, Some(path.to_string())))
+}
+
+/// Builds the schedule table, one row per period.
+fn schedule_table(schedule: &DepreciationSchedule, exported: &Option<String>) -> Table<'static> {
+    let header = Row::new([
+        "Period",
+        "Opening",
+        "Depreciation",
+        "Accumulated",
+        "Closing",
+    ])
+    .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows = schedule.periods.iter().map(|p| {
+        Row::new([
+            Cell::from(p.period.to_string()),
+            Cell::from(p.opening.amount().to_string()),
+            Cell::from(p.depreciation.amount().to_string()),
+            Cell::from(p.accumulated.amount().to_string()),
+            Cell::from(p.closing.amount().to_string()),
+        ])
+    });
+    let mut title = format!("{} – {}", schedule.method, schedule.cost.currency());
+    if let Some(path) = exported {
+        title.push_str(&format!(" – exported to {}", path));
+    }
+    Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(16),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title(title).borders(Borders::ALL))
+}
+
+/// Handles the depreciation schedule screen
+pub fn depreciation_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inputs: [String; 7] = Default::default();
+    inputs[1] = "0".to_string();
+    inputs[2] = "EUR".to_string();
+    inputs[4] = "SL".to_string();
+    let mut step = 0;
+    let mut result: Option<Result<(DepreciationSchedule, Option<String>), String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "Depreciation Schedule", show_border);
+
+            let mut constraints = vec![Constraint::Length(3); FIELDS.len()];
+            constraints.push(Constraint::Min(5));
+            constraints.push(Constraint::Length(1));
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints(constraints)
+                .split(viewport);
+
+            for (index, title) in FIELDS.iter().enumerate() {
+                let field = input_field(title, &inputs[index], step == index, cursor.visible);
+                f.render_widget(field, chunks[index]);
+            }
+
+            match &result {
+                Some(Ok((schedule, exported))) => {
+                    f.render_widget(schedule_table(schedule, exported), chunks[FIELDS.len()])
+                }
+                other => {
+                    let msg = match other {
+                        Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                        _ => "Press Enter to build the schedule.".to_string(),
+                    };
+                    let paragraph = Paragraph::new(Text::from(msg))
+                        .style(Style::default().fg(Color::White))
+                        .block(Block::default().title("Schedule").borders(Borders::ALL));
+                    f.render_widget(paragraph, chunks[FIELDS.len()]);
+                }
+            }
+
+            let legend =
+                Paragraph::new("TAB: next | Shift+TAB: prev | Enter: build/export | ESC: exit")
+                    .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[FIELDS.len() + 1]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::Down => step = (step + 1) % FIELDS.len(),
+                KeyCode::BackTab | KeyCode::Up => {
+                    step = (step + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Enter => result = Some(build(&inputs)),
+                KeyCode::Backspace => {
+                    inputs[step].pop();
+                }
+                KeyCode::Char(c) => inputs[step].push(c),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
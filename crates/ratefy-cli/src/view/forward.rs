@@ -0,0 +1,162 @@
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use chrono::{Local, NaiveDate};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+};
+use ratefy_lib::fx::{CurrencyPair, DayCount, ExchangeRate, Forward, Tenor};
+use rust_decimal::Decimal;
+use std::io;
+use std::str::FromStr;
+
+const FIELDS: [&str; 6] = [
+    "Pair (e.g. EUR/USD)",
+    "Spot rate",
+    "Base currency interest rate (%)",
+    "Quote currency interest rate (%)",
+    "Tenor (1W, 1M, 3M, 1Y or YYYY-MM-DD; empty = standard tenors)",
+    "Spot date (YYYY-MM-DD, empty = today)",
+];
+
+/// Tenors quoted when no tenor is entered.
+const STANDARD_TENORS: [Tenor; 6] = [
+    Tenor::Weeks(1),
+    Tenor::Months(1),
+    Tenor::Months(3),
+    Tenor::Months(6),
+    Tenor::Months(9),
+    Tenor::Years(1),
+];
+
+/// Prices the forward for the entered tenor, or for every standard tenor.
+fn quote(inputs: &[String; 6]) -> Result<String, String> {
+    let pair = CurrencyPair::from_str(&inputs[0]).map_err(|e| e.to_string())?;
+    let decimal = |index: usize| {
+        Decimal::from_str(inputs[index].trim())
+            .map_err(|_| format!("invalid {} '{}'", FIELDS[index], inputs[index].trim()))
+    };
+    let spot_date = if inputs[5].trim().is_empty() {
+        Local::now().date_naive()
+    } else {
+        NaiveDate::parse_from_str(inputs[5].trim(), "%Y-%m-%d")
+            .map_err(|_| format!("invalid date '{}'", inputs[5].trim()))?
+    };
+    let spot =
+        ExchangeRate::new(pair.clone(), decimal(1)?, spot_date).map_err(|e| e.to_string())?;
+    let (base_rate, quote_rate) = (decimal(2)?, decimal(3)?);
+    let tenors = if inputs[4].trim().is_empty() {
+        STANDARD_TENORS.to_vec()
+    } else {
+        vec![Tenor::from_str(&inputs[4]).map_err(|e| e.to_string())?]
+    };
+
+    let mut lines = vec![
+        format!(
+            "{} spot {} on {} | {} {} {}% | {} {} {}%",
+            pair,
+            spot.rate(),
+            spot_date,
+            pair.base(),
+            DayCount::money_market(pair.base()),
+            base_rate,
+            pair.quote(),
+            DayCount::money_market(pair.quote()),
+            quote_rate
+        ),
+        String::new(),
+        format!(
+            "{:<10} {:<10} {:>5} {:>12} {:>12} {:>10}",
+            "Tenor", "Maturity", "Days", "Outright", "Points", "Pips"
+        ),
+    ];
+    for tenor in tenors {
+        let forward =
+            Forward::new(&spot, base_rate, quote_rate, &tenor).map_err(|e| e.to_string())?;
+        lines.push(format!(
+            "{:<10} {:<10} {:>5} {:>12.5} {:>12.5} {:>10.2}",
+            tenor.to_string(),
+            forward.maturity(),
+            forward.days,
+            forward.outright.rate(),
+            forward.points,
+            forward.pips
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Handles the FX forward screen
+pub fn forward_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inputs: [String; 6] = Default::default();
+    inputs[0] = "EUR/USD".to_string();
+    let mut step = 1;
+    let mut result: Option<Result<String, String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "FX Forward", show_border);
+
+            let mut constraints = vec![Constraint::Length(3); FIELDS.len()];
+            constraints.push(Constraint::Min(5));
+            constraints.push(Constraint::Length(1));
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints(constraints)
+                .split(viewport);
+
+            for (index, title) in FIELDS.iter().enumerate() {
+                let field = input_field(title, &inputs[index], step == index, cursor.visible);
+                f.render_widget(field, chunks[index]);
+            }
+
+            let msg = match &result {
+                Some(Ok(report)) => report.clone(),
+                Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                None => "Press Enter to price the forward.".to_string(),
+            };
+            let result_paragraph = Paragraph::new(Text::from(msg))
+                .style(Style::default().fg(Color::White))
+                .block(Block::default().title("Output").borders(Borders::ALL));
+            f.render_widget(result_paragraph, chunks[FIELDS.len()]);
+
+            let legend = Paragraph::new("TAB: next | Shift+TAB: prev | Enter: compute | ESC: exit")
+                .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[FIELDS.len() + 1]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::Down => step = (step + 1) % FIELDS.len(),
+                KeyCode::BackTab | KeyCode::Up => {
+                    step = (step + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Enter => result = Some(quote(&inputs)),
+                KeyCode::Backspace => {
+                    inputs[step].pop();
+                }
+                KeyCode::Char(c) => inputs[step].push(c),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use chrono::NaiveDate;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+};
+use ratefy_lib::inflation::CpiSeries;
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use std::io;
+use std::str::FromStr;
+
+const FIELDS: [&str; 5] = [
+    "CPI file (date,value)",
+    "Amount",
+    "Currency",
+    "From date (YYYY-MM-DD)",
+    "To date (YYYY-MM-DD, empty = latest)",
+];
+
+/// Adjusts the amount between the two dates and reports cumulative and annualized inflation.
+fn adjust(inputs: &[String; 5]) -> Result<String, String> {
+    let cpi = CpiSeries::from_path(inputs[0].trim()).map_err(|e| e.to_string())?;
+    let currency = CurrencyAlpha3::from_str(inputs[2].trim())
+        .map_err(|_| format!("unknown currency '{}'", inputs[2].trim()))?;
+    let money = Money::from_str(&inputs[1], currency).ok_or("invalid amount")?;
+    let from = parse_date(&inputs[3])?;
+    let to = if inputs[4].trim().is_empty() {
+        cpi.latest_date().ok_or("the CPI series is empty")?
+    } else {
+        parse_date(&inputs[4])?
+    };
+
+    let dated = money.with_timestamp(from);
+    let adjusted = cpi.adjust(&dated, to).map_err(|e| e.to_string())?;
+    let cumulative = cpi
+        .cumulative_inflation(from, to)
+        .map_err(|e| e.to_string())?;
+    let annualized = cpi
+        .annualized_inflation(from, to)
+        .map_err(|e| e.to_string())?;
+
+    let mut report = format!(
+        "{:.2} {} on {} is worth {:.2} {} on {}\nCumulative inflation: {:.2}%\nAnnualized inflation: {:.2}%",
+        money.amount(),
+        money.currency(),
+        from,
+        adjusted.amount(),
+        adjusted.currency(),
+        to,
+        cumulative,
+        annualized
+    );
+    if let Some(warning) = dated.validity_warning() {
+        report.push_str(&format!("\nWarning: {}", warning));
+    }
+    Ok(report)
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("invalid date '{}'", value.trim()))
+}
+
+/// Handles the inflation adjustment screen
+pub fn inflation_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inputs: [String; 5] = Default::default();
+    inputs[2] = "EUR".to_string();
+    let mut step = 0;
+    let mut result: Option<Result<String, String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "Inflation Adjustment", show_border);
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(5),
+                    Constraint::Length(1),
+                ])
+                .split(viewport);
+
+            for (index, title) in FIELDS.iter().enumerate() {
+                let field = input_field(title, &inputs[index], step == index, cursor.visible);
+                f.render_widget(field, chunks[index]);
+            }
+
+            let msg = match &result {
+                Some(Ok(report)) => report.clone(),
+                Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                None => "Press Enter to compute the adjusted amount.".to_string(),
+            };
+            let result_paragraph = Paragraph::new(Text::from(msg))
+                .style(Style::default().fg(Color::White))
+                .block(Block::default().title("Output").borders(Borders::ALL));
+            f.render_widget(result_paragraph, chunks[5]);
+
+            let legend = Paragraph::new("TAB: next | Shift+TAB: prev | Enter: compute | ESC: exit")
+                .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[6]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::Down => step = (step + 1) % FIELDS.len(),
+                KeyCode::BackTab | KeyCode::Up => {
+                    step = (step + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Enter => result = Some(adjust(&inputs)),
+                KeyCode::Backspace => {
+                    inputs[step].pop();
+                }
+                KeyCode::Char(c) => inputs[step].push(c),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
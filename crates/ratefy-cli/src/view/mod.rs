@@ -1 +1,9 @@
 pub mod apply_percentage;
+pub mod brackets;
+pub mod cash_flows;
+pub mod common;
+pub mod depreciation;
+pub mod forward;
+pub mod inflation;
+pub mod position;
+pub mod remittance;
@@ -0,0 +1,215 @@
+use crate::config;
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use chrono::NaiveDate;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+};
+use ratefy_lib::fx::{CurrencyPair, PositionCalculator, RateStore, STANDARD_LOT};
+use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup, Money};
+use rust_decimal::Decimal;
+use std::io;
+use std::str::FromStr;
+
+const FIELDS: [&str; 9] = [
+    "Rates file (CSV: date,pair,rate)",
+    "Pair (e.g. EUR/USD)",
+    "Account currency (←/→: currency, PgUp/PgDn: group)",
+    "Rate date (YYYY-MM-DD, empty = latest)",
+    "Position (units of base, negative = short)",
+    "Risk (account currency)",
+    "Stop distance (pips)",
+    "Entry price",
+    "Exit price",
+];
+
+/// Index of the account currency field, which is selected rather than typed.
+const ACCOUNT_FIELD: usize = 2;
+
+/// Formats an amount with two decimals followed by its currency code.
+fn amount(money: &Money) -> String {
+    format!("{:.2} {}", money.amount(), money.currency())
+}
+
+/// Computes pip value, position size and P&L for the entered trade.
+fn calculate(inputs: &[String; 9], account: &CurrencyAlpha3) -> Result<String, String> {
+    let pair = CurrencyPair::from_str(&inputs[1]).map_err(|e| e.to_string())?;
+    let rates = if inputs[0].trim().is_empty() {
+        RateStore::new()
+    } else {
+        RateStore::from_path(inputs[0].trim()).map_err(|e| e.to_string())?
+    };
+    let date = if inputs[3].trim().is_empty() {
+        None
+    } else {
+        Some(
+            NaiveDate::parse_from_str(inputs[3].trim(), "%Y-%m-%d")
+                .map_err(|_| format!("invalid date '{}'", inputs[3].trim()))?,
+        )
+    };
+    let decimal = |index: usize| -> Result<Option<Decimal>, String> {
+        let value = inputs[index].trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        Decimal::from_str(value)
+            .map(Some)
+            .map_err(|_| format!("invalid {} '{}'", FIELDS[index], value))
+    };
+
+    let calculator = PositionCalculator::new(pair.clone(), account.clone(), &rates, date)
+        .map_err(|e| e.to_string())?;
+    let units = decimal(4)?.unwrap_or(STANDARD_LOT);
+    let pip_value = |units: Decimal| calculator.pip_value(units).map_err(|e| e.to_string());
+    let mut lines = vec![
+        format!("{} traded from a {} account", pair, account),
+        String::new(),
+        format!(
+            "Pip value ({} units): {} | per standard lot: {}",
+            units,
+            amount(&pip_value(units)?),
+            amount(&pip_value(STANDARD_LOT)?)
+        ),
+    ];
+
+    if let (Some(risk), Some(stop)) = (decimal(5)?, decimal(6)?) {
+        let risk = Money::new(risk, account.clone());
+        let size = calculator
+            .position_size(&risk, stop)
+            .map_err(|e| e.to_string())?;
+        lines.push(format!(
+            "Position size for {} at {} pips: {:.0} {} ({:.2} lots)",
+            amount(&risk),
+            stop,
+            size.amount().trunc(),
+            size.currency(),
+            size.amount() / STANDARD_LOT
+        ));
+    }
+
+    if let (Some(entry), Some(exit)) = (decimal(7)?, decimal(8)?) {
+        let pips = calculator
+            .pips_between(entry, exit)
+            .map_err(|e| e.to_string())?;
+        let pnl = calculator
+            .profit_loss(units, entry, exit)
+            .map_err(|e| e.to_string())?;
+        lines.push(format!(
+            "P&L {} → {}: {:.1} pips, {}",
+            entry,
+            exit,
+            pips,
+            amount(&pnl)
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Handles the position sizing screen
+pub fn position_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+    groups: &[CurrencyGroup],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inputs: [String; 9] = Default::default();
+    if let Some(path) = config::rates_path() {
+        inputs[0] = path.display().to_string();
+    }
+    inputs[1] = "EUR/USD".to_string();
+    let mut group_index = 0;
+    let mut currency_index = 0;
+    let mut step = 1;
+    let mut result: Option<Result<String, String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        let currencies: Vec<&CurrencyAlpha3> = groups[group_index].currencies().collect();
+        currency_index = currency_index.min(currencies.len().saturating_sub(1));
+        let account = currencies.get(currency_index).copied();
+        inputs[ACCOUNT_FIELD] = match account {
+            Some(currency) => format!("{} ({})", currency, groups[group_index].name()),
+            None => format!("– ({})", groups[group_index].name()),
+        };
+
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "Position Sizing", show_border);
+
+            let mut constraints = vec![Constraint::Length(3); FIELDS.len()];
+            constraints.push(Constraint::Min(5));
+            constraints.push(Constraint::Length(1));
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints(constraints)
+                .split(viewport);
+
+            for (index, title) in FIELDS.iter().enumerate() {
+                let field = input_field(title, &inputs[index], step == index, cursor.visible);
+                f.render_widget(field, chunks[index]);
+            }
+
+            let msg = match &result {
+                Some(Ok(report)) => report.clone(),
+                Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                None => "Press Enter to calculate.".to_string(),
+            };
+            let result_paragraph = Paragraph::new(Text::from(msg))
+                .style(Style::default().fg(Color::White))
+                .block(Block::default().title("Output").borders(Borders::ALL));
+            f.render_widget(result_paragraph, chunks[FIELDS.len()]);
+
+            let legend = Paragraph::new("TAB: next | Shift+TAB: prev | Enter: compute | ESC: exit")
+                .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[FIELDS.len() + 1]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::Down => step = (step + 1) % FIELDS.len(),
+                KeyCode::BackTab | KeyCode::Up => {
+                    step = (step + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Enter => {
+                    result = Some(match account {
+                        Some(account) => calculate(&inputs, account),
+                        None => Err("the selected group has no currencies".to_string()),
+                    });
+                }
+                KeyCode::Right if step == ACCOUNT_FIELD && !currencies.is_empty() => {
+                    currency_index = (currency_index + 1) % currencies.len();
+                }
+                KeyCode::Left if step == ACCOUNT_FIELD && !currencies.is_empty() => {
+                    currency_index = (currency_index + currencies.len() - 1) % currencies.len();
+                }
+                KeyCode::PageDown if step == ACCOUNT_FIELD => {
+                    group_index = (group_index + 1) % groups.len();
+                    currency_index = 0;
+                }
+                KeyCode::PageUp if step == ACCOUNT_FIELD => {
+                    group_index = (group_index + groups.len() - 1) % groups.len();
+                    currency_index = 0;
+                }
+                KeyCode::Backspace if step != ACCOUNT_FIELD => {
+                    inputs[step].pop();
+                }
+                KeyCode::Char(c) if step != ACCOUNT_FIELD => inputs[step].push(c),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use crate::types::layout::{HorizontalAlign, VerticalAlign};
+use crate::view::common::{CursorBlink, input_field, render_frame, viewport};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::Text,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use ratefy_lib::remittance::{RemittanceResult, compare, import};
+use std::io;
+use std::str::FromStr;
+
+const FIELDS: [&str; 4] = [
+    "Offers file (provider,pair,rate,fixed_fee,percent_fee,fee_currency,date)",
+    "Amount to send",
+    "From currency",
+    "To currency",
+];
+
+/// Loads the offers and ranks them by what the recipient receives.
+fn rank(inputs: &[String; 4]) -> Result<Vec<RemittanceResult>, String> {
+    let offers = import::from_path(inputs[0].trim()).map_err(|e| e.to_string())?;
+    let currency = |value: &str| {
+        CurrencyAlpha3::from_str(value.trim())
+            .map_err(|_| format!("unknown currency '{}'", value.trim()))
+    };
+    let amount = Money::from_str(&inputs[1], currency(&inputs[2])?).ok_or("invalid amount")?;
+    compare(&amount, &currency(&inputs[3])?, &offers).map_err(|e| e.to_string())
+}
+
+/// Builds the comparison table, best offer first.
+fn results_table(results: &[RemittanceResult]) -> Table<'static> {
+    let header = Row::new(["#", "Provider", "Rate", "Received", "Effective", "vs best"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows = results.iter().enumerate().map(|(index, r)| {
+        let received = r.received.rounded();
+        Row::new([
+            Cell::from((index + 1).to_string()),
+            Cell::from(r.provider.clone()),
+            Cell::from(format!("{:.4}", r.rate)),
+            Cell::from(format!("{} {}", received.amount(), received.currency())),
+            Cell::from(format!("{:.4}", r.effective_rate)),
+            Cell::from(if index == 0 {
+                "best".to_string()
+            } else {
+                format!("-{}", r.shortfall.rounded().amount())
+            }),
+        ])
+    });
+    Table::new(
+        rows,
+        [
+            Constraint::Length(3),
+            Constraint::Min(12),
+            Constraint::Length(10),
+            Constraint::Length(18),
+            Constraint::Length(10),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header)
+    .block(Block::default().title("Offers").borders(Borders::ALL))
+}
+
+/// Handles the remittance comparison screen
+pub fn remittance_view(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    show_border: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut inputs: [String; 4] = Default::default();
+    inputs[2] = "EUR".to_string();
+    inputs[3] = "USD".to_string();
+    let mut step = 0;
+    let mut result: Option<Result<Vec<RemittanceResult>, String>> = None;
+    let mut cursor = CursorBlink::new();
+
+    loop {
+        cursor.tick();
+        terminal.draw(|f| {
+            let viewport = viewport(f.size(), &h_align, &v_align);
+            render_frame(f, viewport, "Remittance Comparison", show_border);
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(5),
+                    Constraint::Length(1),
+                ])
+                .split(viewport);
+
+            for (index, title) in FIELDS.iter().enumerate() {
+                let field = input_field(title, &inputs[index], step == index, cursor.visible);
+                f.render_widget(field, chunks[index]);
+            }
+
+            match &result {
+                Some(Ok(results)) => f.render_widget(results_table(results), chunks[4]),
+                other => {
+                    let msg = match other {
+                        Some(Err(err_msg)) => format!("Error: {}", err_msg),
+                        _ => "Press Enter to compare the offers.".to_string(),
+                    };
+                    let paragraph = Paragraph::new(Text::from(msg))
+                        .style(Style::default().fg(Color::White))
+                        .block(Block::default().title("Offers").borders(Borders::ALL));
+                    f.render_widget(paragraph, chunks[4]);
+                }
+            }
+
+            let legend = Paragraph::new("TAB: next | Shift+TAB: prev | Enter: compare | ESC: exit")
+                .style(Style::default().fg(Color::White));
+            f.render_widget(legend, chunks[5]);
+        })?;
+
+        if event::poll(std::time::Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Esc => break,
+                KeyCode::Tab | KeyCode::Down => step = (step + 1) % FIELDS.len(),
+                KeyCode::BackTab | KeyCode::Up => {
+                    step = (step + FIELDS.len() - 1) % FIELDS.len();
+                }
+                KeyCode::Enter => result = Some(rank(&inputs)),
+                KeyCode::Backspace => {
+                    inputs[step].pop();
+                }
+                KeyCode::Char(c) => inputs[step].push(c),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
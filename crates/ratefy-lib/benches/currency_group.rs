@@ -0,0 +1,73 @@
+//! Compares the registry-backed `CurrencyGroup` lookups with the previous
+//! implementation, which rebuilt and re-parsed the ISO table on every call.
+//!
+//! Run with `cargo bench -p ratefy-lib`.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use iso_currency::Currency;
+use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+/// `CurrencyGroup::All.contains` before the registry was introduced.
+fn previous_all_contains(currency: &CurrencyAlpha3) -> bool {
+    Currency::iter()
+        .map(|c| CurrencyAlpha3::from_str(c.code()).unwrap())
+        .collect::<Vec<_>>()
+        .contains(currency)
+}
+
+/// `CurrencyGroup::G10.contains` before the registry was introduced.
+fn previous_g10_contains(currency: &CurrencyAlpha3) -> bool {
+    [
+        "USD", "EUR", "JPY", "GBP", "CHF", "CAD", "AUD", "NZD", "SEK", "NOK",
+    ]
+    .contains(&currency.code())
+}
+
+/// Filtering the All group by a search query, as the currency popup does.
+fn previous_filter(query: &str) -> usize {
+    let full_list: Vec<String> = Currency::iter().map(|c| c.code().to_string()).collect();
+    full_list
+        .iter()
+        .filter(|c| c.to_lowercase().contains(&query.to_lowercase()))
+        .count()
+}
+
+fn bench_contains(c: &mut Criterion) {
+    let zar = CurrencyAlpha3::from_str("ZAR").unwrap();
+    let nok = CurrencyAlpha3::from_str("NOK").unwrap();
+
+    let mut group = c.benchmark_group("contains");
+    group.bench_function("all/previous", |b| {
+        b.iter(|| previous_all_contains(black_box(&zar)))
+    });
+    group.bench_function("all/registry", |b| {
+        b.iter(|| CurrencyGroup::All.contains(black_box(&zar)))
+    });
+    group.bench_function("g10/previous", |b| {
+        b.iter(|| previous_g10_contains(black_box(&nok)))
+    });
+    group.bench_function("g10/registry", |b| {
+        b.iter(|| CurrencyGroup::G10.contains(black_box(&nok)))
+    });
+    group.finish();
+}
+
+fn bench_filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter_all");
+    group.bench_function("previous", |b| b.iter(|| previous_filter(black_box("u"))));
+    group.bench_function("registry", |b| {
+        b.iter(|| {
+            let query = black_box("u").to_uppercase();
+            CurrencyGroup::All
+                .codes()
+                .filter(|code| code.contains(&query))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_contains, bench_filter);
+criterion_main!(benches);
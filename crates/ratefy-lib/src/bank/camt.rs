@@ -0,0 +1,165 @@
+//! # camt.rs – ISO 20022 CAMT.053 statements
+//!
+//! Reads the booked entries (`Ntry`) of `BkToCstmrStmt` documents, whatever the
+//! message version. Each entry gives one record:
+//!
+//! - the amount is `Amt` in its `Ccy`, negative when `CdtDbtInd` is `DBIT`;
+//! - the date is the booking date (`BookgDt`), or the value date if absent;
+//! - the description is the unstructured remittance information, falling back
+//!   to `AddtlNtryInf`;
+//! - the reference is `AcctSvcrRef`;
+//! - the source is `camt053:<IBAN>` and the bank transaction code
+//!   (`PMNT/RCDT/ESCT` or a proprietary code) becomes a tag.
+//!
+//! Entries whose status is not booked (`PDNG`, `INFO`) are skipped.
+
+use super::error::BankError;
+use super::record::BankRecord;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+use rust_decimal::Decimal;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Returns the first child element named `name`, whatever its namespace.
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Follows a path of child element names.
+fn path<'a, 'input>(node: Node<'a, 'input>, names: &[&str]) -> Option<Node<'a, 'input>> {
+    names.iter().try_fold(node, |node, name| child(node, name))
+}
+
+/// Returns the trimmed text of the element at `names`, if present and non-empty.
+fn text<'a>(node: Node<'a, '_>, names: &[&str]) -> Option<&'a str> {
+    path(node, names)
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+}
+
+/// Parses the entries of a CAMT.053 document.
+pub fn parse(content: &str) -> Result<Vec<BankRecord>, BankError> {
+    let document = Document::parse(content).map_err(|e| BankError::Parse {
+        line: e.pos().row as usize,
+        message: e.to_string(),
+    })?;
+    let mut records = Vec::new();
+    let statements = document
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "Stmt");
+    for statement in statements {
+        let account = text(statement, &["Acct", "Id", "IBAN"])
+            .or_else(|| text(statement, &["Acct", "Id", "Othr", "Id"]))
+            .unwrap_or_default();
+        let source = if account.is_empty() {
+            "camt053".to_string()
+        } else {
+            format!("camt053:{}", account)
+        };
+        let entries = statement
+            .children()
+            .filter(|n| n.is_element() && n.tag_name().name() == "Ntry");
+        for entry in entries {
+            let line = document.text_pos_at(entry.range().start).row as usize;
+            if let Some(record) = entry_record(entry, line, &source)? {
+                records.push(record);
+            }
+        }
+    }
+    Ok(records)
+}
+
+fn entry_record(entry: Node, line: usize, source: &str) -> Result<Option<BankRecord>, BankError> {
+    let parse_error = |message: String| BankError::Parse { line, message };
+
+    // Version 2 writes the status as text, later versions wrap it in <Cd>.
+    let status = text(entry, &["Sts", "Cd"]).or_else(|| text(entry, &["Sts"]));
+    if status.is_some_and(|s| s != "BOOK") {
+        return Ok(None);
+    }
+
+    let amount_node = child(entry, "Amt").ok_or_else(|| parse_error("missing Amt".to_string()))?;
+    let amount_text = amount_node.text().unwrap_or_default().trim();
+    let mut amount = Decimal::from_str(amount_text)
+        .map_err(|_| parse_error(format!("invalid amount '{}'", amount_text)))?;
+    match text(entry, &["CdtDbtInd"]) {
+        Some("DBIT") => amount = -amount,
+        Some("CRDT") => {}
+        other => {
+            return Err(parse_error(format!(
+                "invalid CdtDbtInd '{}'",
+                other.unwrap_or_default()
+            )));
+        }
+    }
+    let code = amount_node.attribute("Ccy").unwrap_or_default();
+    let currency = CurrencyAlpha3::from_str(code)
+        .map_err(|_| parse_error(format!("unknown currency '{}'", code)))?;
+
+    let date_text = ["BookgDt", "ValDt"]
+        .iter()
+        .find_map(|name| text(entry, &[name, "Dt"]).or_else(|| text(entry, &[name, "DtTm"])))
+        .ok_or_else(|| parse_error("missing booking date".to_string()))?;
+    let date = date_text
+        .get(..10)
+        .and_then(|day| NaiveDate::parse_from_str(day, "%Y-%m-%d").ok())
+        .ok_or_else(|| parse_error(format!("invalid date '{}'", date_text)))?;
+
+    let remittance: Vec<&str> = entry
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "Ustrd")
+        .filter_map(|n| n.text().map(str::trim))
+        .filter(|t| !t.is_empty())
+        .collect();
+    let description = if remittance.is_empty() {
+        text(entry, &["AddtlNtryInf"])
+            .unwrap_or_default()
+            .to_string()
+    } else {
+        remittance.join(" / ")
+    };
+
+    let domain = ["Domn", "Cd"];
+    let family = ["Domn", "Fmly", "Cd"];
+    let sub_family = ["Domn", "Fmly", "SubFmlyCd"];
+    let bank_code = child(entry, "BkTxCd").and_then(|code| {
+        let parts: Option<Vec<&str>> = [&domain[..], &family[..], &sub_family[..]]
+            .iter()
+            .map(|p| text(code, p))
+            .collect();
+        parts
+            .map(|p| p.join("/"))
+            .or_else(|| text(code, &["Prtry", "Cd"]).map(str::to_string))
+    });
+    let tags: Vec<String> = bank_code.into_iter().collect();
+
+    Ok(Some(BankRecord::new(
+        date,
+        Money::new(amount, currency),
+        description,
+        text(entry, &["AcctSvcrRef"]).map(str::to_string),
+        source,
+        &tags,
+    )))
+}
+
+/// Reads the entries of a CAMT.053 document from any source.
+pub fn from_reader<R: Read>(mut reader: R) -> Result<Vec<BankRecord>, BankError> {
+    let mut content = String::new();
+    reader
+        .read_to_string(&mut content)
+        .map_err(|e| BankError::Io(e.to_string()))?;
+    parse(&content)
+}
+
+/// Reads the entries of a CAMT.053 file on disk.
+pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Vec<BankRecord>, BankError> {
+    let content = fs::read_to_string(path).map_err(|e| BankError::Io(e.to_string()))?;
+    parse(&content)
+}
@@ -0,0 +1,284 @@
+//! # csv.rs – Bank CSV exports with a column mapping
+//!
+//! Every bank lays out its CSV export differently. A [`CsvMapping`] names the
+//! columns holding the booking date, the amount (signed, or split into debit and
+//! credit columns), the currency, the description, the bank reference and the
+//! columns copied into tags. Mappings are usually kept in TOML:
+//!
+//! ```toml
+//! date = "Booking date"
+//! date_format = "%d.%m.%Y"
+//! debit = "Debit"
+//! credit = "Credit"
+//! default_currency = "CHF"
+//! description = ["Payee", "Details"]
+//! tags = ["Category"]
+//! delimiter = ";"
+//! decimal_comma = true
+//! ```
+//!
+//! With split columns an amount is the credit minus the debit, so exports that
+//! fill the unused side with `0,00` are read correctly.
+
+use super::error::BankError;
+use super::record::BankRecord;
+use crate::csv_lines::{self, LineTracker};
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Columns of a bank CSV export and how to read them.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CsvMapping {
+    /// Column of the booking date.
+    pub date: String,
+    /// `chrono` format of the dates (default `%Y-%m-%d`).
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// Column of the signed amount (negative for debits).
+    #[serde(default)]
+    pub amount: Option<String>,
+    /// Column of debited amounts, used when there is no signed amount column.
+    #[serde(default)]
+    pub debit: Option<String>,
+    /// Column of credited amounts, used when there is no signed amount column.
+    #[serde(default)]
+    pub credit: Option<String>,
+    /// Column of the currency code.
+    #[serde(default)]
+    pub currency: Option<String>,
+    /// Currency of every amount when there is no currency column.
+    #[serde(default)]
+    pub default_currency: Option<String>,
+    /// Columns joined with " / " into the description.
+    #[serde(default)]
+    pub description: Vec<String>,
+    /// Column of the bank's transaction reference.
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// Columns whose non-empty values become tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Field delimiter (default `,`).
+    #[serde(default = "default_delimiter")]
+    pub delimiter: char,
+    /// Amounts are written `1.234,56` instead of `1,234.56`.
+    #[serde(default)]
+    pub decimal_comma: bool,
+}
+
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_string()
+}
+
+fn default_delimiter() -> char {
+    ','
+}
+
+impl CsvMapping {
+    /// Create a mapping reading a signed `amount` column and a `date` column in
+    /// `YYYY-MM-DD` format; other settings are left at their defaults.
+    pub fn new(date: &str, amount: &str) -> Self {
+        Self {
+            date: date.to_string(),
+            date_format: default_date_format(),
+            amount: Some(amount.to_string()),
+            debit: None,
+            credit: None,
+            currency: None,
+            default_currency: None,
+            description: Vec::new(),
+            reference: None,
+            tags: Vec::new(),
+            delimiter: default_delimiter(),
+            decimal_comma: false,
+        }
+    }
+
+    /// Parses a mapping from TOML.
+    pub fn from_toml(content: &str) -> Result<Self, BankError> {
+        toml::from_str(content).map_err(|e| BankError::Mapping(e.to_string()))
+    }
+
+    /// Reads a mapping from a TOML file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, BankError> {
+        let content = fs::read_to_string(path).map_err(|e| BankError::Io(e.to_string()))?;
+        Self::from_toml(&content)
+    }
+
+    /// Parses an amount written with this mapping's separators; empty cells are `None`.
+    fn parse_amount(&self, text: &str) -> Option<Result<Decimal, ()>> {
+        let cleaned: String = text
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '\'')
+            .collect();
+        if cleaned.is_empty() {
+            return None;
+        }
+        let normalized = if self.decimal_comma {
+            cleaned.replace('.', "").replace(',', ".")
+        } else {
+            cleaned.replace(',', "")
+        };
+        Some(Decimal::from_str(&normalized).map_err(|_| ()))
+    }
+}
+
+/// Column positions resolved from the header.
+struct Columns {
+    date: usize,
+    amount: Option<usize>,
+    debit: Option<usize>,
+    credit: Option<usize>,
+    currency: Option<usize>,
+    description: Vec<usize>,
+    reference: Option<usize>,
+    tags: Vec<usize>,
+}
+
+impl Columns {
+    fn resolve(mapping: &CsvMapping, header: &::csv::StringRecord) -> Result<Self, BankError> {
+        let find = |name: &str| {
+            header
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| BankError::Mapping(format!("no column named '{}'", name)))
+        };
+        let optional = |name: &Option<String>| name.as_deref().map(find).transpose();
+        let columns = Self {
+            date: find(&mapping.date)?,
+            amount: optional(&mapping.amount)?,
+            debit: optional(&mapping.debit)?,
+            credit: optional(&mapping.credit)?,
+            currency: optional(&mapping.currency)?,
+            description: mapping
+                .description
+                .iter()
+                .map(|n| find(n))
+                .collect::<Result<_, _>>()?,
+            reference: optional(&mapping.reference)?,
+            tags: mapping
+                .tags
+                .iter()
+                .map(|n| find(n))
+                .collect::<Result<_, _>>()?,
+        };
+        if columns.amount.is_none() && columns.debit.is_none() && columns.credit.is_none() {
+            return Err(BankError::Mapping(
+                "an amount, debit or credit column is required".to_string(),
+            ));
+        }
+        if columns.currency.is_none() && mapping.default_currency.is_none() {
+            return Err(BankError::Mapping(
+                "a currency column or a default currency is required".to_string(),
+            ));
+        }
+        Ok(columns)
+    }
+}
+
+/// Reads the transactions of a bank CSV export, recording `source` (e.g. the
+/// account name) on every amount.
+pub fn from_reader<R: Read>(
+    reader: R,
+    mapping: &CsvMapping,
+    source: &str,
+) -> Result<Vec<BankRecord>, BankError> {
+    let delimiter = u8::try_from(mapping.delimiter).map_err(|_| {
+        BankError::Mapping(format!("unsupported delimiter '{}'", mapping.delimiter))
+    })?;
+    let mut csv_reader = ::csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(::csv::Trim::All)
+        .flexible(true)
+        .from_reader(LineTracker::new(reader));
+    let header = csv_reader
+        .headers()
+        .map_err(|e| BankError::Io(e.to_string()))?
+        .clone();
+    let columns = Columns::resolve(mapping, &header)?;
+    let default_currency = match &mapping.default_currency {
+        Some(code) => Some(
+            CurrencyAlpha3::from_str(code)
+                .map_err(|_| BankError::Mapping(format!("unknown currency '{}'", code)))?,
+        ),
+        None => None,
+    };
+
+    let mut records = Vec::new();
+    let mut row = ::csv::StringRecord::new();
+    while csv_reader
+        .read_record(&mut row)
+        .map_err(|e| BankError::Parse {
+            line: csv_lines::line(&mut csv_reader, e.position()),
+            message: e.to_string(),
+        })?
+    {
+        let line = csv_lines::line(&mut csv_reader, row.position());
+        let parse_error = |message: String| BankError::Parse { line, message };
+        let cell = |column: usize| row.get(column).unwrap_or_default();
+        if row.iter().all(|c| c.is_empty()) {
+            continue;
+        }
+
+        let date = NaiveDate::parse_from_str(cell(columns.date), &mapping.date_format)
+            .map_err(|_| parse_error(format!("invalid date '{}'", cell(columns.date))))?;
+        let amount_in = |column: Option<usize>| -> Result<Option<Decimal>, BankError> {
+            let Some(column) = column else {
+                return Ok(None);
+            };
+            mapping
+                .parse_amount(cell(column))
+                .transpose()
+                .map_err(|_| parse_error(format!("invalid amount '{}'", cell(column))))
+        };
+        let amount = match amount_in(columns.amount)? {
+            Some(amount) => amount,
+            None => match (amount_in(columns.debit)?, amount_in(columns.credit)?) {
+                (None, None) => return Err(parse_error("no amount".to_string())),
+                (debit, credit) => {
+                    credit.unwrap_or_default().abs() - debit.unwrap_or_default().abs()
+                }
+            },
+        };
+        let currency = match columns.currency.map(cell).filter(|c| !c.is_empty()) {
+            Some(code) => CurrencyAlpha3::from_str(code)
+                .map_err(|_| parse_error(format!("unknown currency '{}'", code)))?,
+            None => default_currency
+                .clone()
+                .ok_or_else(|| parse_error("no currency".to_string()))?,
+        };
+        let description = columns
+            .description
+            .iter()
+            .map(|&c| cell(c))
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let tags: Vec<String> = columns.tags.iter().map(|&c| cell(c).to_string()).collect();
+        records.push(BankRecord::new(
+            date,
+            Money::new(amount, currency),
+            description,
+            columns.reference.map(|c| cell(c).to_string()),
+            source,
+            &tags,
+        ));
+    }
+    Ok(records)
+}
+
+/// Reads the transactions of a bank CSV export on disk.
+pub fn from_path<P: AsRef<Path>>(
+    path: P,
+    mapping: &CsvMapping,
+    source: &str,
+) -> Result<Vec<BankRecord>, BankError> {
+    let file = File::open(path).map_err(|e| BankError::Io(e.to_string()))?;
+    from_reader(file, mapping, source)
+}
@@ -0,0 +1,64 @@
+use super::record::BankRecord;
+use std::collections::HashMap;
+
+/// Records of an import split into those not seen before and the duplicates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportOutcome {
+    pub new: Vec<BankRecord>,
+    pub duplicates: Vec<BankRecord>,
+}
+
+/// Remembers imported records to skip them when a statement is imported again.
+///
+/// Identical records within one statement (two equal card payments on the same
+/// day) are all kept: a record is a duplicate only if it was already imported at
+/// least as many times as it appears so far in the new batch.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Deduplicator {
+    seen: HashMap<String, usize>,
+}
+
+impl Deduplicator {
+    /// Create a deduplicator that has seen nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a deduplicator that has seen `records`, e.g. from a previous import.
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a BankRecord>) -> Self {
+        let mut deduplicator = Self::new();
+        for record in records {
+            *deduplicator.seen.entry(record.fingerprint()).or_default() += 1;
+        }
+        deduplicator
+    }
+
+    /// Returns the number of transactions seen.
+    pub fn len(&self) -> usize {
+        self.seen.values().sum()
+    }
+
+    /// Returns `true` if nothing was seen yet.
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Splits `records` into new ones and duplicates, and remembers the new ones.
+    pub fn import(&mut self, records: Vec<BankRecord>) -> ImportOutcome {
+        let mut in_batch: HashMap<String, usize> = HashMap::new();
+        let mut outcome = ImportOutcome::default();
+        for record in records {
+            let fingerprint = record.fingerprint();
+            let occurrence = in_batch.entry(fingerprint.clone()).or_default();
+            *occurrence += 1;
+            let seen = self.seen.entry(fingerprint).or_default();
+            if *occurrence <= *seen {
+                outcome.duplicates.push(record);
+            } else {
+                *seen += 1;
+                outcome.new.push(record);
+            }
+        }
+        outcome
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+/// Errors raised while importing bank statements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BankError {
+    /// The CSV column mapping is incomplete or refers to missing columns.
+    Mapping(String),
+    /// A record could not be parsed (1-based line number and reason).
+    Parse { line: usize, message: String },
+    /// The statement could not be read.
+    Io(String),
+}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BankError::Mapping(message) => write!(f, "invalid column mapping: {}", message),
+            BankError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            BankError::Io(message) => write!(f, "could not read statement: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for BankError {}
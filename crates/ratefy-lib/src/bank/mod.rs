@@ -0,0 +1,11 @@
+pub mod camt;
+pub mod csv;
+mod dedup;
+mod error;
+pub mod ofx;
+mod record;
+
+pub use csv::CsvMapping;
+pub use dedup::{Deduplicator, ImportOutcome};
+pub use error::BankError;
+pub use record::BankRecord;
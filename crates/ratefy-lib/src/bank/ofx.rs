@@ -0,0 +1,195 @@
+//! # ofx.rs – OFX and QFX statements
+//!
+//! Reads the `STMTTRN` transactions of OFX downloads (QFX is the same format).
+//! Both OFX 1.x, an SGML dialect where elements have no closing tag, and the
+//! XML-based OFX 2.x are accepted:
+//!
+//! ```text
+//! <STMTRS><CURDEF>USD
+//! <BANKACCTFROM><BANKID>121000248<ACCTID>123456789<ACCTTYPE>CHECKING</BANKACCTFROM>
+//! <BANKTRANLIST>
+//! <STMTTRN><TRNTYPE>DEBIT<DTPOSTED>20240105<TRNAMT>-42.50<FITID>2024010501<NAME>GROCERY</STMTTRN>
+//! </BANKTRANLIST></STMTRS>
+//! ```
+//!
+//! Amounts are in the statement's `CURDEF`, sources are `ofx:<ACCTID>` and the
+//! transaction type (`debit`, `atm`, `xfer`…) becomes a tag.
+//!
+//! Files are decoded as UTF-8 unless their header declares Windows-1252 or
+//! Latin-1 (`CHARSET:1252`, as most US banks send OFX 1.x).
+
+use super::error::BankError;
+use super::record::BankRecord;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// An element of the document: its name (upper case, `/NAME` when closing),
+/// the text that follows it and its 1-based line.
+struct Token<'a> {
+    name: String,
+    text: &'a str,
+    line: usize,
+}
+
+fn tokenize(content: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut rest = content;
+    while let Some(start) = rest.find('<') {
+        line += rest[..start].matches('\n').count();
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('>') else {
+            break;
+        };
+        let name = after[..end].trim().to_uppercase();
+        let body = &after[end + 1..];
+        let text_end = body.find('<').unwrap_or(body.len());
+        tokens.push(Token {
+            name,
+            text: body[..text_end].trim(),
+            line,
+        });
+        line += after[..end].matches('\n').count();
+        rest = body;
+    }
+    tokens
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Parses the transactions of an OFX or QFX document.
+pub fn parse(content: &str) -> Result<Vec<BankRecord>, BankError> {
+    let mut records = Vec::new();
+    let mut currency: Option<CurrencyAlpha3> = None;
+    let mut account = String::new();
+    let mut transaction: Option<(usize, HashMap<String, String>)> = None;
+
+    for token in tokenize(content) {
+        match token.name.as_str() {
+            "CURDEF" => {
+                currency =
+                    Some(
+                        CurrencyAlpha3::from_str(token.text).map_err(|_| BankError::Parse {
+                            line: token.line,
+                            message: format!("unknown currency '{}'", token.text),
+                        })?,
+                    );
+            }
+            "ACCTID" => account = unescape(token.text),
+            "STMTTRN" => transaction = Some((token.line, HashMap::new())),
+            "/STMTTRN" => {
+                if let Some((line, fields)) = transaction.take() {
+                    records.push(record(line, &fields, currency.as_ref(), &account)?);
+                }
+            }
+            name if !name.starts_with('/') => {
+                if let Some((_, fields)) = transaction.as_mut() {
+                    fields.insert(token.name.clone(), unescape(token.text));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(records)
+}
+
+fn record(
+    line: usize,
+    fields: &HashMap<String, String>,
+    currency: Option<&CurrencyAlpha3>,
+    account: &str,
+) -> Result<BankRecord, BankError> {
+    let parse_error = |message: String| BankError::Parse { line, message };
+    let field = |name: &str| fields.get(name).map(String::as_str).unwrap_or_default();
+
+    let posted = field("DTPOSTED");
+    let date = posted
+        .get(..8)
+        .and_then(|day| NaiveDate::parse_from_str(day, "%Y%m%d").ok())
+        .ok_or_else(|| parse_error(format!("invalid DTPOSTED '{}'", posted)))?;
+    let amount = Decimal::from_str(&field("TRNAMT").replace(',', "."))
+        .map_err(|_| parse_error(format!("invalid TRNAMT '{}'", field("TRNAMT"))))?;
+    let currency = currency
+        .cloned()
+        .ok_or_else(|| parse_error("transaction before CURDEF".to_string()))?;
+
+    let mut description = field("NAME").to_string();
+    let memo = field("MEMO");
+    if !memo.is_empty() && memo != description {
+        if !description.is_empty() {
+            description.push_str(" / ");
+        }
+        description.push_str(memo);
+    }
+    let source = if account.is_empty() {
+        "ofx".to_string()
+    } else {
+        format!("ofx:{}", account)
+    };
+    let tags = [field("TRNTYPE").to_lowercase()];
+    Ok(BankRecord::new(
+        date,
+        Money::new(amount, currency),
+        description,
+        Some(field("FITID").to_string()),
+        &source,
+        &tags,
+    ))
+}
+
+/// Characters of Windows-1252 for bytes 0x80 to 0x9F, which are control codes
+/// in Latin-1; the five unassigned bytes keep their Latin-1 meaning.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// Decodes a document as Windows-1252 if the header before `<OFX>` declares it
+/// or Latin-1 (a superset for printable text), as UTF-8 otherwise.
+fn decode(bytes: Vec<u8>) -> Result<String, BankError> {
+    let header_end = bytes
+        .windows(4)
+        .position(|w| w.eq_ignore_ascii_case(b"<OFX"))
+        .unwrap_or(bytes.len());
+    let header = String::from_utf8_lossy(&bytes[..header_end]).to_uppercase();
+    let single_byte = ["CHARSET:1252", "8859-1", "WINDOWS-1252"]
+        .iter()
+        .any(|charset| header.contains(charset));
+    if single_byte {
+        return Ok(bytes
+            .iter()
+            .map(|&byte| match byte {
+                0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+                _ => char::from(byte),
+            })
+            .collect());
+    }
+    String::from_utf8(bytes).map_err(|e| BankError::Io(e.to_string()))
+}
+
+/// Reads the transactions of an OFX or QFX document from any source.
+pub fn from_reader<R: Read>(mut reader: R) -> Result<Vec<BankRecord>, BankError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|e| BankError::Io(e.to_string()))?;
+    parse(&decode(bytes)?)
+}
+
+/// Reads the transactions of an OFX or QFX file on disk.
+pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Vec<BankRecord>, BankError> {
+    let bytes = fs::read(path).map_err(|e| BankError::Io(e.to_string()))?;
+    parse(&decode(bytes)?)
+}
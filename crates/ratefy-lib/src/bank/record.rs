@@ -0,0 +1,66 @@
+use crate::money::Money;
+use chrono::NaiveDate;
+
+#[doc = include_str!("../../docs/types/bank_record.md")]
+/// One booked transaction of a bank statement.
+///
+/// The amount is positive for credits and negative for debits. It is dated with
+/// the booking date, its source names the statement account, and its tags carry
+/// the bank's transaction type or category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BankRecord {
+    pub date: NaiveDate,
+    pub money: Money,
+    pub description: String,
+    /// Identifier assigned by the bank (OFX `FITID`, CAMT `AcctSvcrRef`…), if any.
+    pub reference: Option<String>,
+}
+
+impl BankRecord {
+    /// Builds a record, dating the amount and recording its source and tags.
+    pub(crate) fn new(
+        date: NaiveDate,
+        money: Money,
+        description: String,
+        reference: Option<String>,
+        source: &str,
+        tags: &[String],
+    ) -> Self {
+        let mut money = money.with_timestamp(date);
+        if !source.is_empty() {
+            money = money.with_source(source);
+        }
+        for tag in tags.iter().filter(|t| !t.is_empty()) {
+            money = money.with_tag(tag);
+        }
+        Self {
+            date,
+            money,
+            description,
+            reference: reference.filter(|r| !r.is_empty()),
+        }
+    }
+
+    /// Key identifying the transaction across imports of the same account.
+    ///
+    /// The bank reference is used when present; otherwise the date, amount,
+    /// currency and description identify the transaction.
+    pub fn fingerprint(&self) -> String {
+        let source = self.money.source().unwrap_or_default();
+        match &self.reference {
+            Some(reference) => format!("{}|ref|{}", source, reference),
+            None => format!(
+                "{}|{}|{}|{}|{}",
+                source,
+                self.date,
+                self.money.amount().normalize(),
+                self.money.currency(),
+                self.description
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase()
+            ),
+        }
+    }
+}
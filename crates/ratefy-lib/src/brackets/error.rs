@@ -0,0 +1,57 @@
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// Errors raised while defining or applying bracket schedules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BracketError {
+    /// A schedule needs at least one bracket.
+    Empty(String),
+    /// Thresholds must be non-negative and strictly increasing.
+    InvalidThreshold { schedule: String, from: Decimal },
+    /// Rates must lie between -100% and 100%.
+    InvalidRate { schedule: String, rate: Decimal },
+    /// The currency code of a schedule is unknown.
+    UnknownCurrency(String),
+    /// The amount is not in the schedule's currency.
+    CurrencyMismatch { expected: String, found: String },
+    /// Brackets only apply to non-negative amounts.
+    NegativeAmount(Decimal),
+    /// The amount is too large to apply the schedule to.
+    Overflow(Decimal),
+    /// The configuration file is not valid TOML or does not match the expected layout.
+    Parse(String),
+    /// The configuration file could not be read.
+    Io(String),
+}
+
+impl fmt::Display for BracketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BracketError::Empty(name) => write!(f, "schedule '{}' has no brackets", name),
+            BracketError::InvalidThreshold { schedule, from } => write!(
+                f,
+                "threshold {} of schedule '{}' is negative or out of order",
+                from, schedule
+            ),
+            BracketError::InvalidRate { schedule, rate } => write!(
+                f,
+                "rate {}% of schedule '{}' is not between -100% and 100%",
+                rate, schedule
+            ),
+            BracketError::UnknownCurrency(code) => write!(f, "unknown currency '{}'", code),
+            BracketError::CurrencyMismatch { expected, found } => {
+                write!(f, "expected an amount in {}, got {}", expected, found)
+            }
+            BracketError::NegativeAmount(amount) => {
+                write!(f, "amount must not be negative, got {}", amount)
+            }
+            BracketError::Overflow(amount) => {
+                write!(f, "amount {} is too large for the schedule", amount)
+            }
+            BracketError::Parse(message) => write!(f, "invalid brackets file: {}", message),
+            BracketError::Io(message) => write!(f, "could not read brackets: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for BracketError {}
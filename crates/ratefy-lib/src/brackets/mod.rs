@@ -0,0 +1,5 @@
+mod error;
+pub mod schedule;
+
+pub use error::BracketError;
+pub use schedule::{Bracket, BracketLine, BracketResult, BracketSchedule};
@@ -0,0 +1,215 @@
+//! # schedule.rs – Progressive bracket schedules
+//!
+//! A [`BracketSchedule`] applies marginal rates over thresholds: each bracket's
+//! rate only applies to the part of the amount between its threshold and the
+//! next one. The same mechanism covers income tax, tiered commissions and
+//! volume discounts (with negative rates). Schedules are usually kept in TOML:
+//!
+//! ```toml
+//! [[schedule]]
+//! name = "Income tax"
+//! currency = "EUR"
+//! brackets = [
+//!     { from = 0, rate = 0 },
+//!     { from = 12000, rate = 20 },
+//!     { from = 50000, rate = 40 },
+//! ]
+//! ```
+//!
+//! Usage examples are available in
+//! [`docs/types/bracket_schedule.md`](../../docs/types/bracket_schedule.md).
+
+use super::error::BracketError;
+use crate::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A rate (in percent) applying from a threshold up to the next bracket's threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Bracket {
+    pub from: Decimal,
+    pub rate: Decimal,
+}
+
+impl Bracket {
+    /// Create a bracket starting at `from` with a rate in percent.
+    pub fn new(from: Decimal, rate: Decimal) -> Self {
+        Self { from, rate }
+    }
+}
+
+/// Part of an amount falling into one bracket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketLine {
+    pub from: Money,
+    /// Upper threshold, or `None` for the top bracket.
+    pub to: Option<Money>,
+    pub rate: Decimal,
+    /// Part of the amount falling into the bracket.
+    pub base: Money,
+    /// `base` times the rate.
+    pub amount: Money,
+}
+
+/// Outcome of applying a schedule to an amount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketResult {
+    pub amount: Money,
+    /// One line per bracket the amount reaches.
+    pub lines: Vec<BracketLine>,
+    pub total: Money,
+    /// Total as a percentage of the amount.
+    pub effective_rate: Decimal,
+    /// Rate applying to the next unit of the amount.
+    pub marginal_rate: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+struct SchedulesFile {
+    #[serde(default)]
+    schedule: Vec<ScheduleEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScheduleEntry {
+    name: String,
+    currency: String,
+    brackets: Vec<Bracket>,
+}
+
+#[doc = include_str!("../../docs/types/bracket_schedule.md")]
+/// Marginal rates over increasing thresholds, in one currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketSchedule {
+    name: String,
+    currency: CurrencyAlpha3,
+    brackets: Vec<Bracket>,
+}
+
+impl BracketSchedule {
+    /// Create a schedule; thresholds must be non-negative and strictly increasing,
+    /// and rates between -100% and 100%.
+    ///
+    /// Amounts below the first threshold are not charged.
+    pub fn new(
+        name: &str,
+        currency: CurrencyAlpha3,
+        brackets: Vec<Bracket>,
+    ) -> Result<Self, BracketError> {
+        if brackets.is_empty() {
+            return Err(BracketError::Empty(name.to_string()));
+        }
+        let mut previous: Option<Decimal> = None;
+        for bracket in &brackets {
+            if bracket.from < Decimal::ZERO || previous.is_some_and(|p| bracket.from <= p) {
+                return Err(BracketError::InvalidThreshold {
+                    schedule: name.to_string(),
+                    from: bracket.from,
+                });
+            }
+            if bracket.rate.abs() > Decimal::ONE_HUNDRED {
+                return Err(BracketError::InvalidRate {
+                    schedule: name.to_string(),
+                    rate: bracket.rate,
+                });
+            }
+            previous = Some(bracket.from);
+        }
+        Ok(Self {
+            name: name.to_string(),
+            currency,
+            brackets,
+        })
+    }
+
+    /// Parses schedules from TOML (see the module documentation for the layout).
+    pub fn from_toml(content: &str) -> Result<Vec<Self>, BracketError> {
+        let file: SchedulesFile =
+            toml::from_str(content).map_err(|e| BracketError::Parse(e.to_string()))?;
+        file.schedule
+            .into_iter()
+            .map(|entry| {
+                let currency = CurrencyAlpha3::from_str(&entry.currency)
+                    .map_err(|_| BracketError::UnknownCurrency(entry.currency.clone()))?;
+                Self::new(&entry.name, currency, entry.brackets)
+            })
+            .collect()
+    }
+
+    /// Loads schedules from a TOML file (see [`BracketSchedule::from_toml`]).
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Self>, BracketError> {
+        let content = fs::read_to_string(path).map_err(|e| BracketError::Io(e.to_string()))?;
+        Self::from_toml(&content)
+    }
+
+    /// Returns the schedule name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the currency of the thresholds.
+    pub fn currency(&self) -> &CurrencyAlpha3 {
+        &self.currency
+    }
+
+    /// Returns the brackets, lowest threshold first.
+    pub fn brackets(&self) -> &[Bracket] {
+        &self.brackets
+    }
+
+    /// Applies the schedule to `amount`, bracket by bracket.
+    pub fn apply(&self, amount: &Money) -> Result<BracketResult, BracketError> {
+        if amount.currency() != &self.currency {
+            return Err(BracketError::CurrencyMismatch {
+                expected: self.currency.to_string(),
+                found: amount.currency().to_string(),
+            });
+        }
+        let value = amount.amount();
+        if value < Decimal::ZERO {
+            return Err(BracketError::NegativeAmount(value));
+        }
+        let money = |v: Decimal| Money::new(v, self.currency.clone());
+        let overflow = || BracketError::Overflow(value);
+
+        let mut lines = Vec::new();
+        let mut marginal_rate = Decimal::ZERO;
+        for (index, bracket) in self.brackets.iter().enumerate() {
+            if value < bracket.from {
+                break;
+            }
+            let to = self.brackets.get(index + 1).map(|next| next.from);
+            marginal_rate = bracket.rate;
+            let base = to.map_or(value, |to| value.min(to)) - bracket.from;
+            let charged =
+                base.checked_mul(bracket.rate).ok_or_else(overflow)? / Decimal::ONE_HUNDRED;
+            lines.push(BracketLine {
+                from: money(bracket.from),
+                to: to.map(money),
+                rate: bracket.rate,
+                base: money(base),
+                amount: money(charged),
+            });
+        }
+        let total = lines.iter().try_fold(Decimal::ZERO, |total, line| {
+            total.checked_add(line.amount.amount()).ok_or_else(overflow)
+        })?;
+        let effective_rate = if value.is_zero() {
+            Decimal::ZERO
+        } else {
+            (total / value)
+                .checked_mul(Decimal::ONE_HUNDRED)
+                .ok_or_else(overflow)?
+        };
+        Ok(BracketResult {
+            amount: amount.clone(),
+            lines,
+            total: amount.with_amount_in(total, self.currency.clone()),
+            effective_rate,
+            marginal_rate,
+        })
+    }
+}
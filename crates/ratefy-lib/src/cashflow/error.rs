@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// Errors raised while importing or evaluating a set of cash flows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CashFlowError {
+    /// No cash flows were provided.
+    Empty,
+    /// The cash flows are not all expressed in the same currency.
+    MixedCurrencies,
+    /// IRR/XIRR need at least one positive and one negative cash flow.
+    NoSignChange,
+    /// The discount rate is -100% or lower, so the discount factor is not positive.
+    InvalidRate,
+    /// The root finder did not converge within the allowed iterations.
+    NoConvergence { iterations: usize },
+    /// An intermediate value did not fit in a `Decimal`.
+    Overflow,
+    /// A CSV record could not be parsed (1-based line number and reason).
+    Parse { line: usize, message: String },
+    /// The CSV source could not be read.
+    Io(String),
+}
+
+impl fmt::Display for CashFlowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CashFlowError::Empty => write!(f, "no cash flows provided"),
+            CashFlowError::MixedCurrencies => {
+                write!(f, "cash flows must share a single currency")
+            }
+            CashFlowError::NoSignChange => write!(
+                f,
+                "cash flows need at least one positive and one negative amount"
+            ),
+            CashFlowError::InvalidRate => write!(f, "discount rate must be above -100%"),
+            CashFlowError::NoConvergence { iterations } => {
+                write!(f, "rate did not converge after {} iterations", iterations)
+            }
+            CashFlowError::Overflow => write!(f, "arithmetic overflow while discounting"),
+            CashFlowError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            CashFlowError::Io(message) => write!(f, "could not read cash flows: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for CashFlowError {}
@@ -0,0 +1,71 @@
+//! # import.rs – CSV import of cash flows
+//!
+//! Reads cash flows from CSV with a `date,amount,currency` header, dates in
+//! `YYYY-MM-DD` format and amounts as plain decimals (negative for payments):
+//!
+//! ```text
+//! date,amount,currency
+//! 2024-01-01,-1000.00,EUR
+//! 2024-07-01,550.00,EUR
+//! ```
+
+use super::{CashFlow, CashFlowError};
+use crate::csv_lines::{self, LineTracker};
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+struct CashFlowRecord {
+    date: String,
+    amount: String,
+    currency: String,
+}
+
+/// Reads cash flows from any CSV source.
+pub fn from_reader<R: Read>(reader: R) -> Result<Vec<CashFlow>, CashFlowError> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(LineTracker::new(reader));
+    let headers = csv_reader
+        .headers()
+        .map_err(|e| CashFlowError::Parse {
+            line: 1,
+            message: e.to_string(),
+        })?
+        .clone();
+    let mut flows = Vec::new();
+    let mut row = csv::StringRecord::new();
+    while csv_reader
+        .read_record(&mut row)
+        .map_err(|e| CashFlowError::Parse {
+            line: csv_lines::line(&mut csv_reader, e.position()),
+            message: e.to_string(),
+        })?
+    {
+        let line = csv_lines::line(&mut csv_reader, row.position());
+        let parse_error = |message: String| CashFlowError::Parse { line, message };
+        let record: CashFlowRecord = row
+            .deserialize(Some(&headers))
+            .map_err(|e| parse_error(e.to_string()))?;
+
+        let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+            .map_err(|_| parse_error(format!("invalid date '{}'", record.date)))?;
+        let currency = CurrencyAlpha3::from_str(&record.currency)
+            .map_err(|_| parse_error(format!("unknown currency '{}'", record.currency)))?;
+        let money = Money::from_str(&record.amount, currency)
+            .ok_or_else(|| parse_error(format!("invalid amount '{}'", record.amount)))?;
+        flows.push(CashFlow::new(date, money));
+    }
+    Ok(flows)
+}
+
+/// Reads cash flows from a CSV file on disk.
+pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Vec<CashFlow>, CashFlowError> {
+    let file = File::open(path).map_err(|e| CashFlowError::Io(e.to_string()))?;
+    from_reader(file)
+}
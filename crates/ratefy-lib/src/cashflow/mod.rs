@@ -0,0 +1,8 @@
+mod error;
+pub mod import;
+pub mod model;
+mod valuation;
+
+pub use error::CashFlowError;
+pub use model::CashFlow;
+pub use valuation::{irr, npv, xirr, xnpv};
@@ -0,0 +1,47 @@
+//! # model.rs – CashFlow
+//!
+//! A [`CashFlow`] is a dated [`Money`] entry: a payment (negative amount) or a
+//! receipt (positive amount) happening on a given day. Collections of cash flows
+//! are evaluated with the functions in [`crate::cashflow`].
+//!
+//! Usage examples are available in
+//! [`docs/types/cash_flow.md`](../../docs/types/cash_flow.md).
+
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+#[doc = include_str!("../../docs/types/cash_flow.md")]
+/// A single dated movement of money.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CashFlow {
+    date: NaiveDate,
+    money: Money,
+}
+
+impl CashFlow {
+    /// Create a new cash flow happening on `date`.
+    pub fn new(date: NaiveDate, money: Money) -> Self {
+        Self { date, money }
+    }
+
+    /// Returns the date of the cash flow.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the money moved by the cash flow.
+    pub fn money(&self) -> &Money {
+        &self.money
+    }
+
+    /// Returns the signed amount of the cash flow.
+    pub fn amount(&self) -> Decimal {
+        self.money.amount()
+    }
+
+    /// Returns the currency of the cash flow.
+    pub fn currency(&self) -> &CurrencyAlpha3 {
+        self.money.currency()
+    }
+}
@@ -0,0 +1,192 @@
+//! # valuation.rs – NPV, IRR and XIRR
+//!
+//! Rates are expressed as percentages, consistently with [`Money::apply_rate`]:
+//! a discount rate of `10` means 10% per period (or per year for the dated variants).
+//!
+//! - [`npv`] / [`irr`] treat the cash flows as evenly spaced periods, the first one at period 0.
+//! - [`xnpv`] / [`xirr`] use the actual dates, measured in years of 365 days from the earliest flow.
+
+use super::{CashFlow, CashFlowError};
+use crate::money::Money;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, MathematicalOps};
+
+/// Newton-Raphson attempts before falling back to bisection.
+const NEWTON_ITERATIONS: usize = 50;
+/// Bisection attempts once a bracketing interval has been found.
+const BISECTION_ITERATIONS: usize = 200;
+/// Convergence tolerance on the rate, as a fraction.
+const TOLERANCE: f64 = 1e-10;
+/// Lowest rate (as a fraction) probed when bracketing the root.
+const LOWER_BOUND: f64 = -0.9999;
+/// Highest rate (as a fraction) probed when bracketing the root.
+const UPPER_BOUND: f64 = 1e6;
+
+/// Net present value of evenly spaced cash flows discounted at `rate` percent per period.
+///
+/// The first cash flow is at period 0 and is not discounted.
+pub fn npv(flows: &[CashFlow], rate: Decimal) -> Result<Money, CashFlowError> {
+    ensure_single_currency(flows)?;
+    let factor = discount_base(rate)?;
+    let mut total = Decimal::ZERO;
+    for (period, flow) in flows.iter().enumerate() {
+        let discount = factor
+            .checked_powi(period as i64)
+            .ok_or(CashFlowError::Overflow)?;
+        total = flow
+            .amount()
+            .checked_div(discount)
+            .and_then(|value| total.checked_add(value))
+            .ok_or(CashFlowError::Overflow)?;
+    }
+    Ok(Money::new(total, flows[0].currency().clone()))
+}
+
+/// Net present value of dated cash flows discounted at `rate` percent per year.
+pub fn xnpv(flows: &[CashFlow], rate: Decimal) -> Result<Money, CashFlowError> {
+    ensure_single_currency(flows)?;
+    let factor = discount_base(rate)?;
+    let origin = earliest_date(flows);
+    let mut total = Decimal::ZERO;
+    for flow in flows {
+        let years = Decimal::from((flow.date() - origin).num_days()) / Decimal::from(365);
+        let discount = factor.checked_powd(years).ok_or(CashFlowError::Overflow)?;
+        total = flow
+            .amount()
+            .checked_div(discount)
+            .and_then(|value| total.checked_add(value))
+            .ok_or(CashFlowError::Overflow)?;
+    }
+    Ok(Money::new(total, flows[0].currency().clone()))
+}
+
+/// Internal rate of return of evenly spaced cash flows, as a percentage per period.
+pub fn irr(flows: &[CashFlow]) -> Result<Decimal, CashFlowError> {
+    ensure_single_currency(flows)?;
+    let points = flows
+        .iter()
+        .enumerate()
+        .map(|(period, flow)| Ok((period as f64, to_f64(flow.amount())?)))
+        .collect::<Result<Vec<_>, CashFlowError>>()?;
+    solve_rate(&points)
+}
+
+/// Internal rate of return of dated cash flows, as an annual percentage.
+pub fn xirr(flows: &[CashFlow]) -> Result<Decimal, CashFlowError> {
+    ensure_single_currency(flows)?;
+    let origin = earliest_date(flows);
+    let points = flows
+        .iter()
+        .map(|flow| {
+            let years = (flow.date() - origin).num_days() as f64 / 365.0;
+            Ok((years, to_f64(flow.amount())?))
+        })
+        .collect::<Result<Vec<_>, CashFlowError>>()?;
+    solve_rate(&points)
+}
+
+fn ensure_single_currency(flows: &[CashFlow]) -> Result<(), CashFlowError> {
+    let first = flows.first().ok_or(CashFlowError::Empty)?;
+    if flows.iter().all(|f| f.currency() == first.currency()) {
+        Ok(())
+    } else {
+        Err(CashFlowError::MixedCurrencies)
+    }
+}
+
+fn earliest_date(flows: &[CashFlow]) -> chrono::NaiveDate {
+    flows
+        .iter()
+        .map(CashFlow::date)
+        .min()
+        .expect("flows checked to be non-empty")
+}
+
+fn discount_base(rate: Decimal) -> Result<Decimal, CashFlowError> {
+    let factor = Decimal::ONE + rate / Decimal::ONE_HUNDRED;
+    if factor <= Decimal::ZERO {
+        return Err(CashFlowError::InvalidRate);
+    }
+    Ok(factor)
+}
+
+fn to_f64(value: Decimal) -> Result<f64, CashFlowError> {
+    value.to_f64().ok_or(CashFlowError::Overflow)
+}
+
+/// Present value of `(time, amount)` points at `rate` (fraction) and its derivative.
+fn present_value(points: &[(f64, f64)], rate: f64) -> (f64, f64) {
+    points
+        .iter()
+        .fold((0.0, 0.0), |(value, slope), (t, amount)| {
+            let discount = (1.0 + rate).powf(*t);
+            (
+                value + amount / discount,
+                slope - t * amount / (discount * (1.0 + rate)),
+            )
+        })
+}
+
+/// Finds the rate zeroing the present value, trying Newton-Raphson first and
+/// falling back to bisection over a bracketing interval.
+fn solve_rate(points: &[(f64, f64)]) -> Result<Decimal, CashFlowError> {
+    let has_inflow = points.iter().any(|(_, a)| *a > 0.0);
+    let has_outflow = points.iter().any(|(_, a)| *a < 0.0);
+    if !has_inflow || !has_outflow {
+        return Err(CashFlowError::NoSignChange);
+    }
+
+    let root =
+        newton(points)
+            .or_else(|| bisection(points))
+            .ok_or(CashFlowError::NoConvergence {
+                iterations: NEWTON_ITERATIONS + BISECTION_ITERATIONS,
+            })?;
+    Decimal::from_f64(root * 100.0)
+        .map(|r| r.round_dp(8))
+        .ok_or(CashFlowError::Overflow)
+}
+
+fn newton(points: &[(f64, f64)]) -> Option<f64> {
+    let mut rate = 0.1;
+    for _ in 0..NEWTON_ITERATIONS {
+        let (value, slope) = present_value(points, rate);
+        if slope == 0.0 || !slope.is_finite() {
+            return None;
+        }
+        let next = rate - value / slope;
+        if !next.is_finite() || next <= -1.0 {
+            return None;
+        }
+        if (next - rate).abs() < TOLERANCE {
+            return Some(next);
+        }
+        rate = next;
+    }
+    None
+}
+
+fn bisection(points: &[(f64, f64)]) -> Option<f64> {
+    let mut low = LOWER_BOUND;
+    let mut high = 1.0;
+    let low_value = present_value(points, low).0;
+    while (present_value(points, high).0 > 0.0) == (low_value > 0.0) {
+        high *= 2.0;
+        if high > UPPER_BOUND {
+            return None;
+        }
+    }
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        let mid_value = present_value(points, mid).0;
+        if (mid_value > 0.0) == (low_value > 0.0) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+        if high - low < TOLERANCE {
+            return Some((low + high) / 2.0);
+        }
+    }
+    None
+}
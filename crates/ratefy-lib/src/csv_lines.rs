@@ -0,0 +1,85 @@
+//! # csv_lines.rs – Line numbers of CSV records
+//!
+//! The `csv` crate dates a record from the end of the previous one, before the
+//! blank lines it skips, so a record following a blank line is reported one
+//! line too early. [`LineTracker`] wraps the source, notes where each non-blank
+//! line starts as bytes are read, and maps a record's position to the line it
+//! really starts on. Quoted fields spanning lines are handled the same way.
+//!
+//! Only the lines read ahead of the current record are kept, so memory stays
+//! bounded when a large file is streamed.
+
+use std::collections::VecDeque;
+use std::io::{self, Read};
+
+/// A CSV source keeping track of where its non-blank lines start.
+pub(crate) struct LineTracker<R> {
+    inner: R,
+    /// Bytes read so far.
+    offset: u64,
+    /// Number of the line being read (1-based) and the offset it starts at.
+    line: usize,
+    line_start: u64,
+    /// Whether the line being read has only held `\r` so far.
+    blank: bool,
+    /// Start offset and number of the non-blank lines not yet passed.
+    starts: VecDeque<(u64, usize)>,
+}
+
+impl<R> LineTracker<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            offset: 0,
+            line: 1,
+            line_start: 0,
+            blank: true,
+            starts: VecDeque::new(),
+        }
+    }
+
+    /// Returns the line a record (or error) at `position` starts on.
+    fn line_at(&mut self, position: &csv::Position) -> usize {
+        while self
+            .starts
+            .front()
+            .is_some_and(|(start, _)| *start < position.byte())
+        {
+            self.starts.pop_front();
+        }
+        self.starts
+            .front()
+            .map_or(position.line() as usize, |(_, line)| *line)
+    }
+}
+
+impl<R: Read> Read for LineTracker<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        for &byte in &buf[..read] {
+            match byte {
+                b'\n' => {
+                    self.line += 1;
+                    self.line_start = self.offset + 1;
+                    self.blank = true;
+                }
+                b'\r' => {}
+                _ if self.blank => {
+                    self.blank = false;
+                    self.starts.push_back((self.line_start, self.line));
+                }
+                _ => {}
+            }
+            self.offset += 1;
+        }
+        Ok(read)
+    }
+}
+
+/// Returns the 1-based line of the record or error at `position`, or 0 if unknown.
+pub(crate) fn line<R: Read>(
+    reader: &mut csv::Reader<LineTracker<R>>,
+    position: Option<&csv::Position>,
+) -> usize {
+    position.map_or(0, |p| reader.get_mut().line_at(p))
+}
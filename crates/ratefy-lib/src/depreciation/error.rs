@@ -0,0 +1,51 @@
+use super::schedule::MAX_LIFE;
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// Errors raised while building or exporting depreciation schedules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepreciationError {
+    /// The useful life must be between one and [`MAX_LIFE`](super::schedule::MAX_LIFE) periods.
+    InvalidLife,
+    /// Cost and salvage value must be in the same currency.
+    CurrencyMismatch { cost: String, salvage: String },
+    /// The salvage value must be between zero and the cost.
+    InvalidSalvage(Decimal),
+    /// Declining-balance rates must be above 0% and at most 100%.
+    InvalidRate(Decimal),
+    /// Units of production need one non-negative figure per period and a positive total.
+    InvalidUnits(String),
+    /// The schedule could not be written.
+    Io(String),
+}
+
+impl fmt::Display for DepreciationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DepreciationError::InvalidLife => {
+                write!(f, "useful life must be between 1 and {} periods", MAX_LIFE)
+            }
+            DepreciationError::CurrencyMismatch { cost, salvage } => {
+                write!(f, "cost is in {} but salvage value in {}", cost, salvage)
+            }
+            DepreciationError::InvalidSalvage(salvage) => {
+                write!(
+                    f,
+                    "salvage value {} must be between 0 and the cost",
+                    salvage
+                )
+            }
+            DepreciationError::InvalidRate(rate) => {
+                write!(
+                    f,
+                    "declining-balance rate must be in (0, 100], got {}%",
+                    rate
+                )
+            }
+            DepreciationError::InvalidUnits(message) => write!(f, "invalid units: {}", message),
+            DepreciationError::Io(message) => write!(f, "could not write schedule: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for DepreciationError {}
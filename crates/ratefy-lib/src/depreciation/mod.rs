@@ -0,0 +1,5 @@
+mod error;
+pub mod schedule;
+
+pub use error::DepreciationError;
+pub use schedule::{DepreciationMethod, DepreciationPeriod, DepreciationSchedule, MAX_LIFE};
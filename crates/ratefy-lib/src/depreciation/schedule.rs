@@ -0,0 +1,216 @@
+//! # schedule.rs – Depreciation schedules
+//!
+//! Spreads the cost of an asset, less its salvage value, over its useful life
+//! with the usual methods: straight-line, declining balance at a fixed rate,
+//! double-declining balance switching to straight-line, sum-of-the-years'
+//! digits and units of production.
+//!
+//! Each period's charge is rounded to the minor units of the currency, and the
+//! last period takes whatever is left so that the book value ends exactly at
+//! the salvage value, without stray cents.
+//!
+//! Usage examples are available in
+//! [`docs/types/depreciation_schedule.md`](../../docs/types/depreciation_schedule.md).
+
+use super::error::DepreciationError;
+use crate::money::Money;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::io::Write;
+
+/// Longest useful life accepted, in periods.
+pub const MAX_LIFE: u32 = 1000;
+
+/// How the depreciable amount is spread over the periods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepreciationMethod {
+    /// Equal charges every period.
+    StraightLine,
+    /// A fixed percentage of the opening book value every period.
+    DecliningBalance { rate: Decimal },
+    /// Twice the straight-line rate on the book value, switching to
+    /// straight-line once that gives a larger charge.
+    DoubleDeclining,
+    /// Charges proportional to the remaining life: n, n-1, … 1 over n(n+1)/2.
+    SumOfYearsDigits,
+    /// Charges proportional to the units produced in each period, out of the
+    /// units the asset is expected to produce over its life.
+    UnitsOfProduction {
+        total_units: Decimal,
+        units: Vec<Decimal>,
+    },
+}
+
+impl fmt::Display for DepreciationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DepreciationMethod::StraightLine => write!(f, "Straight-line"),
+            DepreciationMethod::DecliningBalance { rate } => {
+                write!(f, "Declining balance {}%", rate)
+            }
+            DepreciationMethod::DoubleDeclining => {
+                write!(f, "Double-declining balance (switch to straight-line)")
+            }
+            DepreciationMethod::SumOfYearsDigits => write!(f, "Sum-of-the-years' digits"),
+            DepreciationMethod::UnitsOfProduction { .. } => write!(f, "Units of production"),
+        }
+    }
+}
+
+/// One period of a depreciation schedule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepreciationPeriod {
+    /// 1-based period number.
+    pub period: u32,
+    /// Book value at the start of the period.
+    pub opening: Money,
+    pub depreciation: Money,
+    /// Depreciation charged so far, this period included.
+    pub accumulated: Money,
+    /// Book value at the end of the period.
+    pub closing: Money,
+}
+
+#[doc = include_str!("../../docs/types/depreciation_schedule.md")]
+/// Period-by-period depreciation of an asset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepreciationSchedule {
+    pub method: DepreciationMethod,
+    pub cost: Money,
+    pub salvage: Money,
+    pub periods: Vec<DepreciationPeriod>,
+}
+
+impl DepreciationSchedule {
+    /// Builds the schedule of an asset bought for `cost`, worth `salvage` after
+    /// `life` periods (at most [`MAX_LIFE`]).
+    ///
+    /// For units of production, `units` must hold one figure per period; the
+    /// period in which the units produced reach `total_units` absorbs the residual.
+    pub fn new(
+        cost: &Money,
+        salvage: &Money,
+        life: u32,
+        method: DepreciationMethod,
+    ) -> Result<Self, DepreciationError> {
+        if life == 0 || life > MAX_LIFE {
+            return Err(DepreciationError::InvalidLife);
+        }
+        if cost.currency() != salvage.currency() {
+            return Err(DepreciationError::CurrencyMismatch {
+                cost: cost.currency().to_string(),
+                salvage: salvage.currency().to_string(),
+            });
+        }
+        if salvage.amount() < Decimal::ZERO || salvage.amount() > cost.amount() {
+            return Err(DepreciationError::InvalidSalvage(salvage.amount()));
+        }
+        match &method {
+            DepreciationMethod::DecliningBalance { rate }
+                if *rate <= Decimal::ZERO || *rate > Decimal::ONE_HUNDRED =>
+            {
+                return Err(DepreciationError::InvalidRate(*rate));
+            }
+            DepreciationMethod::UnitsOfProduction { total_units, units } => {
+                if units.len() != life as usize {
+                    return Err(DepreciationError::InvalidUnits(format!(
+                        "{} figures for {} periods",
+                        units.len(),
+                        life
+                    )));
+                }
+                if *total_units <= Decimal::ZERO || units.iter().any(|u| *u < Decimal::ZERO) {
+                    return Err(DepreciationError::InvalidUnits(
+                        "units must not be negative and the total must be positive".to_string(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        let money = |amount: Decimal| cost.with_amount_in(amount, cost.currency().clone());
+        let round = |amount: Decimal| money(amount).rounded().amount();
+        let depreciable = cost.amount() - salvage.amount();
+        let life_dec = Decimal::from(life);
+        let digits = life_dec * (life_dec + Decimal::ONE) / Decimal::TWO;
+        let mut units_done = Decimal::ZERO;
+
+        let mut periods = Vec::new();
+        let mut book = cost.amount();
+        for period in 1..=life {
+            let remaining_periods = Decimal::from(life - period + 1);
+            let left = book - salvage.amount();
+            let mut last = period == life;
+            let charge = match &method {
+                DepreciationMethod::StraightLine => depreciable / life_dec,
+                DepreciationMethod::DecliningBalance { rate } => book * rate / Decimal::ONE_HUNDRED,
+                DepreciationMethod::DoubleDeclining => {
+                    let declining = book * Decimal::TWO / life_dec;
+                    declining.max(left / remaining_periods)
+                }
+                DepreciationMethod::SumOfYearsDigits => depreciable * remaining_periods / digits,
+                DepreciationMethod::UnitsOfProduction { total_units, units } => {
+                    let produced = units[(period - 1) as usize].min(*total_units - units_done);
+                    units_done += produced;
+                    last = units_done == *total_units;
+                    depreciable * produced / total_units
+                }
+            };
+            let charge = if last { left } else { round(charge).min(left) };
+            let closing = book - charge;
+            periods.push(DepreciationPeriod {
+                period,
+                opening: money(book),
+                depreciation: money(charge),
+                accumulated: money(cost.amount() - closing),
+                closing: money(closing),
+            });
+            book = closing;
+        }
+
+        Ok(Self {
+            method,
+            cost: cost.clone(),
+            salvage: salvage.clone(),
+            periods,
+        })
+    }
+
+    /// Returns the depreciation charged over the whole schedule.
+    pub fn total(&self) -> Money {
+        let total = self.periods.iter().map(|p| p.depreciation.amount()).sum();
+        Money::new(total, self.cost.currency().clone())
+    }
+
+    /// Writes the schedule as CSV with a
+    /// `period,opening,depreciation,accumulated,closing,currency` header.
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), DepreciationError> {
+        let io_error = |e: csv::Error| DepreciationError::Io(e.to_string());
+        let mut csv_writer = csv::Writer::from_writer(writer);
+        csv_writer
+            .write_record([
+                "period",
+                "opening",
+                "depreciation",
+                "accumulated",
+                "closing",
+                "currency",
+            ])
+            .map_err(io_error)?;
+        for period in &self.periods {
+            csv_writer
+                .write_record([
+                    period.period.to_string(),
+                    period.opening.amount().to_string(),
+                    period.depreciation.amount().to_string(),
+                    period.accumulated.amount().to_string(),
+                    period.closing.amount().to_string(),
+                    period.closing.currency().to_string(),
+                ])
+                .map_err(io_error)?;
+        }
+        csv_writer
+            .flush()
+            .map_err(|e| DepreciationError::Io(e.to_string()))
+    }
+}
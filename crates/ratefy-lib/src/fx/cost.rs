@@ -0,0 +1,146 @@
+//! # cost.rs – Conversion cost analysis
+//!
+//! Providers rarely convert at the mid-market rate: they add a markup to the
+//! rate and often a fixed fee. [`conversion_cost`] splits the difference between
+//! what a mid-market conversion would give and what is actually received into
+//! these two parts. A fee in the source currency is valued at the provider's
+//! rate, since that is what it would have been converted at, so the two parts
+//! always add up to the total.
+//!
+//! Usage examples are available in
+//! [`docs/types/conversion_cost.md`](../../docs/types/conversion_cost.md).
+
+use super::error::FxError;
+use super::rate::ExchangeRate;
+use crate::money::Money;
+use rust_decimal::Decimal;
+use std::fmt;
+
+#[doc = include_str!("../../docs/types/conversion_cost.md")]
+/// Breakdown of what a conversion really costs, in the target currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionCost {
+    /// Amount handed to the provider.
+    pub sent: Money,
+    /// Amount received after the provider's rate and fees.
+    pub received: Money,
+    /// Amount a conversion of `sent` at the mid-market rate would give.
+    pub at_mid: Money,
+    /// Part of the cost due to the provider's rate being worse than mid.
+    pub rate_markup: Money,
+    /// Part of the cost due to fees, valued at the provider's rate.
+    pub fees: Money,
+    /// Total cost, `at_mid - received`.
+    pub total: Money,
+    /// Total cost as a percentage of `at_mid`.
+    pub total_percent: Decimal,
+    /// Target units received per unit sent, fees included.
+    pub effective_rate: Decimal,
+}
+
+/// Compares a provider conversion against a mid-market reference.
+///
+/// `fee` is a fixed, non-negative fee, in either the source currency (deducted
+/// before conversion) or the target currency (deducted from the proceeds).
+/// `provider` and `mid` must both be rates between the currencies of `amount`
+/// and the target.
+pub fn conversion_cost(
+    amount: &Money,
+    provider: &ExchangeRate,
+    fee: &Money,
+    mid: &ExchangeRate,
+) -> Result<ConversionCost, FxError> {
+    if fee.amount() < Decimal::ZERO {
+        return Err(FxError::NegativeFee(fee.amount()));
+    }
+    let out_of_range = || FxError::AmountOutOfRange {
+        pair: provider.pair().to_string(),
+        amount: amount.amount(),
+    };
+    let at_mid = mid.convert(amount)?;
+    let at_provider_rate = provider.convert(amount)?;
+    let target = at_mid.currency().clone();
+    if at_provider_rate.currency() != &target {
+        return Err(FxError::CurrencyMismatch {
+            pair: provider.pair().to_string(),
+            currency: target.to_string(),
+        });
+    }
+
+    let (received, fees) = if fee.currency() == amount.currency() {
+        let net = amount
+            .amount()
+            .checked_sub(fee.amount())
+            .ok_or_else(out_of_range)?;
+        let net = amount.with_amount_in(net, amount.currency().clone());
+        (provider.convert(&net)?, provider.convert(fee)?.amount())
+    } else if fee.currency() == &target {
+        let net = at_provider_rate
+            .amount()
+            .checked_sub(fee.amount())
+            .ok_or_else(out_of_range)?;
+        (
+            at_provider_rate.with_amount_in(net, target.clone()),
+            fee.amount(),
+        )
+    } else {
+        return Err(FxError::CurrencyMismatch {
+            pair: provider.pair().to_string(),
+            currency: fee.currency().to_string(),
+        });
+    };
+
+    let total = at_mid
+        .amount()
+        .checked_sub(received.amount())
+        .ok_or_else(out_of_range)?;
+    let rate_markup = at_mid
+        .amount()
+        .checked_sub(at_provider_rate.amount())
+        .ok_or_else(out_of_range)?;
+    let total_percent = if at_mid.amount().is_zero() {
+        Decimal::ZERO
+    } else {
+        total
+            .checked_div(at_mid.amount())
+            .and_then(|share| share.checked_mul(Decimal::ONE_HUNDRED))
+            .ok_or_else(out_of_range)?
+    };
+    let effective_rate = if amount.amount().is_zero() {
+        Decimal::ZERO
+    } else {
+        received
+            .amount()
+            .checked_div(amount.amount())
+            .ok_or_else(out_of_range)?
+    };
+    let in_target = |value: Decimal| at_mid.with_amount_in(value, target.clone());
+    Ok(ConversionCost {
+        sent: amount.clone(),
+        rate_markup: in_target(rate_markup),
+        fees: in_target(fees),
+        total: in_target(total),
+        total_percent,
+        effective_rate,
+        received,
+        at_mid,
+    })
+}
+
+/// Formats the breakdown as a short multi-line report, rounded to minor units.
+impl fmt::Display for ConversionCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |m: &Money| format!("{} {}", m.rounded().amount(), m.currency());
+        writeln!(f, "Sent:          {}", show(&self.sent))?;
+        writeln!(f, "At mid-market: {}", show(&self.at_mid))?;
+        writeln!(f, "Received:      {}", show(&self.received))?;
+        writeln!(f, "Rate markup:   {}", show(&self.rate_markup))?;
+        writeln!(f, "Fees:          {}", show(&self.fees))?;
+        write!(
+            f,
+            "True cost:     {} ({:.2}%)",
+            show(&self.total),
+            self.total_percent
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// Errors raised while parsing currency pairs or converting with exchange rates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FxError {
+    /// The text is not a pair such as "EURUSD", "EUR/USD" or "EUR-USD".
+    InvalidPair(String),
+    /// A code in the pair is not a known currency or registered asset.
+    UnknownCurrency(String),
+    /// Base and quote are the same currency.
+    SameCurrency(String),
+    /// Exchange rates must be strictly positive.
+    NonPositiveRate,
+    /// The bid of a quote is above its ask.
+    CrossedQuote { bid: Decimal, ask: Decimal },
+    /// The text is not a tenor such as "1W", "3M", "1Y" or a `YYYY-MM-DD` date.
+    InvalidTenor(String),
+    /// The maturity is not after the spot date.
+    InvalidMaturity { spot: NaiveDate },
+    /// Option volatility must be between 0.01% and 500%.
+    InvalidVolatility(Decimal),
+    /// No volatility between 0.01% and 500% reproduces the premium.
+    NoImpliedVolatility(Decimal),
+    /// The spot or strike is too large for the option to be valued.
+    ValuationOutOfRange,
+    /// Carrying or discounting at the interest rate (in percent) until maturity overflows.
+    InvalidInterestRate(Decimal),
+    /// No stored rate, direct or crossed, is available for the pair on the date.
+    MissingRate {
+        pair: String,
+        date: Option<NaiveDate>,
+    },
+    /// The amount is in neither currency of the pair.
+    CurrencyMismatch { pair: String, currency: String },
+    /// The converted amount does not fit in a `Decimal`.
+    AmountOutOfRange { pair: String, amount: Decimal },
+    /// A conversion fee must not be negative.
+    NegativeFee(Decimal),
+    /// The stop distance of a position, in pips, must not be zero.
+    InvalidStop(Decimal),
+    /// Lot amounts must be strictly positive.
+    NonPositiveAmount(Decimal),
+    /// A disposal exceeds the amount held.
+    InsufficientHoldings { held: Decimal, requested: Decimal },
+    /// Lots must be recorded in date order.
+    OutOfOrder { date: NaiveDate, last: NaiveDate },
+    /// A CSV record could not be parsed (1-based line number and reason).
+    Parse { line: usize, message: String },
+    /// The CSV source could not be read.
+    Io(String),
+}
+
+impl fmt::Display for FxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FxError::InvalidPair(text) => write!(f, "invalid currency pair '{}'", text),
+            FxError::UnknownCurrency(code) => write!(f, "unknown currency '{}'", code),
+            FxError::SameCurrency(code) => {
+                write!(
+                    f,
+                    "a pair needs two different currencies, got {} twice",
+                    code
+                )
+            }
+            FxError::NonPositiveRate => write!(f, "exchange rate must be positive"),
+            FxError::CrossedQuote { bid, ask } => {
+                write!(f, "bid {} is above ask {}", bid, ask)
+            }
+            FxError::InvalidTenor(text) => write!(f, "invalid tenor '{}'", text),
+            FxError::InvalidMaturity { spot } => {
+                write!(f, "maturity must be after the spot date {}", spot)
+            }
+            FxError::InvalidStop(pips) => {
+                write!(f, "stop distance must not be zero, got {} pips", pips)
+            }
+            FxError::InvalidInterestRate(rate) => {
+                write!(
+                    f,
+                    "interest rate of {}% is out of range for this maturity",
+                    rate
+                )
+            }
+            FxError::InvalidVolatility(vol) => {
+                write!(f, "volatility must be between 0.01% and 500%, got {}%", vol)
+            }
+            FxError::ValuationOutOfRange => {
+                write!(f, "the option cannot be valued at this spot and strike")
+            }
+            FxError::NoImpliedVolatility(premium) => {
+                write!(f, "no volatility matches premium {}", premium)
+            }
+            FxError::CurrencyMismatch { pair, currency } => {
+                write!(f, "cannot convert {} with a {} rate", currency, pair)
+            }
+            FxError::AmountOutOfRange { pair, amount } => {
+                write!(f, "{} is out of range at the {} rate", amount, pair)
+            }
+            FxError::MissingRate { pair, date } => match date {
+                Some(date) => write!(f, "no {} rate on or before {}", pair, date),
+                None => write!(f, "no {} rate available", pair),
+            },
+            FxError::NegativeFee(fee) => write!(f, "fee must not be negative, got {}", fee),
+            FxError::NonPositiveAmount(amount) => {
+                write!(f, "amount must be positive, got {}", amount)
+            }
+            FxError::InsufficientHoldings { held, requested } => {
+                write!(f, "cannot dispose of {} with only {} held", requested, held)
+            }
+            FxError::OutOfOrder { date, last } => {
+                write!(f, "{} is before the last recorded date {}", date, last)
+            }
+            FxError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            FxError::Io(message) => write!(f, "could not read rates: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for FxError {}
@@ -0,0 +1,147 @@
+//! # forward.rs – FX forwards via covered interest rate parity
+//!
+//! The outright forward rate of a pair is the spot rate carried by the interest
+//! rates of both currencies until maturity:
+//!
+//! `F = S × (1 + r_quote × t_quote) / (1 + r_base × t_base)`
+//!
+//! with simple interest and each currency's own day count. Forward points are
+//! `F - S`. Rates are percentages, as everywhere in the crate.
+//!
+//! Usage examples are available in
+//! [`docs/types/forward.md`](../../docs/types/forward.md).
+
+use super::error::FxError;
+use super::rate::ExchangeRate;
+use super::tenor::{DayCount, Tenor};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+#[doc = include_str!("../../docs/types/forward.md")]
+/// Outright forward rate and forward points for a maturity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Forward {
+    /// Spot rate the forward was derived from.
+    pub spot: ExchangeRate,
+    /// Outright forward rate, dated at maturity.
+    pub outright: ExchangeRate,
+    /// Outright minus spot, in quote currency.
+    pub points: Decimal,
+    /// Forward points expressed in pips of the pair.
+    pub pips: Decimal,
+    /// Calendar days from spot to maturity.
+    pub days: i64,
+}
+
+impl Forward {
+    /// Computes the forward with each currency's money-market day count.
+    ///
+    /// `base_rate` and `quote_rate` are the annual interest rates, in percent,
+    /// of the pair's base and quote currencies.
+    pub fn new(
+        spot: &ExchangeRate,
+        base_rate: Decimal,
+        quote_rate: Decimal,
+        tenor: &Tenor,
+    ) -> Result<Self, FxError> {
+        Self::with_day_counts(
+            spot,
+            base_rate,
+            DayCount::money_market(spot.base()),
+            quote_rate,
+            DayCount::money_market(spot.quote()),
+            tenor,
+        )
+    }
+
+    /// Computes the forward with explicit day count conventions.
+    pub fn with_day_counts(
+        spot: &ExchangeRate,
+        base_rate: Decimal,
+        base_day_count: DayCount,
+        quote_rate: Decimal,
+        quote_day_count: DayCount,
+        tenor: &Tenor,
+    ) -> Result<Self, FxError> {
+        let maturity = tenor.maturity(spot.date())?;
+        let base_growth = growth(base_rate, base_day_count, spot.date(), maturity)?;
+        let quote_growth = growth(quote_rate, quote_day_count, spot.date(), maturity)?;
+        // The outright can only grow out of range through the quote rate
+        // (multiplying) or the base rate (dividing by a tiny growth factor).
+        let outright = spot
+            .rate()
+            .checked_mul(quote_growth)
+            .ok_or(FxError::InvalidInterestRate(quote_rate))?
+            .checked_div(base_growth)
+            .ok_or(FxError::InvalidInterestRate(base_rate))?;
+        let points = outright - spot.rate();
+        let pips = points
+            .checked_div(spot.pair().pip_size())
+            .ok_or(FxError::InvalidInterestRate(quote_rate))?;
+        Ok(Self {
+            spot: spot.clone(),
+            outright: ExchangeRate::new(spot.pair().clone(), outright, maturity)?,
+            points,
+            pips,
+            days: (maturity - spot.date()).num_days(),
+        })
+    }
+
+    /// Returns the maturity date.
+    pub fn maturity(&self) -> NaiveDate {
+        self.outright.date()
+    }
+}
+
+/// Returns the quote-minus-base interest differential, in percent, implied by
+/// forward `points` over `tenor`, given the base currency's rate.
+///
+/// This inverts [`Forward::new`]: it solves the parity equation for the quote
+/// rate using each currency's money-market day count.
+pub fn implied_differential(
+    spot: &ExchangeRate,
+    points: Decimal,
+    tenor: &Tenor,
+    base_rate: Decimal,
+) -> Result<Decimal, FxError> {
+    let maturity = tenor.maturity(spot.date())?;
+    let base_growth = growth(
+        base_rate,
+        DayCount::money_market(spot.base()),
+        spot.date(),
+        maturity,
+    )?;
+    let quote_fraction = DayCount::money_market(spot.quote()).year_fraction(spot.date(), maturity);
+    let out_of_range = || FxError::AmountOutOfRange {
+        pair: spot.pair().to_string(),
+        amount: points,
+    };
+    let outright = spot.rate().checked_add(points).ok_or_else(out_of_range)?;
+    if outright <= Decimal::ZERO {
+        return Err(FxError::NonPositiveRate);
+    }
+    (outright / spot.rate())
+        .checked_mul(base_growth)
+        .and_then(|carried| carried.checked_sub(Decimal::ONE))
+        .and_then(|interest| interest.checked_div(quote_fraction))
+        .and_then(|fraction| fraction.checked_mul(Decimal::ONE_HUNDRED))
+        .and_then(|quote_rate| quote_rate.checked_sub(base_rate))
+        .ok_or_else(out_of_range)
+}
+
+/// Simple-interest growth factor `1 + r × t` for a rate in percent.
+fn growth(
+    rate: Decimal,
+    day_count: DayCount,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Decimal, FxError> {
+    let factor = (rate / Decimal::ONE_HUNDRED)
+        .checked_mul(day_count.year_fraction(start, end))
+        .and_then(|interest| interest.checked_add(Decimal::ONE))
+        .ok_or(FxError::InvalidInterestRate(rate))?;
+    if factor <= Decimal::ZERO {
+        return Err(FxError::NonPositiveRate);
+    }
+    Ok(factor)
+}
@@ -0,0 +1,334 @@
+//! # lots.rs – Foreign currency lots and FX gains
+//!
+//! A [`LotLedger`] tracks the cost basis of a foreign currency holding. Every
+//! acquisition is recorded as a lot, valued in a reporting currency at the
+//! dated rate of the day; disposals consume lots first-in first-out, last-in
+//! first-out, or at the weighted average cost, and realize the difference
+//! between proceeds and cost as an FX gain or loss. The lots still held carry
+//! an unrealized gain against any later rate.
+//!
+//! Usage examples are available in
+//! [`docs/types/lot_ledger.md`](../../docs/types/lot_ledger.md).
+
+use super::error::FxError;
+use super::rate::ExchangeRate;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+
+/// Order in which disposals consume the lots held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CostMethod {
+    /// Oldest lots are disposed of first.
+    Fifo,
+    /// Most recent lots are disposed of first.
+    Lifo,
+    /// Every unit costs the average of the holding; all lots shrink pro rata.
+    Average,
+}
+
+impl fmt::Display for CostMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CostMethod::Fifo => write!(f, "FIFO"),
+            CostMethod::Lifo => write!(f, "LIFO"),
+            CostMethod::Average => write!(f, "Average"),
+        }
+    }
+}
+
+impl FromStr for CostMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "fifo" => Ok(CostMethod::Fifo),
+            "lifo" => Ok(CostMethod::Lifo),
+            "average" | "avg" | "wac" => Ok(CostMethod::Average),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Foreign currency still held from one acquisition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lot {
+    /// Date of the acquisition.
+    pub date: NaiveDate,
+    /// Remaining amount, in the held currency.
+    pub amount: Money,
+    /// Cost of the remaining amount, in the reporting currency.
+    pub cost: Money,
+}
+
+/// Realized result of one disposal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disposal {
+    pub date: NaiveDate,
+    /// Amount disposed of, in the held currency.
+    pub amount: Money,
+    /// Value of the amount at the disposal rate, in the reporting currency.
+    pub proceeds: Money,
+    /// Cost of the lots consumed, in the reporting currency.
+    pub cost: Money,
+    /// Proceeds minus cost (negative for a loss).
+    pub gain: Money,
+}
+
+#[doc = include_str!("../../docs/types/lot_ledger.md")]
+/// Cost basis of a foreign currency holding, in a reporting currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LotLedger {
+    currency: CurrencyAlpha3,
+    reporting: CurrencyAlpha3,
+    method: CostMethod,
+    lots: Vec<Lot>,
+    disposals: Vec<Disposal>,
+    last_date: Option<NaiveDate>,
+}
+
+impl LotLedger {
+    /// Create an empty ledger for `currency`, reporting gains in `reporting`.
+    pub fn new(
+        currency: CurrencyAlpha3,
+        reporting: CurrencyAlpha3,
+        method: CostMethod,
+    ) -> Result<Self, FxError> {
+        if currency == reporting {
+            return Err(FxError::SameCurrency(currency.to_string()));
+        }
+        Ok(Self {
+            currency,
+            reporting,
+            method,
+            lots: Vec::new(),
+            disposals: Vec::new(),
+            last_date: None,
+        })
+    }
+
+    /// Returns the held currency.
+    pub fn currency(&self) -> &CurrencyAlpha3 {
+        &self.currency
+    }
+
+    /// Returns the currency gains are reported in.
+    pub fn reporting(&self) -> &CurrencyAlpha3 {
+        &self.reporting
+    }
+
+    /// Returns the cost method.
+    pub fn method(&self) -> CostMethod {
+        self.method
+    }
+
+    /// Returns the lots still held, oldest first.
+    pub fn lots(&self) -> &[Lot] {
+        &self.lots
+    }
+
+    /// Returns every disposal recorded, in order.
+    pub fn disposals(&self) -> &[Disposal] {
+        &self.disposals
+    }
+
+    /// Returns the amount held.
+    pub fn holdings(&self) -> Result<Money, FxError> {
+        let total = self.sum(self.lots.iter().map(|lot| lot.amount.amount()))?;
+        Ok(Money::new(total, self.currency.clone()))
+    }
+
+    /// Returns the cost of the amount held, in the reporting currency.
+    pub fn cost_basis(&self) -> Result<Money, FxError> {
+        let total = self.sum(self.lots.iter().map(|lot| lot.cost.amount()))?;
+        Ok(Money::new(total, self.reporting.clone()))
+    }
+
+    /// Returns the sum of the gains realized by all disposals.
+    pub fn realized(&self) -> Result<Money, FxError> {
+        let total = self.sum(self.disposals.iter().map(|d| d.gain.amount()))?;
+        Ok(Money::new(total, self.reporting.clone()))
+    }
+
+    /// Returns the gain on the amount held if it were valued at `rate`.
+    pub fn unrealized(&self, rate: &ExchangeRate) -> Result<Money, FxError> {
+        let value = self.value(&self.holdings()?, rate)?.amount();
+        let gain = value
+            .checked_sub(self.cost_basis()?.amount())
+            .ok_or_else(|| self.out_of_range(value))?;
+        Ok(Money::new(gain, self.reporting.clone()).with_timestamp(rate.date()))
+    }
+
+    /// Records the purchase of `amount` at `rate`, dated on the rate's date.
+    pub fn acquire(&mut self, amount: &Money, rate: &ExchangeRate) -> Result<&Lot, FxError> {
+        self.check(amount, rate)?;
+        let cost = self.value(amount, rate)?;
+        // The totals of the holding must stay in range once the lot is added.
+        self.holdings()?
+            .amount()
+            .checked_add(amount.amount())
+            .ok_or_else(|| self.out_of_range(amount.amount()))?;
+        self.cost_basis()?
+            .amount()
+            .checked_add(cost.amount())
+            .ok_or_else(|| self.out_of_range(cost.amount()))?;
+        self.last_date = Some(rate.date());
+        self.lots.push(Lot {
+            date: rate.date(),
+            amount: amount.with_timestamp(rate.date()),
+            cost,
+        });
+        Ok(self.lots.last().expect("lot just pushed"))
+    }
+
+    /// Records the sale of `amount` at `rate` and realizes its gain or loss.
+    pub fn dispose(&mut self, amount: &Money, rate: &ExchangeRate) -> Result<&Disposal, FxError> {
+        self.check(amount, rate)?;
+        let held = self.holdings()?.amount();
+        if amount.amount() > held {
+            return Err(FxError::InsufficientHoldings {
+                held,
+                requested: amount.amount(),
+            });
+        }
+        let proceeds = self.value(amount, rate)?;
+        // Lots are consumed on a copy, so a failed disposal leaves them untouched.
+        let mut lots = self.lots.clone();
+        let cost = match self.method {
+            CostMethod::Fifo => self.consume(&mut lots, amount.amount(), false)?,
+            CostMethod::Lifo => self.consume(&mut lots, amount.amount(), true)?,
+            CostMethod::Average => self.consume_average(&mut lots, amount.amount(), held)?,
+        };
+        let gain = proceeds
+            .amount()
+            .checked_sub(cost)
+            .ok_or_else(|| self.out_of_range(proceeds.amount()))?;
+        self.realized()?
+            .amount()
+            .checked_add(gain)
+            .ok_or_else(|| self.out_of_range(gain))?;
+        self.lots = lots;
+        self.last_date = Some(rate.date());
+        self.disposals.push(Disposal {
+            date: rate.date(),
+            amount: amount.with_timestamp(rate.date()),
+            gain: Money::new(gain, self.reporting.clone()).with_timestamp(rate.date()),
+            cost: Money::new(cost, self.reporting.clone()),
+            proceeds,
+        });
+        Ok(self.disposals.last().expect("disposal just pushed"))
+    }
+
+    /// Validates the currency, sign and date of an acquisition or disposal.
+    fn check(&self, amount: &Money, rate: &ExchangeRate) -> Result<(), FxError> {
+        if amount.currency() != &self.currency {
+            return Err(FxError::CurrencyMismatch {
+                pair: format!("{}/{}", self.currency, self.reporting),
+                currency: amount.currency().to_string(),
+            });
+        }
+        if amount.amount() <= Decimal::ZERO {
+            return Err(FxError::NonPositiveAmount(amount.amount()));
+        }
+        if let Some(last) = self.last_date
+            && rate.date() < last
+        {
+            return Err(FxError::OutOfOrder {
+                date: rate.date(),
+                last,
+            });
+        }
+        Ok(())
+    }
+
+    /// Values an amount of the held currency in the reporting currency.
+    fn value(&self, amount: &Money, rate: &ExchangeRate) -> Result<Money, FxError> {
+        if !rate.pair().contains(&self.reporting) {
+            return Err(FxError::CurrencyMismatch {
+                pair: rate.pair().to_string(),
+                currency: self.reporting.to_string(),
+            });
+        }
+        rate.convert(amount)
+    }
+
+    /// Adds up amounts of the holding, failing if the total does not fit.
+    fn sum(&self, mut amounts: impl Iterator<Item = Decimal>) -> Result<Decimal, FxError> {
+        amounts.try_fold(Decimal::ZERO, |total, amount| {
+            total
+                .checked_add(amount)
+                .ok_or_else(|| self.out_of_range(amount))
+        })
+    }
+
+    fn out_of_range(&self, amount: Decimal) -> FxError {
+        FxError::AmountOutOfRange {
+            pair: format!("{}/{}", self.currency, self.reporting),
+            amount,
+        }
+    }
+
+    /// Takes `amount` from the oldest (or newest) lots and returns its cost.
+    fn consume(
+        &self,
+        lots: &mut Vec<Lot>,
+        mut amount: Decimal,
+        newest_first: bool,
+    ) -> Result<Decimal, FxError> {
+        let mut cost = Decimal::ZERO;
+        while amount > Decimal::ZERO {
+            let index = if newest_first { lots.len() - 1 } else { 0 };
+            let lot = &mut lots[index];
+            if amount >= lot.amount.amount {
+                amount -= lot.amount.amount;
+                cost += lot.cost.amount;
+                lots.remove(index);
+            } else {
+                let share = lot
+                    .cost
+                    .amount
+                    .checked_mul(amount)
+                    .and_then(|product| product.checked_div(lot.amount.amount))
+                    .ok_or_else(|| self.out_of_range(amount))?;
+                lot.amount.amount -= amount;
+                lot.cost.amount -= share;
+                cost += share;
+                amount = Decimal::ZERO;
+            }
+        }
+        Ok(cost)
+    }
+
+    /// Shrinks every lot by the share of the holding disposed of and returns its cost.
+    ///
+    /// Scaling rounds, so the last lot takes whatever remains of the holding:
+    /// the lots then add up to exactly `held - amount`.
+    fn consume_average(
+        &self,
+        lots: &mut Vec<Lot>,
+        amount: Decimal,
+        held: Decimal,
+    ) -> Result<Decimal, FxError> {
+        let basis = self.sum(lots.iter().map(|lot| lot.cost.amount))?;
+        if amount == held {
+            lots.clear();
+            return Ok(basis);
+        }
+        let kept = Decimal::ONE - amount / held;
+        let (mut amount_left, mut cost_left) = (held - amount, Decimal::ZERO);
+        let last = lots.len() - 1;
+        for (index, lot) in lots.iter_mut().enumerate() {
+            lot.cost.amount *= kept;
+            cost_left += lot.cost.amount;
+            if index == last {
+                lot.amount.amount = amount_left;
+            } else {
+                lot.amount.amount *= kept;
+                amount_left -= lot.amount.amount;
+            }
+        }
+        Ok(basis - cost_left)
+    }
+}
@@ -0,0 +1,23 @@
+pub mod cost;
+mod error;
+pub mod forward;
+pub mod lots;
+pub mod option;
+pub mod pair;
+pub mod position;
+pub mod quote;
+pub mod rate;
+pub mod store;
+pub mod tenor;
+
+pub use cost::{ConversionCost, conversion_cost};
+pub use error::FxError;
+pub use forward::{Forward, implied_differential};
+pub use lots::{CostMethod, Disposal, Lot, LotLedger};
+pub use option::{FxOption, OptionKind, OptionMarket, OptionValuation};
+pub use pair::CurrencyPair;
+pub use position::{PositionCalculator, STANDARD_LOT};
+pub use quote::Quote;
+pub use rate::ExchangeRate;
+pub use store::RateStore;
+pub use tenor::{DayCount, Tenor};
@@ -0,0 +1,333 @@
+//! # option.rs – Garman–Kohlhagen FX option pricing
+//!
+//! Prices European options on a currency pair with the Garman–Kohlhagen model,
+//! Black–Scholes with the base (foreign) currency paying a continuous yield:
+//!
+//! - `d1 = (ln(S/K) + (r_d - r_f + σ²/2)·T) / (σ·√T)`, `d2 = d1 - σ·√T`
+//! - call `= S·e^(-r_f·T)·N(d1) - K·e^(-r_d·T)·N(d2)`
+//! - put `= K·e^(-r_d·T)·N(-d2) - S·e^(-r_f·T)·N(-d1)`
+//!
+//! The domestic currency is the pair's quote currency and the foreign one its
+//! base, so premiums are in quote currency per unit of base. Rates and
+//! volatility are percentages and time runs ACT/365 from the spot date. The
+//! normal distribution comes from `rust_decimal`'s maths module, which is
+//! accurate to about 1e-7: enough for sanity checks, not for a trading book.
+//!
+//! Usage examples are available in
+//! [`docs/types/fx_option.md`](../../docs/types/fx_option.md).
+
+use super::error::FxError;
+use super::rate::ExchangeRate;
+use super::tenor::DayCount;
+use crate::money::Money;
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, MathematicalOps};
+
+/// Bounds of the implied volatility search, in percent.
+const MIN_VOLATILITY: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+const MAX_VOLATILITY: Decimal = Decimal::from_parts(500, 0, 0, false, 0);
+const MAX_ITERATIONS: usize = 200;
+
+/// Right bought with an option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionKind {
+    /// Right to buy the base currency at the strike.
+    Call,
+    /// Right to sell the base currency at the strike.
+    Put,
+}
+
+/// Market inputs of the Garman–Kohlhagen model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionMarket {
+    /// Spot rate of the pair; its date is the valuation date.
+    pub spot: ExchangeRate,
+    /// Interest rate of the quote (domestic) currency, in percent.
+    pub domestic_rate: Decimal,
+    /// Interest rate of the base (foreign) currency, in percent.
+    pub foreign_rate: Decimal,
+    /// Annual volatility, in percent.
+    pub volatility: Decimal,
+}
+
+#[doc = include_str!("../../docs/types/fx_option.md")]
+/// European option on the base currency of a pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FxOption {
+    kind: OptionKind,
+    strike: Decimal,
+    expiry: NaiveDate,
+}
+
+/// Premium and Greeks of an option, per unit of base currency.
+///
+/// Vega and both rhos are per percentage point, theta per calendar day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionValuation {
+    /// Spot rate the option was valued at.
+    pub spot: ExchangeRate,
+    /// Premium in quote currency per unit of base.
+    pub premium: Decimal,
+    /// Change of the premium for a unit change of spot.
+    pub delta: Decimal,
+    /// Change of delta for a unit change of spot.
+    pub gamma: Decimal,
+    /// Change of the premium for a 1% rise in volatility.
+    pub vega: Decimal,
+    /// Change of the premium after one calendar day.
+    pub theta: Decimal,
+    /// Change of the premium for a 1% rise in the domestic rate.
+    pub rho_domestic: Decimal,
+    /// Change of the premium for a 1% rise in the foreign rate.
+    pub rho_foreign: Decimal,
+}
+
+impl FxOption {
+    /// Create an option with a positive strike, expiring on `expiry`.
+    pub fn new(kind: OptionKind, strike: Decimal, expiry: NaiveDate) -> Result<Self, FxError> {
+        if strike <= Decimal::ZERO {
+            return Err(FxError::NonPositiveRate);
+        }
+        Ok(Self {
+            kind,
+            strike,
+            expiry,
+        })
+    }
+
+    /// Returns whether the option is a call or a put.
+    pub fn kind(&self) -> OptionKind {
+        self.kind
+    }
+
+    /// Returns the strike rate.
+    pub fn strike(&self) -> Decimal {
+        self.strike
+    }
+
+    /// Returns the expiry date.
+    pub fn expiry(&self) -> NaiveDate {
+        self.expiry
+    }
+
+    /// Computes the premium and Greeks.
+    ///
+    /// The volatility must be between 0.01% and 500%, the range searched by
+    /// [`FxOption::implied_volatility`].
+    pub fn value(&self, market: &OptionMarket) -> Result<OptionValuation, FxError> {
+        let inputs = Inputs::new(self, market)?;
+        if market.volatility < MIN_VOLATILITY || market.volatility > MAX_VOLATILITY {
+            return Err(FxError::InvalidVolatility(market.volatility));
+        }
+        let sigma = market.volatility / Decimal::ONE_HUNDRED;
+        self.valuation(&inputs, market, sigma)
+            .ok_or(FxError::ValuationOutOfRange)
+    }
+
+    /// Finds the volatility, in percent, at which the option is worth `premium`
+    /// (quote currency per unit of base).
+    ///
+    /// The volatility in `market` is ignored. The search is a bisection between
+    /// 0.01% and 500%, as the premium rises with volatility.
+    pub fn implied_volatility(
+        &self,
+        market: &OptionMarket,
+        premium: Decimal,
+    ) -> Result<Decimal, FxError> {
+        let inputs = Inputs::new(self, market)?;
+        let price = |vol: Decimal| {
+            inputs
+                .premium(self.kind, vol / Decimal::ONE_HUNDRED)
+                .ok_or(FxError::ValuationOutOfRange)
+        };
+        let (mut low, mut high) = (MIN_VOLATILITY, MAX_VOLATILITY);
+        if premium < price(low)? || premium > price(high)? {
+            return Err(FxError::NoImpliedVolatility(premium));
+        }
+        let tolerance = Decimal::new(1, 12);
+        for _ in 0..MAX_ITERATIONS {
+            let mid = (low + high) / Decimal::TWO;
+            if price(mid)? < premium {
+                low = mid;
+            } else {
+                high = mid;
+            }
+            if high - low < tolerance {
+                break;
+            }
+        }
+        Ok(((low + high) / Decimal::TWO).round_dp(8))
+    }
+
+    /// Premium and Greeks, or `None` if an intermediate result overflows.
+    fn valuation(
+        &self,
+        inputs: &Inputs,
+        market: &OptionMarket,
+        sigma: Decimal,
+    ) -> Option<OptionValuation> {
+        let (d1, d2) = inputs.d1_d2(sigma)?;
+        // A put mirrors a call: N(-d1) and N(-d2) in place of N(d1) and N(d2),
+        // with the signs of the legs reversed.
+        let (sign, n_d1, n_d2) = match self.kind {
+            OptionKind::Call => (Decimal::ONE, norm_cdf(d1), norm_cdf(d2)),
+            OptionKind::Put => (Decimal::NEGATIVE_ONE, norm_cdf(-d1), norm_cdf(-d2)),
+        };
+        let (forward_leg, strike_leg) = inputs.legs()?;
+        let foreign = forward_leg * n_d1;
+        let domestic = strike_leg * n_d2;
+        let (t, sqrt_t) = (inputs.years, inputs.sqrt_t);
+        let hundred = Decimal::ONE_HUNDRED;
+        let pdf_d1 = norm_pdf(d1);
+
+        let decay = -(forward_leg * pdf_d1 * sigma).checked_div(Decimal::TWO * sqrt_t)?;
+        let carry = inputs
+            .rf
+            .checked_mul(foreign)?
+            .checked_sub(inputs.rd.checked_mul(domestic)?)?;
+        let theta = decay.checked_add(sign * carry)? / Decimal::from(365);
+        let gamma = (inputs.foreign_df * pdf_d1)
+            .checked_div(inputs.spot.checked_mul(sigma)?.checked_mul(sqrt_t)?)?;
+        Some(OptionValuation {
+            spot: market.spot.clone(),
+            premium: sign * (foreign - domestic),
+            delta: sign * inputs.foreign_df * n_d1,
+            gamma,
+            vega: forward_leg.checked_mul(pdf_d1 * sqrt_t)? / hundred,
+            theta,
+            rho_domestic: sign * domestic.checked_mul(t)? / hundred,
+            rho_foreign: -sign * foreign.checked_mul(t)? / hundred,
+        })
+    }
+}
+
+impl OptionValuation {
+    /// Returns the premium in pips of the pair.
+    pub fn premium_pips(&self) -> Decimal {
+        self.premium / self.spot.pair().pip_size()
+    }
+
+    /// Returns the premium as a percentage of the base notional, valued at spot.
+    pub fn premium_percent(&self) -> Decimal {
+        self.premium / self.spot.rate() * Decimal::ONE_HUNDRED
+    }
+
+    /// Returns the premium for `notional` in quote currency.
+    ///
+    /// The notional may be given in either currency of the pair; a quote amount
+    /// is turned into base units at spot.
+    pub fn premium_in_quote(&self, notional: &Money) -> Result<Money, FxError> {
+        let base_units = self.base_units(notional)?;
+        let amount =
+            base_units
+                .checked_mul(self.premium)
+                .ok_or_else(|| FxError::AmountOutOfRange {
+                    pair: self.spot.pair().to_string(),
+                    amount: notional.amount(),
+                })?;
+        let premium = Money::new(amount, self.spot.quote().clone());
+        Ok(premium.with_timestamp(self.spot.date()))
+    }
+
+    /// Returns the premium for `notional` in base currency, converted at spot.
+    pub fn premium_in_base(&self, notional: &Money) -> Result<Money, FxError> {
+        self.spot.convert(&self.premium_in_quote(notional)?)
+    }
+
+    fn base_units(&self, notional: &Money) -> Result<Decimal, FxError> {
+        if notional.currency() == self.spot.base() {
+            Ok(notional.amount())
+        } else {
+            Ok(self.spot.convert(notional)?.amount())
+        }
+    }
+}
+
+/// Quantities shared by the pricing formulas.
+struct Inputs {
+    spot: Decimal,
+    strike: Decimal,
+    rd: Decimal,
+    rf: Decimal,
+    years: Decimal,
+    sqrt_t: Decimal,
+    domestic_df: Decimal,
+    foreign_df: Decimal,
+}
+
+impl Inputs {
+    fn new(option: &FxOption, market: &OptionMarket) -> Result<Self, FxError> {
+        let valuation = market.spot.date();
+        if option.expiry <= valuation {
+            return Err(FxError::InvalidMaturity { spot: valuation });
+        }
+        let years = DayCount::Act365Fixed.year_fraction(valuation, option.expiry);
+        let rd = market.domestic_rate / Decimal::ONE_HUNDRED;
+        let rf = market.foreign_rate / Decimal::ONE_HUNDRED;
+        let discount = |rate: Decimal, percent: Decimal| {
+            rate.checked_mul(years)
+                .and_then(|exponent| (-exponent).checked_exp())
+                .ok_or(FxError::InvalidInterestRate(percent))
+        };
+        Ok(Self {
+            spot: market.spot.rate(),
+            strike: option.strike,
+            rd,
+            rf,
+            years,
+            sqrt_t: years.sqrt().expect("positive time to expiry"),
+            domestic_df: discount(rd, market.domestic_rate)?,
+            foreign_df: discount(rf, market.foreign_rate)?,
+        })
+    }
+
+    fn d1_d2(&self, sigma: Decimal) -> Option<(Decimal, Decimal)> {
+        let spread = sigma.checked_mul(self.sqrt_t)?;
+        let drift = self
+            .rd
+            .checked_sub(self.rf)?
+            .checked_add(sigma.checked_mul(sigma)? / Decimal::TWO)?
+            .checked_mul(self.years)?;
+        let d1 = self
+            .spot
+            .checked_div(self.strike)?
+            .checked_ln()?
+            .checked_add(drift)?
+            .checked_div(spread)?;
+        Some((d1, d1.checked_sub(spread)?))
+    }
+
+    /// Spot and strike discounted at the foreign and domestic rates.
+    fn legs(&self) -> Option<(Decimal, Decimal)> {
+        Some((
+            self.spot.checked_mul(self.foreign_df)?,
+            self.strike.checked_mul(self.domestic_df)?,
+        ))
+    }
+
+    fn premium(&self, kind: OptionKind, sigma: Decimal) -> Option<Decimal> {
+        let (d1, d2) = self.d1_d2(sigma)?;
+        let (forward_leg, strike_leg) = self.legs()?;
+        Some(match kind {
+            OptionKind::Call => forward_leg * norm_cdf(d1) - strike_leg * norm_cdf(d2),
+            OptionKind::Put => strike_leg * norm_cdf(-d2) - forward_leg * norm_cdf(-d1),
+        })
+    }
+}
+
+/// Standard normal CDF, saturated far in the tails where the series overflows.
+fn norm_cdf(x: Decimal) -> Decimal {
+    let limit = Decimal::from(8);
+    if x > limit {
+        Decimal::ONE
+    } else if x < -limit {
+        Decimal::ZERO
+    } else {
+        x.norm_cdf()
+    }
+}
+
+/// Standard normal density, zero where it underflows far in the tails.
+fn norm_pdf(x: Decimal) -> Decimal {
+    x.checked_norm_pdf().unwrap_or(Decimal::ZERO)
+}
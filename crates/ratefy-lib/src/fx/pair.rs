@@ -0,0 +1,132 @@
+//! # pair.rs – CurrencyPair
+//!
+//! A [`CurrencyPair`] names the two currencies of an exchange rate: one unit of
+//! the base currency is worth `rate` units of the quote currency. Pairs parse
+//! from the usual trader notations and know the market quoting convention and
+//! pip size.
+//!
+//! Usage examples are available in
+//! [`docs/types/currency_pair.md`](../../docs/types/currency_pair.md).
+
+use super::error::FxError;
+use crate::money::CurrencyAlpha3;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+
+/// Quoting priority of the major currencies: a currency earlier in the list is
+/// the base of any pair it forms with a later one. Other currencies come after
+/// CHF in alphabetical order, then JPY, which is always quoted.
+const MARKET_PRIORITY: &[&str] = &["EUR", "GBP", "AUD", "NZD", "USD", "CAD", "CHF"];
+
+#[doc = include_str!("../../docs/types/currency_pair.md")]
+/// An ordered pair of currencies, written BASE/QUOTE.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurrencyPair {
+    base: CurrencyAlpha3,
+    quote: CurrencyAlpha3,
+}
+
+impl CurrencyPair {
+    /// Create a pair quoting `quote` units per unit of `base`.
+    pub fn new(base: CurrencyAlpha3, quote: CurrencyAlpha3) -> Result<Self, FxError> {
+        if base == quote {
+            return Err(FxError::SameCurrency(base.code().to_string()));
+        }
+        Ok(Self { base, quote })
+    }
+
+    /// Create the pair of two currencies in market convention, whatever their order.
+    pub fn market(a: CurrencyAlpha3, b: CurrencyAlpha3) -> Result<Self, FxError> {
+        Self::new(a, b).map(|pair| pair.to_market_convention())
+    }
+
+    /// Returns the base currency.
+    pub fn base(&self) -> &CurrencyAlpha3 {
+        &self.base
+    }
+
+    /// Returns the quote currency.
+    pub fn quote(&self) -> &CurrencyAlpha3 {
+        &self.quote
+    }
+
+    /// Returns the pair with base and quote swapped (e.g., USD/EUR for EUR/USD).
+    pub fn inverse(&self) -> Self {
+        Self {
+            base: self.quote.clone(),
+            quote: self.base.clone(),
+        }
+    }
+
+    /// Returns `true` if the pair is written the way the market quotes it.
+    ///
+    /// The order is EUR > GBP > AUD > NZD > USD > CAD > CHF > others
+    /// (alphabetically) > JPY.
+    pub fn is_market_convention(&self) -> bool {
+        market_rank(&self.base) < market_rank(&self.quote)
+    }
+
+    /// Returns the pair in market convention, inverting it if needed.
+    pub fn to_market_convention(&self) -> Self {
+        if self.is_market_convention() {
+            self.clone()
+        } else {
+            self.inverse()
+        }
+    }
+
+    /// Returns the size of one pip: 0.01 for pairs quoted in JPY, 0.0001 otherwise.
+    pub fn pip_size(&self) -> Decimal {
+        if self.quote.code() == "JPY" {
+            Decimal::new(1, 2)
+        } else {
+            Decimal::new(1, 4)
+        }
+    }
+
+    /// Returns `true` if `currency` is the base or the quote of the pair.
+    pub fn contains(&self, currency: &CurrencyAlpha3) -> bool {
+        &self.base == currency || &self.quote == currency
+    }
+}
+
+/// Sort key of a currency in the market quoting order.
+fn market_rank(currency: &CurrencyAlpha3) -> (usize, &'static str) {
+    let code = currency.code();
+    match MARKET_PRIORITY.iter().position(|c| *c == code) {
+        Some(index) => (index, ""),
+        None if code == "JPY" => (MARKET_PRIORITY.len() + 1, ""),
+        None => (MARKET_PRIORITY.len(), code),
+    }
+}
+
+/// Parses "EURUSD", "EUR/USD" or "EUR-USD" (case-insensitive).
+///
+/// Codes longer than three letters, such as "USDT", need a separator.
+impl FromStr for CurrencyPair {
+    type Err = FxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let (base, quote) = match text.split_once(['/', '-']) {
+            Some((base, quote)) => (base.trim(), quote.trim()),
+            None if text.len() == 6 && text.is_ascii() => text.split_at(3),
+            None => return Err(FxError::InvalidPair(text.to_string())),
+        };
+        if base.is_empty() || quote.is_empty() {
+            return Err(FxError::InvalidPair(text.to_string()));
+        }
+        let parse = |code: &str| {
+            CurrencyAlpha3::from_str(code).map_err(|_| FxError::UnknownCurrency(code.to_string()))
+        };
+        Self::new(parse(base)?, parse(quote)?)
+    }
+}
+
+/// Formats the pair as "BASE/QUOTE" (e.g., "EUR/USD").
+impl fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
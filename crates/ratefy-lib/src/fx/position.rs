@@ -0,0 +1,146 @@
+//! # position.rs – Position sizing, pip value and P&L
+//!
+//! A [`PositionCalculator`] answers the usual questions before and after a
+//! trade on a pair: what one pip is worth in the account currency, how large a
+//! position may be for a given risk and stop distance, and what an entry/exit
+//! earned. Amounts in the quote currency are converted into the account
+//! currency with a rate from a [`RateStore`], crossed if needed.
+//!
+//! Usage examples are available in
+//! [`docs/types/position_calculator.md`](../../docs/types/position_calculator.md).
+
+use super::error::FxError;
+use super::pair::CurrencyPair;
+use super::store::RateStore;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// Units of base currency in a standard lot.
+pub const STANDARD_LOT: Decimal = Decimal::from_parts(100_000, 0, 0, false, 0);
+
+#[doc = include_str!("../../docs/types/position_calculator.md")]
+/// Trade calculations on a pair, expressed in an account currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionCalculator {
+    pair: CurrencyPair,
+    account: CurrencyAlpha3,
+    /// Account currency units per quote currency unit.
+    quote_to_account: Decimal,
+}
+
+impl PositionCalculator {
+    /// Create a calculator, looking up the quote → account rate on `date`
+    /// (latest if `None`) in `rates`.
+    pub fn new(
+        pair: CurrencyPair,
+        account: CurrencyAlpha3,
+        rates: &RateStore,
+        date: Option<NaiveDate>,
+    ) -> Result<Self, FxError> {
+        let quote_to_account = if pair.quote() == &account {
+            Decimal::ONE
+        } else {
+            rates.rate(pair.quote(), &account, date)?.rate()
+        };
+        Self::with_conversion(pair, account, quote_to_account)
+    }
+
+    /// Create a calculator with an explicit quote → account conversion rate.
+    ///
+    /// Returns an error if the rate is zero or negative.
+    pub fn with_conversion(
+        pair: CurrencyPair,
+        account: CurrencyAlpha3,
+        quote_to_account: Decimal,
+    ) -> Result<Self, FxError> {
+        if quote_to_account <= Decimal::ZERO {
+            return Err(FxError::NonPositiveRate);
+        }
+        Ok(Self {
+            pair,
+            account,
+            quote_to_account,
+        })
+    }
+
+    /// Returns the currency pair.
+    pub fn pair(&self) -> &CurrencyPair {
+        &self.pair
+    }
+
+    /// Returns the account currency.
+    pub fn account(&self) -> &CurrencyAlpha3 {
+        &self.account
+    }
+
+    /// Returns the value of one pip for a position of `units` of base currency.
+    pub fn pip_value(&self, units: Decimal) -> Result<Money, FxError> {
+        let quote_amount = units
+            .abs()
+            .checked_mul(self.pair.pip_size())
+            .ok_or_else(|| self.out_of_range(units))?;
+        self.in_account(quote_amount)
+    }
+
+    /// Returns the position, in base currency units, that loses `risk` if the
+    /// price moves `stop_pips` against it; the sign of `stop_pips` is ignored.
+    pub fn position_size(&self, risk: &Money, stop_pips: Decimal) -> Result<Money, FxError> {
+        if risk.currency() != &self.account {
+            return Err(FxError::CurrencyMismatch {
+                pair: self.pair.to_string(),
+                currency: risk.currency().to_string(),
+            });
+        }
+        if stop_pips.is_zero() {
+            return Err(FxError::InvalidStop(stop_pips));
+        }
+        let loss_per_unit = stop_pips
+            .abs()
+            .checked_mul(self.pip_value(Decimal::ONE)?.amount())
+            .ok_or_else(|| self.out_of_range(stop_pips))?;
+        // A stop so small that the loss per unit rounds to zero allows no finite size.
+        let units = risk
+            .amount()
+            .checked_div(loss_per_unit)
+            .ok_or_else(|| self.out_of_range(risk.amount()))?;
+        Ok(Money::new(units, self.pair.base().clone()))
+    }
+
+    /// Returns the price move from `entry` to `exit` in pips, positive when the
+    /// pair rose.
+    pub fn pips_between(&self, entry: Decimal, exit: Decimal) -> Result<Decimal, FxError> {
+        exit.checked_sub(entry)
+            .and_then(|change| change.checked_div(self.pair.pip_size()))
+            .ok_or_else(|| self.out_of_range(exit))
+    }
+
+    /// Returns the profit (or loss, negative) of `units` of base currency bought
+    /// at `entry` and sold at `exit`; use negative units for short positions.
+    pub fn profit_loss(
+        &self,
+        units: Decimal,
+        entry: Decimal,
+        exit: Decimal,
+    ) -> Result<Money, FxError> {
+        let quote_amount = exit
+            .checked_sub(entry)
+            .and_then(|change| units.checked_mul(change))
+            .ok_or_else(|| self.out_of_range(units))?;
+        self.in_account(quote_amount)
+    }
+
+    fn in_account(&self, quote_amount: Decimal) -> Result<Money, FxError> {
+        let amount = quote_amount
+            .checked_mul(self.quote_to_account)
+            .ok_or_else(|| self.out_of_range(quote_amount))?;
+        Ok(Money::new(amount, self.account.clone()))
+    }
+
+    fn out_of_range(&self, amount: Decimal) -> FxError {
+        FxError::AmountOutOfRange {
+            pair: self.pair.to_string(),
+            amount,
+        }
+    }
+}
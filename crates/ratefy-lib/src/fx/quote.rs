@@ -0,0 +1,127 @@
+//! # quote.rs – Quote
+//!
+//! A [`Quote`] is a two-way price for a currency pair: the dealer buys the base
+//! currency at the bid and sells it at the ask. Converting at a quote always
+//! uses the side that is less favourable to the customer, as a bank would.
+//!
+//! Usage examples are available in
+//! [`docs/types/quote.md`](../../docs/types/quote.md).
+
+use super::error::FxError;
+use super::pair::CurrencyPair;
+use super::rate::ExchangeRate;
+use crate::money::Money;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+#[doc = include_str!("../../docs/types/quote.md")]
+/// Dated bid/ask quote for a currency pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    pair: CurrencyPair,
+    bid: Decimal,
+    ask: Decimal,
+    date: NaiveDate,
+}
+
+impl Quote {
+    /// Create a quote; the bid must be positive and not above the ask.
+    pub fn new(
+        pair: CurrencyPair,
+        bid: Decimal,
+        ask: Decimal,
+        date: NaiveDate,
+    ) -> Result<Self, FxError> {
+        if bid <= Decimal::ZERO {
+            return Err(FxError::NonPositiveRate);
+        }
+        if bid > ask {
+            return Err(FxError::CrossedQuote { bid, ask });
+        }
+        Ok(Self {
+            pair,
+            bid,
+            ask,
+            date,
+        })
+    }
+
+    /// Returns the currency pair.
+    pub fn pair(&self) -> &CurrencyPair {
+        &self.pair
+    }
+
+    /// Returns the price at which the dealer buys the base currency.
+    pub fn bid(&self) -> Decimal {
+        self.bid
+    }
+
+    /// Returns the price at which the dealer sells the base currency.
+    pub fn ask(&self) -> Decimal {
+        self.ask
+    }
+
+    /// Returns the mid-market price, halfway between bid and ask.
+    pub fn mid(&self) -> Decimal {
+        // Adding half the spread to the bid stays in range, unlike `bid + ask`.
+        self.bid + (self.ask - self.bid) / Decimal::TWO
+    }
+
+    /// Returns the date of the quote.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the mid-market price as an exchange rate.
+    pub fn mid_rate(&self) -> ExchangeRate {
+        ExchangeRate::new(self.pair.clone(), self.mid(), self.date)
+            .expect("mid of a valid quote is positive")
+    }
+
+    /// Returns the same quote seen from the other side (e.g., USD/EUR from EUR/USD).
+    pub fn inverse(&self) -> Self {
+        Self {
+            pair: self.pair.inverse(),
+            bid: Decimal::ONE / self.ask,
+            ask: Decimal::ONE / self.bid,
+            date: self.date,
+        }
+    }
+
+    /// Returns the spread, ask minus bid, in quote currency.
+    pub fn spread(&self) -> Decimal {
+        self.ask - self.bid
+    }
+
+    /// Returns the spread in pips of the pair.
+    pub fn spread_pips(&self) -> Decimal {
+        self.spread() / self.pair.pip_size()
+    }
+
+    /// Returns the spread as a percentage of the mid price.
+    pub fn spread_percent(&self) -> Decimal {
+        self.spread() / self.mid() * Decimal::ONE_HUNDRED
+    }
+
+    /// Returns the rate applied when converting `money`: the bid when selling the
+    /// base currency, the ask when buying it.
+    pub fn rate_for(&self, money: &Money) -> Result<ExchangeRate, FxError> {
+        let rate = if money.currency() == self.pair.base() {
+            self.bid
+        } else if money.currency() == self.pair.quote() {
+            self.ask
+        } else {
+            return Err(FxError::CurrencyMismatch {
+                pair: self.pair.to_string(),
+                currency: money.currency().to_string(),
+            });
+        };
+        ExchangeRate::new(self.pair.clone(), rate, self.date)
+    }
+
+    /// Converts an amount in either currency of the pair at the side of the quote
+    /// that applies (see [`Quote::rate_for`]).
+    pub fn convert(&self, money: &Money) -> Result<Money, FxError> {
+        self.rate_for(money)?.convert(money)
+    }
+}
@@ -0,0 +1,101 @@
+//! # rate.rs – ExchangeRate
+//!
+//! An [`ExchangeRate`] is the price of one unit of a pair's base currency in its
+//! quote currency on a given day. It converts [`Money`] in either direction and
+//! can be restated in market convention.
+//!
+//! Usage examples are available in
+//! [`docs/types/exchange_rate.md`](../../docs/types/exchange_rate.md).
+
+use super::error::FxError;
+use super::pair::CurrencyPair;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+#[doc = include_str!("../../docs/types/exchange_rate.md")]
+/// Dated exchange rate: 1 base = `rate` quote.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeRate {
+    pair: CurrencyPair,
+    rate: Decimal,
+    date: NaiveDate,
+}
+
+impl ExchangeRate {
+    /// Create a rate for `pair` observed on `date`.
+    pub fn new(pair: CurrencyPair, rate: Decimal, date: NaiveDate) -> Result<Self, FxError> {
+        if rate <= Decimal::ZERO {
+            return Err(FxError::NonPositiveRate);
+        }
+        Ok(Self { pair, rate, date })
+    }
+
+    /// Returns the currency pair.
+    pub fn pair(&self) -> &CurrencyPair {
+        &self.pair
+    }
+
+    /// Returns the base currency.
+    pub fn base(&self) -> &CurrencyAlpha3 {
+        self.pair.base()
+    }
+
+    /// Returns the quote currency.
+    pub fn quote(&self) -> &CurrencyAlpha3 {
+        self.pair.quote()
+    }
+
+    /// Returns the number of quote units per base unit.
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns the date the rate was observed.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the same rate seen from the other side (e.g., USD/EUR from EUR/USD).
+    pub fn inverse(&self) -> Self {
+        Self {
+            pair: self.pair.inverse(),
+            rate: Decimal::ONE / self.rate,
+            date: self.date,
+        }
+    }
+
+    /// Returns the rate in market convention, inverting it if needed.
+    pub fn to_market_convention(&self) -> Self {
+        if self.pair.is_market_convention() {
+            self.clone()
+        } else {
+            self.inverse()
+        }
+    }
+
+    /// Converts an amount in either currency of the pair into the other one.
+    ///
+    /// The result keeps the source, tags and percentage rate of `money`, and is
+    /// dated at the rate's date. Amounts are not rounded; a result that does
+    /// not fit in a `Decimal` is an [`FxError::AmountOutOfRange`].
+    pub fn convert(&self, money: &Money) -> Result<Money, FxError> {
+        let (amount, currency) = if money.currency() == self.base() {
+            (money.amount().checked_mul(self.rate), self.quote())
+        } else if money.currency() == self.quote() {
+            (money.amount().checked_div(self.rate), self.base())
+        } else {
+            return Err(FxError::CurrencyMismatch {
+                pair: self.pair.to_string(),
+                currency: money.currency().to_string(),
+            });
+        };
+        let amount = amount.ok_or_else(|| FxError::AmountOutOfRange {
+            pair: self.pair.to_string(),
+            amount: money.amount(),
+        })?;
+        Ok(money
+            .with_amount_in(amount, currency.clone())
+            .with_timestamp(self.date))
+    }
+}
@@ -0,0 +1,216 @@
+//! # store.rs – RateStore
+//!
+//! A [`RateStore`] keeps dated exchange rates for many pairs and answers "what
+//! was the FROM/TO rate on this day?", inverting stored pairs and crossing two
+//! of them through a common currency (e.g., GBP/JPY from GBP/USD and USD/JPY)
+//! when no direct rate is available.
+//!
+//! Rates load from CSV with a `date,pair,rate` header:
+//!
+//! ```text
+//! date,pair,rate
+//! 2024-03-01,EUR/USD,1.0850
+//! 2024-03-01,USDJPY,150.10
+//! ```
+//!
+//! Usage examples are available in
+//! [`docs/types/rate_store.md`](../../docs/types/rate_store.md).
+
+use super::error::FxError;
+use super::pair::CurrencyPair;
+use super::rate::ExchangeRate;
+use crate::csv_lines::{self, LineTracker};
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Currencies tried first when crossing two rates.
+const PREFERRED_PIVOTS: &[&str] = &["USD", "EUR"];
+
+#[derive(Debug, Deserialize)]
+struct RateRecord {
+    date: String,
+    pair: String,
+    rate: String,
+}
+
+#[doc = include_str!("../../docs/types/rate_store.md")]
+/// Dated exchange rates for many currency pairs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateStore {
+    /// Observations per pair, stored in market convention.
+    pairs: HashMap<CurrencyPair, BTreeMap<NaiveDate, Decimal>>,
+}
+
+impl RateStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rate, replacing any rate already stored for the same pair and day.
+    pub fn insert(&mut self, rate: ExchangeRate) {
+        let market = rate.to_market_convention();
+        self.pairs
+            .entry(market.pair().clone())
+            .or_default()
+            .insert(market.date(), market.rate());
+    }
+
+    /// Reads rates from any CSV source.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, FxError> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(LineTracker::new(reader));
+        let headers = csv_reader
+            .headers()
+            .map_err(|e| FxError::Parse {
+                line: 1,
+                message: e.to_string(),
+            })?
+            .clone();
+        let mut store = Self::new();
+        let mut row = csv::StringRecord::new();
+        while csv_reader
+            .read_record(&mut row)
+            .map_err(|e| FxError::Parse {
+                line: csv_lines::line(&mut csv_reader, e.position()),
+                message: e.to_string(),
+            })?
+        {
+            let line = csv_lines::line(&mut csv_reader, row.position());
+            let parse_error = |message: String| FxError::Parse { line, message };
+            let record: RateRecord = row
+                .deserialize(Some(&headers))
+                .map_err(|e| parse_error(e.to_string()))?;
+            let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+                .map_err(|_| parse_error(format!("invalid date '{}'", record.date)))?;
+            let pair =
+                CurrencyPair::from_str(&record.pair).map_err(|e| parse_error(e.to_string()))?;
+            let value = Decimal::from_str(&record.rate)
+                .map_err(|_| parse_error(format!("invalid rate '{}'", record.rate)))?;
+            let rate =
+                ExchangeRate::new(pair, value, date).map_err(|e| parse_error(e.to_string()))?;
+            store.insert(rate);
+        }
+        Ok(store)
+    }
+
+    /// Reads rates from a CSV file on disk.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, FxError> {
+        let file = File::open(path).map_err(|e| FxError::Io(e.to_string()))?;
+        Self::from_reader(file)
+    }
+
+    /// Returns the number of stored observations.
+    pub fn len(&self) -> usize {
+        self.pairs.values().map(BTreeMap::len).sum()
+    }
+
+    /// Returns `true` if no rate is stored.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns the stored pairs, in market convention.
+    pub fn pairs(&self) -> impl Iterator<Item = &CurrencyPair> {
+        self.pairs.keys()
+    }
+
+    /// Returns every stored observation of a pair, oldest first, quoted as `pair`.
+    pub fn history(&self, pair: &CurrencyPair) -> Vec<ExchangeRate> {
+        let market = pair.to_market_convention();
+        let Some(observations) = self.pairs.get(&market) else {
+            return Vec::new();
+        };
+        observations
+            .iter()
+            .filter_map(|(date, rate)| ExchangeRate::new(market.clone(), *rate, *date).ok())
+            .map(|rate| {
+                if &market == pair {
+                    rate
+                } else {
+                    rate.inverse()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the FROM/TO rate on `date`, or the latest rate if `date` is `None`.
+    ///
+    /// Each pair uses its latest observation on or before the date. Without a
+    /// direct rate, two rates are crossed through a common currency (USD and
+    /// EUR first); the result is then dated at the older of the two observations.
+    pub fn rate(
+        &self,
+        from: &CurrencyAlpha3,
+        to: &CurrencyAlpha3,
+        date: Option<NaiveDate>,
+    ) -> Result<ExchangeRate, FxError> {
+        let pair = CurrencyPair::new(from.clone(), to.clone())?;
+        if let Some(rate) = self.direct(&pair, date) {
+            return Ok(rate);
+        }
+        let mut pivots: Vec<&CurrencyAlpha3> = self
+            .pairs
+            .keys()
+            .flat_map(|p| [p.base(), p.quote()])
+            .filter(|c| *c != from && *c != to)
+            .collect();
+        pivots.sort_by_key(|c| {
+            let rank = PREFERRED_PIVOTS.iter().position(|p| *p == c.code());
+            (rank.unwrap_or(PREFERRED_PIVOTS.len()), c.code())
+        });
+        pivots.dedup();
+        for pivot in pivots {
+            let first = CurrencyPair::new(from.clone(), pivot.clone())?;
+            let second = CurrencyPair::new(pivot.clone(), to.clone())?;
+            if let (Some(a), Some(b)) = (self.direct(&first, date), self.direct(&second, date))
+                && let Some(rate) = a.rate().checked_mul(b.rate())
+            {
+                return ExchangeRate::new(pair, rate, a.date().min(b.date()));
+            }
+        }
+        Err(FxError::MissingRate {
+            pair: pair.to_string(),
+            date,
+        })
+    }
+
+    /// Converts `money` into `to` at the rate on `date` (see [`RateStore::rate`]).
+    ///
+    /// Amounts already in `to` are returned unchanged.
+    pub fn convert(
+        &self,
+        money: &Money,
+        to: &CurrencyAlpha3,
+        date: Option<NaiveDate>,
+    ) -> Result<Money, FxError> {
+        if money.currency() == to {
+            return Ok(money.clone());
+        }
+        self.rate(money.currency(), to, date)?.convert(money)
+    }
+
+    /// Stored rate for a pair in either orientation.
+    fn direct(&self, pair: &CurrencyPair, date: Option<NaiveDate>) -> Option<ExchangeRate> {
+        let market = pair.to_market_convention();
+        let observations = self.pairs.get(&market)?;
+        let (date, rate) = match date {
+            Some(date) => observations.range(..=date).next_back()?,
+            None => observations.last_key_value()?,
+        };
+        let rate = ExchangeRate::new(market.clone(), *rate, *date).ok()?;
+        Some(if &market == pair {
+            rate
+        } else {
+            rate.inverse()
+        })
+    }
+}
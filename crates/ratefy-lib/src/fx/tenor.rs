@@ -0,0 +1,144 @@
+//! # tenor.rs – Tenors and day count conventions
+//!
+//! A [`Tenor`] is the period until a forward settles (1W, 1M, 3M, 1Y…) or an
+//! explicit date. A [`DayCount`] turns the days between two dates into the year
+//! fraction used to accrue simple interest.
+
+use super::error::FxError;
+use crate::money::CurrencyAlpha3;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+
+/// Period from the spot date to the maturity of a forward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tenor {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+    /// Explicit maturity date.
+    Date(NaiveDate),
+}
+
+impl Tenor {
+    /// Returns the maturity date for a given spot date.
+    ///
+    /// Month and year tenors stay on the same day of the month, moving back to
+    /// the last day when it does not exist (e.g., 31 Jan + 1M = 29 Feb 2024).
+    pub fn maturity(&self, spot: NaiveDate) -> Result<NaiveDate, FxError> {
+        let maturity = match *self {
+            Tenor::Days(n) => spot.checked_add_days(Days::new(n.into())),
+            Tenor::Weeks(n) => spot.checked_add_days(Days::new(u64::from(n) * 7)),
+            Tenor::Months(n) => spot.checked_add_months(Months::new(n)),
+            Tenor::Years(n) => n
+                .checked_mul(12)
+                .and_then(|months| spot.checked_add_months(Months::new(months))),
+            Tenor::Date(date) => Some(date),
+        };
+        match maturity {
+            Some(date) if date > spot => Ok(date),
+            _ => Err(FxError::InvalidMaturity { spot }),
+        }
+    }
+}
+
+/// Parses "3D", "1W", "6M", "1Y" (case-insensitive) or a `YYYY-MM-DD` date.
+impl FromStr for Tenor {
+    type Err = FxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim().to_uppercase();
+        if let Ok(date) = NaiveDate::parse_from_str(&text, "%Y-%m-%d") {
+            return Ok(Tenor::Date(date));
+        }
+        let invalid = || FxError::InvalidTenor(s.trim().to_string());
+        let unit = text.chars().last().ok_or_else(invalid)?;
+        let count: u32 = text[..text.len() - unit.len_utf8()]
+            .parse()
+            .map_err(|_| invalid())?;
+        if count == 0 {
+            return Err(invalid());
+        }
+        match unit {
+            'D' => Ok(Tenor::Days(count)),
+            'W' => Ok(Tenor::Weeks(count)),
+            'M' => Ok(Tenor::Months(count)),
+            'Y' => Ok(Tenor::Years(count)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Formats the tenor as "1W", "3M"… or the maturity date.
+impl fmt::Display for Tenor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tenor::Days(n) => write!(f, "{}D", n),
+            Tenor::Weeks(n) => write!(f, "{}W", n),
+            Tenor::Months(n) => write!(f, "{}M", n),
+            Tenor::Years(n) => write!(f, "{}Y", n),
+            Tenor::Date(date) => write!(f, "{}", date),
+        }
+    }
+}
+
+/// Currencies whose money markets accrue interest on an actual/365 basis.
+const ACT_365_CURRENCIES: &[&str] = &["GBP", "AUD", "NZD", "CAD", "HKD", "SGD", "ZAR", "JPY"];
+
+/// Convention turning a period into a fraction of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    /// Actual days over 360 (USD, EUR, CHF and most money markets).
+    Act360,
+    /// Actual days over 365 (GBP, AUD, NZD, CAD, JPY…).
+    Act365Fixed,
+    /// 30/360 US: every month counts 30 days.
+    Thirty360,
+}
+
+impl DayCount {
+    /// Returns the money-market convention of a currency.
+    pub fn money_market(currency: &CurrencyAlpha3) -> Self {
+        if ACT_365_CURRENCIES.contains(&currency.code()) {
+            DayCount::Act365Fixed
+        } else {
+            DayCount::Act360
+        }
+    }
+
+    /// Returns the year fraction between `start` and `end`.
+    pub fn year_fraction(&self, start: NaiveDate, end: NaiveDate) -> Decimal {
+        match self {
+            DayCount::Act360 => Decimal::from((end - start).num_days()) / Decimal::from(360),
+            DayCount::Act365Fixed => Decimal::from((end - start).num_days()) / Decimal::from(365),
+            DayCount::Thirty360 => {
+                let mut d1 = start.day();
+                let mut d2 = end.day();
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 == 30 {
+                    d2 = 30;
+                }
+                let days = 360 * (end.year() - start.year())
+                    + 30 * (end.month() as i32 - start.month() as i32)
+                    + (d2 as i32 - d1 as i32);
+                Decimal::from(days) / Decimal::from(360)
+            }
+        }
+    }
+}
+
+/// Formats the convention as "ACT/360", "ACT/365F" or "30/360".
+impl fmt::Display for DayCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            DayCount::Act360 => "ACT/360",
+            DayCount::Act365Fixed => "ACT/365F",
+            DayCount::Thirty360 => "30/360",
+        };
+        write!(f, "{}", label)
+    }
+}
@@ -0,0 +1,192 @@
+//! # cpi.rs – CpiSeries
+//!
+//! A consumer price index series used to move [`Money`] amounts between the
+//! purchasing power of two dates (nominal ↔ real values).
+//!
+//! The reference date of an amount is the `Money` timestamp. Lookups use the
+//! latest observation on or before the requested date, so monthly series can be
+//! queried with any day of the month.
+//!
+//! Usage examples are available in
+//! [`docs/types/cpi_series.md`](../../docs/types/cpi_series.md).
+
+use super::InflationError;
+use crate::csv_lines::{self, LineTracker};
+use crate::money::Money;
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct CpiRecord {
+    date: String,
+    value: String,
+}
+
+#[doc = include_str!("../../docs/types/cpi_series.md")]
+/// Dated consumer price index observations.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CpiSeries {
+    observations: BTreeMap<NaiveDate, Decimal>,
+}
+
+impl CpiSeries {
+    /// Create an empty series.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the index value observed on `date`.
+    ///
+    /// Returns an error if the value is zero or negative.
+    pub fn insert(&mut self, date: NaiveDate, value: Decimal) -> Result<(), InflationError> {
+        if value <= Decimal::ZERO {
+            return Err(InflationError::NonPositiveIndex(date));
+        }
+        self.observations.insert(date, value);
+        Ok(())
+    }
+
+    /// Reads a series from CSV with a `date,value` header and `YYYY-MM-DD` dates.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, InflationError> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(LineTracker::new(reader));
+        let headers = csv_reader
+            .headers()
+            .map_err(|e| InflationError::Parse {
+                line: 1,
+                message: e.to_string(),
+            })?
+            .clone();
+        let mut series = Self::new();
+        let mut row = csv::StringRecord::new();
+        while csv_reader
+            .read_record(&mut row)
+            .map_err(|e| InflationError::Parse {
+                line: csv_lines::line(&mut csv_reader, e.position()),
+                message: e.to_string(),
+            })?
+        {
+            let line = csv_lines::line(&mut csv_reader, row.position());
+            let record: CpiRecord =
+                row.deserialize(Some(&headers))
+                    .map_err(|e| InflationError::Parse {
+                        line,
+                        message: e.to_string(),
+                    })?;
+            let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d").map_err(|_| {
+                InflationError::Parse {
+                    line,
+                    message: format!("invalid date '{}'", record.date),
+                }
+            })?;
+            let value = record
+                .value
+                .parse::<Decimal>()
+                .map_err(|_| InflationError::Parse {
+                    line,
+                    message: format!("invalid value '{}'", record.value),
+                })?;
+            series.insert(date, value)?;
+        }
+        Ok(series)
+    }
+
+    /// Reads a series from a CSV file on disk.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, InflationError> {
+        let file = File::open(path).map_err(|e| InflationError::Io(e.to_string()))?;
+        Self::from_reader(file)
+    }
+
+    /// Returns the number of observations.
+    pub fn len(&self) -> usize {
+        self.observations.len()
+    }
+
+    /// Returns `true` if the series has no observations.
+    pub fn is_empty(&self) -> bool {
+        self.observations.is_empty()
+    }
+
+    /// Returns the date of the most recent observation.
+    pub fn latest_date(&self) -> Option<NaiveDate> {
+        self.observations.keys().next_back().copied()
+    }
+
+    /// Returns the index value in force on `date`: the latest observation on or before it.
+    pub fn value_at(&self, date: NaiveDate) -> Result<Decimal, InflationError> {
+        if self.is_empty() {
+            return Err(InflationError::EmptySeries);
+        }
+        self.observations
+            .range(..=date)
+            .next_back()
+            .map(|(_, value)| *value)
+            .ok_or(InflationError::OutOfRange(date))
+    }
+
+    /// Cumulative inflation between two dates, as a percentage.
+    ///
+    /// For example, an index moving from 100 to 125 gives 25.
+    pub fn cumulative_inflation(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Decimal, InflationError> {
+        let ratio = self.ratio(from, to)?;
+        percent_change(ratio).ok_or(InflationError::Overflow { from, to })
+    }
+
+    /// Annualized (compound) inflation between two dates, as a percentage per year of 365 days.
+    pub fn annualized_inflation(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Decimal, InflationError> {
+        let days = (to - from).num_days();
+        if days == 0 {
+            return Err(InflationError::SameDate);
+        }
+        let ratio = self.ratio(from, to)?;
+        let exponent = Decimal::from(365) / Decimal::from(days);
+        ratio
+            .checked_powd(exponent)
+            .and_then(percent_change)
+            .ok_or(InflationError::Overflow { from, to })
+    }
+
+    /// Expresses `money` (dated by its timestamp) in the purchasing power of `to`.
+    ///
+    /// The result is dated at `to` and keeps the source, tags and rate of `money`.
+    /// Going forward in time turns a past amount into
+    /// today's nominal equivalent; going backwards gives its real value in past terms.
+    pub fn adjust(&self, money: &Money, to: NaiveDate) -> Result<Money, InflationError> {
+        let from = money.timestamp().ok_or(InflationError::MissingTimestamp)?;
+        let amount = money
+            .amount()
+            .checked_mul(self.ratio(from, to)?)
+            .ok_or(InflationError::Overflow { from, to })?;
+        Ok(money
+            .with_amount_in(amount, money.currency().clone())
+            .with_timestamp(to))
+    }
+
+    /// Index value on `to` divided by the index value on `from`.
+    fn ratio(&self, from: NaiveDate, to: NaiveDate) -> Result<Decimal, InflationError> {
+        self.value_at(to)?
+            .checked_div(self.value_at(from)?)
+            .ok_or(InflationError::Overflow { from, to })
+    }
+}
+
+/// Percentage change for a growth `ratio` (1.25 gives 25), or `None` if out of range.
+fn percent_change(ratio: Decimal) -> Option<Decimal> {
+    ratio
+        .checked_sub(Decimal::ONE)?
+        .checked_mul(Decimal::ONE_HUNDRED)
+}
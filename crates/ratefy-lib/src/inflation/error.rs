@@ -0,0 +1,51 @@
+use chrono::NaiveDate;
+use std::fmt;
+
+/// Errors raised while loading a CPI series or adjusting amounts with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InflationError {
+    /// The series has no observations.
+    EmptySeries,
+    /// The `Money` to adjust has no timestamp to take as its reference date.
+    MissingTimestamp,
+    /// The date is earlier than the first observation of the series.
+    OutOfRange(NaiveDate),
+    /// An index value is zero or negative.
+    NonPositiveIndex(NaiveDate),
+    /// Annualizing needs two different dates.
+    SameDate,
+    /// A rate or adjusted amount is too large to represent (e.g. a big move
+    /// annualized over a few days).
+    Overflow { from: NaiveDate, to: NaiveDate },
+    /// A CSV record could not be parsed (1-based line number and reason).
+    Parse { line: usize, message: String },
+    /// The CSV source could not be read.
+    Io(String),
+}
+
+impl fmt::Display for InflationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InflationError::EmptySeries => write!(f, "the CPI series is empty"),
+            InflationError::MissingTimestamp => write!(f, "the amount has no reference date"),
+            InflationError::OutOfRange(date) => {
+                write!(f, "no CPI observation on or before {}", date)
+            }
+            InflationError::NonPositiveIndex(date) => {
+                write!(f, "CPI value for {} is not positive", date)
+            }
+            InflationError::SameDate => write!(f, "dates must differ to annualize"),
+            InflationError::Overflow { from, to } => {
+                write!(
+                    f,
+                    "inflation from {} to {} is too large to represent",
+                    from, to
+                )
+            }
+            InflationError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            InflationError::Io(message) => write!(f, "could not read CPI series: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for InflationError {}
@@ -0,0 +1,5 @@
+pub mod cpi;
+mod error;
+
+pub use cpi::CpiSeries;
+pub use error::InflationError;
@@ -0,0 +1,79 @@
+use super::error::LedgerError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Category of an account, deciding on which side of the trial balance it usually sits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountKind {
+    Asset,
+    Liability,
+    Equity,
+    Income,
+    Expense,
+}
+
+impl AccountKind {
+    /// Returns `true` for kinds whose balance is normally a debit (assets and expenses).
+    pub fn is_debit_normal(&self) -> bool {
+        matches!(self, AccountKind::Asset | AccountKind::Expense)
+    }
+}
+
+impl fmt::Display for AccountKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AccountKind::Asset => "Asset",
+            AccountKind::Liability => "Liability",
+            AccountKind::Equity => "Equity",
+            AccountKind::Income => "Income",
+            AccountKind::Expense => "Expense",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for AccountKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "asset" | "assets" => Ok(AccountKind::Asset),
+            "liability" | "liabilities" => Ok(AccountKind::Liability),
+            "equity" => Ok(AccountKind::Equity),
+            "income" | "revenue" => Ok(AccountKind::Income),
+            "expense" | "expenses" => Ok(AccountKind::Expense),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A named account of the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Account {
+    name: String,
+    kind: AccountKind,
+}
+
+impl Account {
+    /// Create an account; names are ':'-separated segments such as "Assets:Bank:EUR".
+    pub fn new(name: &str, kind: AccountKind) -> Result<Self, LedgerError> {
+        let name = name.trim();
+        if name.is_empty() || name.split(':').any(|segment| segment.trim().is_empty()) {
+            return Err(LedgerError::InvalidAccount(name.to_string()));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            kind,
+        })
+    }
+
+    /// Returns the full account name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the account kind.
+    pub fn kind(&self) -> AccountKind {
+        self.kind
+    }
+}
@@ -0,0 +1,47 @@
+use crate::fx::FxError;
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// Errors raised while recording or reporting journal entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerError {
+    /// Account names are non-empty segments separated by ':' (e.g. "Assets:Bank:EUR").
+    InvalidAccount(String),
+    /// The account is already open.
+    DuplicateAccount(String),
+    /// The posting refers to an account that was never opened.
+    UnknownAccount(String),
+    /// A transaction needs at least two postings.
+    TooFewPostings,
+    /// The postings of a currency do not sum to zero.
+    Unbalanced {
+        currency: String,
+        difference: Decimal,
+    },
+    /// No rate converts a balance into the reporting currency.
+    Fx(FxError),
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerError::InvalidAccount(name) => write!(f, "invalid account name '{}'", name),
+            LedgerError::DuplicateAccount(name) => write!(f, "account '{}' is already open", name),
+            LedgerError::UnknownAccount(name) => write!(f, "unknown account '{}'", name),
+            LedgerError::TooFewPostings => write!(f, "a transaction needs at least two postings"),
+            LedgerError::Unbalanced {
+                currency,
+                difference,
+            } => write!(f, "{} postings are off by {}", currency, difference),
+            LedgerError::Fx(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+impl From<FxError> for LedgerError {
+    fn from(error: FxError) -> Self {
+        LedgerError::Fx(error)
+    }
+}
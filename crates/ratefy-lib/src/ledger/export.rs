@@ -0,0 +1,303 @@
+//! # export.rs – Plain-text accounting export
+//!
+//! Writes journal transactions, currency conversions and exchange rates in the
+//! syntax of the plain-text accounting tools:
+//!
+//! - `ledger` and `hledger` share the same layout: `P` price directives and
+//!   indented postings under a dated description;
+//! - `beancount` needs accounts opened before use, under one of the roots
+//!   `Assets`, `Liabilities`, `Equity`, `Income` and `Expenses`, so accounts
+//!   are placed under the root matching their kind and `open` directives are
+//!   added.
+//!
+//! Price directives come from a [`RateStore`], so that the tools value foreign
+//! balances with the same rates ratefy used. Money tags are written as tags of
+//! their posting (ledger, hledger) or of the transaction (beancount).
+
+use super::account::{Account, AccountKind};
+use super::error::LedgerError;
+use super::journal::{Journal, Posting, Transaction};
+use crate::fx::{FxError, RateStore};
+use crate::money::Money;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[doc = include_str!("../../docs/types/export_format.md")]
+/// Target syntax of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    Ledger,
+    Hledger,
+    Beancount,
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Ledger => write!(f, "ledger"),
+            ExportFormat::Hledger => write!(f, "hledger"),
+            ExportFormat::Beancount => write!(f, "beancount"),
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "ledger" => Ok(ExportFormat::Ledger),
+            "hledger" => Ok(ExportFormat::Hledger),
+            "beancount" | "bean" => Ok(ExportFormat::Beancount),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Exports the price directives of every rate in `rates`, oldest first.
+pub fn export_prices(rates: &RateStore, format: ExportFormat) -> String {
+    let mut prices: Vec<_> = rates.pairs().flat_map(|pair| rates.history(pair)).collect();
+    prices.sort_by_key(|rate| (rate.date(), rate.pair().to_string()));
+    prices
+        .iter()
+        .map(|rate| match format {
+            ExportFormat::Ledger | ExportFormat::Hledger => format!(
+                "P {} {} {} {}\n",
+                rate.date(),
+                rate.base(),
+                rate.rate(),
+                rate.quote()
+            ),
+            ExportFormat::Beancount => format!(
+                "{} price {} {} {}\n",
+                rate.date(),
+                rate.base(),
+                rate.rate(),
+                rate.quote()
+            ),
+        })
+        .collect()
+}
+
+/// Exports every transaction of `journal`, ordered by date, preceded by the
+/// prices of `rates` if given and, for beancount, by the `open` directives.
+pub fn export_journal(
+    journal: &Journal,
+    rates: Option<&RateStore>,
+    format: ExportFormat,
+) -> String {
+    let mut sections = Vec::new();
+    if let Some(rates) = rates.filter(|r| !r.is_empty()) {
+        sections.push(export_prices(rates, format));
+    }
+    let transactions = journal.transactions();
+    let kind = |name: &str| journal.account(name).map(|a| a.kind());
+    if format == ExportFormat::Beancount {
+        let mut opened: BTreeMap<String, NaiveDate> = BTreeMap::new();
+        for transaction in &transactions {
+            for posting in transaction.postings() {
+                let account = beancount_account(&posting.account, kind(&posting.account));
+                opened.entry(account).or_insert(transaction.date());
+            }
+        }
+        let mut opens: Vec<_> = opened.into_iter().collect();
+        opens.sort_by(|a, b| (a.1, &a.0).cmp(&(b.1, &b.0)));
+        sections.push(
+            opens
+                .iter()
+                .map(|(account, date)| format!("{} open {}\n", date, account))
+                .collect(),
+        );
+    }
+    for transaction in transactions {
+        sections.push(write_transaction(transaction, format, &kind));
+    }
+    sections.join("\n")
+}
+
+/// Exports a conversion of `from`, taken out of `from_account`, into `to`,
+/// received in `to_account`, as a transaction priced with the total cost
+/// (`@@`), so the tools record the rate actually obtained.
+///
+/// For beancount, both accounts are placed under the root of their kind and
+/// opened on `date`, so the output is a complete file on its own.
+pub fn export_conversion(
+    date: NaiveDate,
+    description: &str,
+    from_account: &Account,
+    from: &Money,
+    to_account: &Account,
+    to: &Money,
+    format: ExportFormat,
+) -> Result<String, LedgerError> {
+    if from.currency() == to.currency() {
+        return Err(FxError::SameCurrency(from.currency().to_string()).into());
+    }
+    let spent = Money::new(-from.amount().abs(), from.currency().clone());
+    let received = to.amount().abs();
+    let mut output = String::new();
+    let (from_account, to_account) = match format {
+        ExportFormat::Beancount => {
+            let from_account = beancount_account(from_account.name(), Some(from_account.kind()));
+            let to_account = beancount_account(to_account.name(), Some(to_account.kind()));
+            let mut opens = vec![&from_account, &to_account];
+            opens.sort();
+            opens.dedup();
+            for account in opens {
+                output.push_str(&format!("{} open {}\n", date, account));
+            }
+            output.push('\n');
+            (from_account, to_account)
+        }
+        _ => (
+            from_account.name().to_string(),
+            to_account.name().to_string(),
+        ),
+    };
+    output.push_str(&header(date, description, to.tags(), format));
+    output.push_str(&format!(
+        "    {}  {} {} @@ {} {}\n",
+        to_account,
+        received,
+        to.currency(),
+        spent.amount().abs(),
+        spent.currency()
+    ));
+    output.push_str(&format!(
+        "    {}  {} {}\n",
+        from_account,
+        spent.amount(),
+        spent.currency()
+    ));
+    Ok(output)
+}
+
+fn write_transaction(
+    transaction: &Transaction,
+    format: ExportFormat,
+    kind: &dyn Fn(&str) -> Option<AccountKind>,
+) -> String {
+    let tags: Vec<String> = match format {
+        ExportFormat::Beancount => {
+            let mut tags: Vec<String> = Vec::new();
+            for tag in transaction.postings().iter().flat_map(|p| p.amount.tags()) {
+                if !tags.contains(tag) {
+                    tags.push(tag.clone());
+                }
+            }
+            tags
+        }
+        _ => Vec::new(),
+    };
+    let mut output = header(transaction.date(), transaction.description(), &tags, format);
+    for posting in transaction.postings() {
+        output.push_str(&write_posting(posting, format, kind));
+    }
+    output
+}
+
+fn header(date: NaiveDate, description: &str, tags: &[String], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Ledger | ExportFormat::Hledger => format!("{} {}\n", date, description),
+        ExportFormat::Beancount => {
+            let mut line = format!("{} * \"{}\"", date, description.replace('"', "\\\""));
+            for tag in tags {
+                line.push_str(&format!(" #{}", tag_name(tag)));
+            }
+            line.push('\n');
+            line
+        }
+    }
+}
+
+fn write_posting(
+    posting: &Posting,
+    format: ExportFormat,
+    kind: &dyn Fn(&str) -> Option<AccountKind>,
+) -> String {
+    let amount = format!("{} {}", posting.amount.amount(), posting.amount.currency());
+    let tags = posting.amount.tags();
+    match format {
+        ExportFormat::Beancount => format!(
+            "    {}  {}\n",
+            beancount_account(&posting.account, kind(&posting.account)),
+            amount
+        ),
+        ExportFormat::Ledger if !tags.is_empty() => {
+            let tags: String = tags.iter().map(|t| format!("{}:", tag_name(t))).collect();
+            format!("    {}  {}  ; :{}\n", posting.account, amount, tags)
+        }
+        ExportFormat::Hledger if !tags.is_empty() => {
+            let tags: Vec<String> = tags.iter().map(|t| format!("{}:", tag_name(t))).collect();
+            format!(
+                "    {}  {}  ; {}\n",
+                posting.account,
+                amount,
+                tags.join(", ")
+            )
+        }
+        _ => format!("    {}  {}\n", posting.account, amount),
+    }
+}
+
+/// Tags may not contain spaces or the separators of the formats.
+fn tag_name(tag: &str) -> String {
+    tag.trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '/' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Root account names required by beancount for each kind.
+fn beancount_root(kind: AccountKind) -> &'static str {
+    match kind {
+        AccountKind::Asset => "Assets",
+        AccountKind::Liability => "Liabilities",
+        AccountKind::Equity => "Equity",
+        AccountKind::Income => "Income",
+        AccountKind::Expense => "Expenses",
+    }
+}
+
+/// Rewrites an account name into a valid beancount account: every segment
+/// starts with an upper-case letter or digit and holds only letters, digits
+/// and dashes, and the name starts with the root matching `kind`.
+fn beancount_account(name: &str, kind: Option<AccountKind>) -> String {
+    let mut segments: Vec<String> = name
+        .split(':')
+        .map(|segment| {
+            let cleaned: String = segment
+                .trim()
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '-' })
+                .collect();
+            let mut chars = cleaned.chars();
+            match chars.next() {
+                Some(first) if first.is_alphanumeric() => {
+                    first.to_uppercase().chain(chars).collect()
+                }
+                _ => format!("X{}", cleaned),
+            }
+        })
+        .collect();
+    if let Some(kind) = kind {
+        let root = beancount_root(kind);
+        if segments.first().map(String::as_str) != Some(root) {
+            let first = segments[0].as_str();
+            if AccountKind::from_str(first).ok() == Some(kind) {
+                segments[0] = root.to_string();
+            } else {
+                segments.insert(0, root.to_string());
+            }
+        }
+    }
+    segments.join(":")
+}
@@ -0,0 +1,335 @@
+//! # journal.rs – Double-entry journal
+//!
+//! A [`Journal`] holds a chart of accounts and the transactions posted to it.
+//! Every transaction is made of postings of [`Money`]; debits are positive and
+//! credits negative, and the postings of each currency must sum to zero. A
+//! currency exchange therefore goes through a conversion (trading) account that
+//! receives one currency and gives the other, which keeps every currency
+//! balanced on its own and leaves the exchange result visible in that account.
+//!
+//! Usage examples are available in [`docs/types/journal.md`](../../docs/types/journal.md).
+
+use super::account::{Account, AccountKind};
+use super::error::LedgerError;
+use super::revaluation::{Revaluation, RevaluationLine};
+use crate::fx::RateStore;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// One line of a transaction: an amount debited (positive) or credited (negative).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Posting {
+    pub account: String,
+    pub amount: Money,
+}
+
+impl Posting {
+    /// Create a posting to `account`.
+    pub fn new(account: &str, amount: Money) -> Self {
+        Self {
+            account: account.trim().to_string(),
+            amount,
+        }
+    }
+}
+
+/// A dated, balanced set of postings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transaction {
+    date: NaiveDate,
+    description: String,
+    postings: Vec<Posting>,
+}
+
+impl Transaction {
+    /// Create a transaction, checking that it has at least two postings and that
+    /// the postings of every currency sum to zero.
+    pub fn new(
+        date: NaiveDate,
+        description: &str,
+        postings: Vec<Posting>,
+    ) -> Result<Self, LedgerError> {
+        if postings.len() < 2 {
+            return Err(LedgerError::TooFewPostings);
+        }
+        for (currency, difference) in sum_by_currency(postings.iter().map(|p| &p.amount)) {
+            if !difference.is_zero() {
+                return Err(LedgerError::Unbalanced {
+                    currency: currency.to_string(),
+                    difference,
+                });
+            }
+        }
+        Ok(Self {
+            date,
+            description: description.trim().to_string(),
+            postings,
+        })
+    }
+
+    /// Returns the transaction date.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the postings, in entry order.
+    pub fn postings(&self) -> &[Posting] {
+        &self.postings
+    }
+
+    /// Returns the currencies the transaction moves, ordered by code.
+    pub fn currencies(&self) -> Vec<CurrencyAlpha3> {
+        sum_by_currency(self.postings.iter().map(|p| &p.amount))
+            .into_iter()
+            .map(|(currency, _)| currency)
+            .collect()
+    }
+}
+
+/// Balance of one account in one currency, split on the debit or credit side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrialBalanceLine {
+    pub account: String,
+    pub kind: AccountKind,
+    pub debit: Money,
+    pub credit: Money,
+}
+
+/// Debit and credit balances of every account, per currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrialBalance {
+    /// Last date included, or `None` for every transaction.
+    pub date: Option<NaiveDate>,
+    /// One line per account and currency with a non-zero balance.
+    pub lines: Vec<TrialBalanceLine>,
+}
+
+impl TrialBalance {
+    /// Returns the total debits and credits of each currency, ordered by code.
+    pub fn totals(&self) -> Vec<(Money, Money)> {
+        let mut totals: BTreeMap<&str, (CurrencyAlpha3, Decimal, Decimal)> = BTreeMap::new();
+        for line in &self.lines {
+            let currency = line.debit.currency();
+            let entry = totals
+                .entry(currency.code())
+                .or_insert_with(|| (currency.clone(), Decimal::ZERO, Decimal::ZERO));
+            entry.1 += line.debit.amount();
+            entry.2 += line.credit.amount();
+        }
+        totals
+            .into_values()
+            .map(|(currency, debit, credit)| {
+                (
+                    Money::new(debit, currency.clone()),
+                    Money::new(credit, currency),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns `true` if debits equal credits in every currency.
+    pub fn is_balanced(&self) -> bool {
+        self.totals()
+            .iter()
+            .all(|(debit, credit)| debit.amount() == credit.amount())
+    }
+}
+
+impl fmt::Display for TrialBalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.date {
+            Some(date) => writeln!(f, "Trial balance at {}", date)?,
+            None => writeln!(f, "Trial balance")?,
+        }
+        writeln!(f, "{:<32} {:>16} {:>16}", "Account", "Debit", "Credit")?;
+        for line in &self.lines {
+            writeln!(
+                f,
+                "{:<32} {:>16} {:>16}",
+                line.account,
+                side(&line.debit),
+                side(&line.credit)
+            )?;
+        }
+        for (debit, credit) in self.totals() {
+            writeln!(
+                f,
+                "{:<32} {:>16} {:>16}",
+                format!("Total {}", debit.currency()),
+                side(&debit),
+                side(&credit)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats one side of a trial balance line, blank when zero.
+fn side(money: &Money) -> String {
+    if money.amount().is_zero() {
+        String::new()
+    } else {
+        format!("{:.2} {}", money.amount(), money.currency())
+    }
+}
+
+#[doc = include_str!("../../docs/types/journal.md")]
+/// Chart of accounts and the transactions posted to it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Journal {
+    accounts: BTreeMap<String, Account>,
+    transactions: Vec<Transaction>,
+}
+
+impl Journal {
+    /// Create an empty journal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens an account.
+    pub fn open(&mut self, account: Account) -> Result<(), LedgerError> {
+        if self.accounts.contains_key(account.name()) {
+            return Err(LedgerError::DuplicateAccount(account.name().to_string()));
+        }
+        self.accounts.insert(account.name().to_string(), account);
+        Ok(())
+    }
+
+    /// Returns the account named `name`, if open.
+    pub fn account(&self, name: &str) -> Option<&Account> {
+        self.accounts.get(name)
+    }
+
+    /// Returns every open account, ordered by name.
+    pub fn accounts(&self) -> impl Iterator<Item = &Account> {
+        self.accounts.values()
+    }
+
+    /// Records a transaction whose postings all refer to open accounts.
+    pub fn post(&mut self, transaction: Transaction) -> Result<(), LedgerError> {
+        if let Some(posting) = transaction
+            .postings
+            .iter()
+            .find(|p| !self.accounts.contains_key(&p.account))
+        {
+            return Err(LedgerError::UnknownAccount(posting.account.clone()));
+        }
+        self.transactions.push(transaction);
+        Ok(())
+    }
+
+    /// Returns the transactions ordered by date (entry order within a day).
+    pub fn transactions(&self) -> Vec<&Transaction> {
+        let mut transactions: Vec<&Transaction> = self.transactions.iter().collect();
+        transactions.sort_by_key(|t| t.date);
+        transactions
+    }
+
+    /// Returns the balance of `account` in each currency up to `date` included
+    /// (all transactions if `None`), ordered by currency code.
+    pub fn balance(&self, account: &str, date: Option<NaiveDate>) -> Vec<Money> {
+        let amounts = self
+            .postings_until(date)
+            .filter(|p| p.account == account)
+            .map(|p| &p.amount);
+        sum_by_currency(amounts)
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(currency, amount)| Money::new(amount, currency))
+            .collect()
+    }
+
+    /// Lists the debit or credit balance of every account and currency up to `date`.
+    pub fn trial_balance(&self, date: Option<NaiveDate>) -> TrialBalance {
+        let lines = self
+            .accounts
+            .values()
+            .flat_map(|account| {
+                self.balance(account.name(), date)
+                    .into_iter()
+                    .map(|balance| {
+                        let zero = Money::new(Decimal::ZERO, balance.currency().clone());
+                        let (debit, credit) = if balance.amount() > Decimal::ZERO {
+                            (balance, zero)
+                        } else {
+                            let credit = Money::new(-balance.amount(), zero.currency().clone());
+                            (zero, credit)
+                        };
+                        TrialBalanceLine {
+                            account: account.name().to_string(),
+                            kind: account.kind(),
+                            debit,
+                            credit,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        TrialBalance { date, lines }
+    }
+
+    /// Values every balance at `date` in `reporting`, with the latest rates on or
+    /// before that date.
+    ///
+    /// Balances already in the reporting currency are kept as they are; others are
+    /// converted, crossed through USD or EUR if needed.
+    pub fn revalue(
+        &self,
+        date: NaiveDate,
+        reporting: &CurrencyAlpha3,
+        rates: &RateStore,
+    ) -> Result<Revaluation, LedgerError> {
+        let mut lines = Vec::new();
+        for account in self.accounts.values() {
+            for balance in self.balance(account.name(), Some(date)) {
+                let (rate, value) = if balance.currency() == reporting {
+                    (None, balance.clone())
+                } else {
+                    let rate = rates.rate(balance.currency(), reporting, Some(date))?;
+                    let value = rate.convert(&balance)?;
+                    (Some(rate), value)
+                };
+                lines.push(RevaluationLine {
+                    account: account.name().to_string(),
+                    kind: account.kind(),
+                    balance,
+                    rate,
+                    value: value.with_timestamp(date),
+                });
+            }
+        }
+        Ok(Revaluation {
+            date,
+            reporting: reporting.clone(),
+            lines,
+        })
+    }
+
+    fn postings_until(&self, date: Option<NaiveDate>) -> impl Iterator<Item = &Posting> {
+        self.transactions
+            .iter()
+            .filter(move |t| date.is_none_or(|until| t.date <= until))
+            .flat_map(|t| t.postings.iter())
+    }
+}
+
+/// Sums amounts per currency, ordered by currency code.
+fn sum_by_currency<'a>(amounts: impl Iterator<Item = &'a Money>) -> Vec<(CurrencyAlpha3, Decimal)> {
+    let mut sums: BTreeMap<&str, (CurrencyAlpha3, Decimal)> = BTreeMap::new();
+    for money in amounts {
+        let currency = money.currency();
+        sums.entry(currency.code())
+            .or_insert_with(|| (currency.clone(), Decimal::ZERO))
+            .1 += money.amount();
+    }
+    sums.into_values().collect()
+}
@@ -0,0 +1,11 @@
+mod account;
+mod error;
+pub mod export;
+pub mod journal;
+pub mod revaluation;
+
+pub use account::{Account, AccountKind};
+pub use error::LedgerError;
+pub use export::{ExportFormat, export_conversion, export_journal, export_prices};
+pub use journal::{Journal, Posting, Transaction, TrialBalance, TrialBalanceLine};
+pub use revaluation::{Revaluation, RevaluationLine};
@@ -0,0 +1,89 @@
+//! # revaluation.rs – Journal balances at a date's rates
+//!
+//! Foreign currency balances are carried in their own currency by the journal.
+//! A [`Revaluation`] values them in a reporting currency at the rates of a
+//! closing date. Exchanges go through a conversion account holding what was
+//! given in one currency and received in the other; once rates have moved, the
+//! value of that account is the unrealized FX result, which [`Revaluation::entry`]
+//! moves to a gain/loss account.
+
+use super::account::AccountKind;
+use super::error::LedgerError;
+use super::journal::{Posting, Transaction};
+use crate::fx::ExchangeRate;
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// Balance of one account in one currency, valued in the reporting currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevaluationLine {
+    pub account: String,
+    pub kind: AccountKind,
+    /// Balance in its own currency (positive for debit balances).
+    pub balance: Money,
+    /// Rate used, or `None` for balances already in the reporting currency.
+    pub rate: Option<ExchangeRate>,
+    /// Balance valued in the reporting currency.
+    pub value: Money,
+}
+
+/// Journal balances valued in a reporting currency at a date's rates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revaluation {
+    pub date: NaiveDate,
+    pub reporting: CurrencyAlpha3,
+    pub lines: Vec<RevaluationLine>,
+}
+
+impl Revaluation {
+    /// Returns the value of every balance of `account`, in the reporting currency.
+    pub fn account_value(&self, account: &str) -> Money {
+        self.sum(|line| line.account == account)
+    }
+
+    /// Returns the value of every balance of the accounts of `kind`.
+    pub fn kind_value(&self, kind: AccountKind) -> Money {
+        self.sum(|line| line.kind == kind)
+    }
+
+    /// Returns the FX gain (positive) or loss held in `conversion_account`: what
+    /// was received in exchanges is worth that much more than what was given.
+    pub fn fx_gain(&self, conversion_account: &str) -> Money {
+        let value = self.account_value(conversion_account);
+        Money::new(-value.amount(), self.reporting.clone()).with_timestamp(self.date)
+    }
+
+    /// Builds the entry moving the FX result of `conversion_account` to
+    /// `gain_account`, or `None` if there is nothing to recognize.
+    pub fn entry(
+        &self,
+        conversion_account: &str,
+        gain_account: &str,
+    ) -> Result<Option<Transaction>, LedgerError> {
+        let gain = self.fx_gain(conversion_account);
+        if gain.amount().is_zero() {
+            return Ok(None);
+        }
+        let loss = Money::new(-gain.amount(), self.reporting.clone());
+        let transaction = Transaction::new(
+            self.date,
+            &format!("FX revaluation at {}", self.date),
+            vec![
+                Posting::new(conversion_account, gain),
+                Posting::new(gain_account, loss),
+            ],
+        )?;
+        Ok(Some(transaction))
+    }
+
+    fn sum(&self, filter: impl Fn(&RevaluationLine) -> bool) -> Money {
+        let total: Decimal = self
+            .lines
+            .iter()
+            .filter(|line| filter(line))
+            .map(|line| line.value.amount())
+            .sum();
+        Money::new(total, self.reporting.clone()).with_timestamp(self.date)
+    }
+}
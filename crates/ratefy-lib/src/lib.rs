@@ -1,4 +1,15 @@
+pub mod bank;
+pub mod brackets;
+pub mod cashflow;
+mod csv_lines;
+pub mod depreciation;
+pub mod fx;
+pub mod inflation;
+pub mod ledger;
 pub mod money;
+pub mod rates;
+pub mod remittance;
+pub mod repricing;
 use rust_decimal::Decimal;
 use std::str::FromStr;
 
@@ -13,7 +24,7 @@ pub fn apply_percentage_str(
     let money = Money::from_str(base_str.trim(), currency)?;
     let rate = rate_str.trim().parse::<Decimal>().ok()?;
 
-    let result_money = money.apply_rate(rate);
+    let result_money = money.checked_apply_rate(rate)?;
 
     Some((result_money.amount, result_money.currency.to_string()))
 }
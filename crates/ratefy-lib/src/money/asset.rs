@@ -0,0 +1,214 @@
+//! # asset.rs – Registered non-ISO assets
+//!
+//! ISO 4217 only covers national currencies, but amounts are also kept in crypto
+//! assets (BTC, ETH, USDT) and internal units such as loyalty points. This module
+//! keeps a process-wide registry of such assets, each with its own precision.
+//!
+//! Registered assets are wrapped by [`CurrencyAlpha3`] like any ISO currency, so
+//! they work with `Money` and custom `CurrencyGroup`s. They stay clearly apart
+//! from ISO codes: registering an ISO code is rejected, [`CurrencyAlpha3::is_iso`]
+//! is `false` for them, and their [`CurrencyKind`] is `Crypto` or `Custom`.
+//!
+//! BTC, ETH and USDT are registered by default. Usage examples are available in
+//! [`docs/types/asset.md`](../../docs/types/asset.md).
+
+use super::history::CurrencyHistory;
+use super::kind::CurrencyKind;
+use super::model::CurrencyAlpha3;
+use iso_currency::Currency;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+
+static ASSETS: LazyLock<RwLock<HashMap<String, &'static AssetDefinition>>> = LazyLock::new(|| {
+    let defaults = [
+        ("BTC", "Bitcoin", "₿", 8),
+        ("ETH", "Ether", "Ξ", 18),
+        ("USDT", "Tether USD", "₮", 6),
+    ];
+    let assets = defaults
+        .into_iter()
+        .map(|(code, name, symbol, precision)| {
+            let definition =
+                AssetDefinition::new(code, name, symbol, precision, CurrencyKind::Crypto);
+            (code.to_string(), leak(definition))
+        })
+        .collect();
+    RwLock::new(assets)
+});
+
+#[doc = include_str!("../../docs/types/asset.md")]
+/// Description of a non-ISO asset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetDefinition {
+    pub code: String,
+    pub name: String,
+    pub symbol: String,
+    /// Number of decimal places amounts are kept with (e.g. 8 for BTC).
+    pub precision: u16,
+    /// Either [`CurrencyKind::Crypto`] or [`CurrencyKind::Custom`].
+    pub kind: CurrencyKind,
+}
+
+impl AssetDefinition {
+    /// Create a new asset definition; the code is stored in upper case.
+    pub fn new(code: &str, name: &str, symbol: &str, precision: u16, kind: CurrencyKind) -> Self {
+        Self {
+            code: code.trim().to_uppercase(),
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            precision,
+            kind,
+        }
+    }
+}
+
+/// Errors raised while registering non-ISO assets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetError {
+    /// Codes must be 2 to 10 ASCII letters or digits.
+    InvalidCode(String),
+    /// The code is already a current or withdrawn ISO 4217 currency.
+    IsoCode(String),
+    /// The code is registered with a different definition.
+    AlreadyRegistered(String),
+    /// Registered assets must be of kind `Crypto` or `Custom`.
+    InvalidKind(CurrencyKind),
+    /// The configuration file is not valid TOML or does not match the expected layout.
+    Parse(String),
+    /// The configuration file could not be read.
+    Io(String),
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::InvalidCode(code) => write!(f, "invalid asset code '{}'", code),
+            AssetError::IsoCode(code) => write!(f, "'{}' is an ISO 4217 currency", code),
+            AssetError::AlreadyRegistered(code) => {
+                write!(f, "asset '{}' is already registered differently", code)
+            }
+            AssetError::InvalidKind(kind) => {
+                write!(f, "assets must be crypto or custom, not {}", kind)
+            }
+            AssetError::Parse(message) => write!(f, "invalid assets file: {}", message),
+            AssetError::Io(message) => write!(f, "could not read assets: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+#[derive(Debug, Deserialize)]
+struct AssetsFile {
+    #[serde(default)]
+    asset: Vec<AssetEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum AssetEntryKind {
+    Crypto,
+    Custom,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetEntry {
+    code: String,
+    name: String,
+    #[serde(default)]
+    symbol: Option<String>,
+    precision: u16,
+    kind: AssetEntryKind,
+}
+
+/// Registers a non-ISO asset and returns it as a currency.
+///
+/// Registering the same definition twice is allowed and returns the existing asset.
+pub fn register_asset(definition: AssetDefinition) -> Result<CurrencyAlpha3, AssetError> {
+    let code = definition.code.clone();
+    let valid_code =
+        (2..=10).contains(&code.len()) && code.chars().all(|c| c.is_ascii_alphanumeric());
+    if !valid_code {
+        return Err(AssetError::InvalidCode(code));
+    }
+    if Currency::from_str(&code).is_ok() || CurrencyHistory::of(&code).is_some() {
+        return Err(AssetError::IsoCode(code));
+    }
+    if !matches!(definition.kind, CurrencyKind::Crypto | CurrencyKind::Custom) {
+        return Err(AssetError::InvalidKind(definition.kind));
+    }
+
+    let mut assets = ASSETS.write().unwrap_or_else(|e| e.into_inner());
+    match assets.get(&code) {
+        Some(existing) if **existing == definition => Ok(CurrencyAlpha3::from(*existing)),
+        Some(_) => Err(AssetError::AlreadyRegistered(code)),
+        None => {
+            let registered = leak(definition);
+            assets.insert(code, registered);
+            Ok(CurrencyAlpha3::from(registered))
+        }
+    }
+}
+
+/// Returns every registered non-ISO asset, ordered by code.
+pub fn registered_assets() -> Vec<CurrencyAlpha3> {
+    let assets = ASSETS.read().unwrap_or_else(|e| e.into_inner());
+    let mut list: Vec<CurrencyAlpha3> = assets.values().map(|a| CurrencyAlpha3::from(*a)).collect();
+    list.sort_by(|a, b| a.code().cmp(b.code()));
+    list
+}
+
+/// Registers assets defined in TOML such as:
+///
+/// ```toml
+/// [[asset]]
+/// code = "PTS"
+/// name = "Loyalty points"
+/// precision = 0
+/// kind = "custom"
+/// ```
+pub fn register_assets_from_toml(content: &str) -> Result<Vec<CurrencyAlpha3>, AssetError> {
+    let file: AssetsFile = toml::from_str(content).map_err(|e| AssetError::Parse(e.to_string()))?;
+    file.asset
+        .into_iter()
+        .map(|entry| {
+            let kind = match entry.kind {
+                AssetEntryKind::Crypto => CurrencyKind::Crypto,
+                AssetEntryKind::Custom => CurrencyKind::Custom,
+            };
+            let symbol = entry.symbol.unwrap_or_else(|| entry.code.clone());
+            register_asset(AssetDefinition::new(
+                &entry.code,
+                &entry.name,
+                &symbol,
+                entry.precision,
+                kind,
+            ))
+        })
+        .collect()
+}
+
+/// Registers assets from a TOML file (see [`register_assets_from_toml`]).
+pub fn register_assets_from_path<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<CurrencyAlpha3>, AssetError> {
+    let content = fs::read_to_string(path).map_err(|e| AssetError::Io(e.to_string()))?;
+    register_assets_from_toml(&content)
+}
+
+/// Looks up a registered asset by its upper-case code.
+pub(crate) fn lookup(code: &str) -> Option<&'static AssetDefinition> {
+    let assets = ASSETS.read().unwrap_or_else(|e| e.into_inner());
+    assets.get(code).copied()
+}
+
+/// Registered definitions live for the whole process, so that currencies can
+/// hand out `&'static` codes like ISO ones do.
+fn leak(definition: AssetDefinition) -> &'static AssetDefinition {
+    Box::leak(Box::new(definition))
+}
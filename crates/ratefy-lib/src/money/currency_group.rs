@@ -1,24 +1,232 @@
 //! # CurrencyGroup – Documentation
 //!
 //! This module provides the `CurrencyGroup` enum and associated methods
-//! to classify and filter currencies by predefined macro-groups such as G3, G10, and All.
+//! to classify and filter currencies by predefined macro-groups such as G3, G10, and All,
+//! regional and market groups (G20, EM, LATAM, Nordics, APAC), fiat-only currencies,
+//! and user-defined groups loaded from a TOML configuration file.
 //!
 //! Usage examples can be found in [`docs/types/currency_group.md`](../../../docs/types/currency_group.md).
 
+use super::kind::CurrencyKind;
 use super::model::CurrencyAlpha3;
-use iso_currency::Currency;
+use super::registry::{CurrencyMask, CurrencyRegistry};
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::path::Path;
 use std::str::FromStr;
-use strum::IntoEnumIterator;
+use std::sync::LazyLock;
 
+const G3: &[&str] = &["USD", "EUR", "JPY"];
+
+const G10: &[&str] = &[
+    "USD", "EUR", "JPY", "GBP", "CHF", "CAD", "AUD", "NZD", "SEK", "NOK",
+];
+
+const G20: &[&str] = &[
+    "ARS", "AUD", "BRL", "CAD", "CNY", "EUR", "GBP", "IDR", "INR", "JPY", "KRW", "MXN", "RUB",
+    "SAR", "TRY", "USD", "ZAR",
+];
+
+const EMERGING: &[&str] = &[
+    "AED", "BRL", "CLP", "CNY", "COP", "CZK", "EGP", "HUF", "IDR", "INR", "KRW", "KWD", "MXN",
+    "MYR", "PEN", "PHP", "PLN", "QAR", "SAR", "THB", "TRY", "TWD", "ZAR",
+];
+
+const LATAM: &[&str] = &[
+    "ARS", "BOB", "BRL", "CLP", "COP", "CRC", "DOP", "GTQ", "HNL", "MXN", "NIO", "PAB", "PEN",
+    "PYG", "UYU", "VES",
+];
+
+const NORDICS: &[&str] = &["DKK", "ISK", "NOK", "SEK"];
+
+const ASIA_PACIFIC: &[&str] = &[
+    "AUD", "CNY", "HKD", "IDR", "INR", "JPY", "KRW", "MYR", "NZD", "PHP", "SGD", "THB", "TWD",
+    "VND",
+];
+
+/// Members of a built-in group resolved against the registry once, on first use.
+struct GroupTable {
+    members: Vec<CurrencyAlpha3>,
+    mask: CurrencyMask,
+}
+
+impl GroupTable {
+    fn new(codes: &[&str]) -> Self {
+        let registry = CurrencyRegistry::global();
+        Self {
+            members: codes
+                .iter()
+                .filter_map(|code| registry.get(code).cloned())
+                .collect(),
+            mask: registry.mask_of(codes),
+        }
+    }
+
+    fn from_currencies<'a>(currencies: impl Iterator<Item = &'a CurrencyAlpha3>) -> Self {
+        let registry = CurrencyRegistry::global();
+        let mut mask = CurrencyMask::default();
+        let members = currencies
+            .inspect(|c| {
+                if let Some(index) = registry.index_of(c) {
+                    mask.insert(index);
+                }
+            })
+            .cloned()
+            .collect();
+        Self { members, mask }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CurrencyGroup {
     G3,
     G10,
+    G20,
+    Emerging,
+    Latam,
+    Nordics,
+    AsiaPacific,
+    /// Every ISO 4217 code classified as [`CurrencyKind::Fiat`].
+    Fiat,
     All,
+    /// A user-defined group, usually loaded with [`CurrencyGroup::load_custom`].
+    Custom {
+        name: String,
+        currencies: Vec<CurrencyAlpha3>,
+    },
+}
+
+/// Errors raised while loading user-defined currency groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurrencyGroupError {
+    /// The configuration file could not be read.
+    Io(String),
+    /// The configuration file is not valid TOML or does not match the expected layout.
+    Parse(String),
+    /// A group lists a code that is not an ISO 4217 currency.
+    UnknownCurrency { group: String, code: String },
+    /// A group has no currencies.
+    EmptyGroup(String),
+}
+
+impl fmt::Display for CurrencyGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurrencyGroupError::Io(message) => {
+                write!(f, "could not read currency groups: {}", message)
+            }
+            CurrencyGroupError::Parse(message) => {
+                write!(f, "invalid currency groups file: {}", message)
+            }
+            CurrencyGroupError::UnknownCurrency { group, code } => {
+                write!(f, "group '{}' has unknown currency '{}'", group, code)
+            }
+            CurrencyGroupError::EmptyGroup(group) => write!(f, "group '{}' is empty", group),
+        }
+    }
+}
+
+impl std::error::Error for CurrencyGroupError {}
+
+#[derive(Debug, Deserialize)]
+struct GroupsFile {
+    #[serde(default)]
+    group: Vec<GroupEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GroupEntry {
+    name: String,
+    currencies: Vec<String>,
 }
 
 impl CurrencyGroup {
+    /// Returns every built-in group, in the order shown by the currency selector.
+    pub fn builtin() -> Vec<CurrencyGroup> {
+        vec![
+            CurrencyGroup::G3,
+            CurrencyGroup::G10,
+            CurrencyGroup::G20,
+            CurrencyGroup::Emerging,
+            CurrencyGroup::Latam,
+            CurrencyGroup::Nordics,
+            CurrencyGroup::AsiaPacific,
+            CurrencyGroup::Fiat,
+            CurrencyGroup::All,
+        ]
+    }
+
+    /// Returns the display name of the group (e.g. "G10", "LATAM" or a custom name).
+    pub fn name(&self) -> &str {
+        match self {
+            CurrencyGroup::G3 => "G3",
+            CurrencyGroup::G10 => "G10",
+            CurrencyGroup::G20 => "G20",
+            CurrencyGroup::Emerging => "EM",
+            CurrencyGroup::Latam => "LATAM",
+            CurrencyGroup::Nordics => "Nordics",
+            CurrencyGroup::AsiaPacific => "APAC",
+            CurrencyGroup::Fiat => "Fiat",
+            CurrencyGroup::All => "All",
+            CurrencyGroup::Custom { name, .. } => name,
+        }
+    }
+
+    /// Builds a user-defined group from currency codes such as "BRL" or "mxn".
+    ///
+    /// # Errors
+    ///
+    /// Fails if any code is neither an ISO 4217 currency nor a registered asset,
+    /// or if `codes` is empty.
+    pub fn custom<S: AsRef<str>>(name: &str, codes: &[S]) -> Result<Self, CurrencyGroupError> {
+        if codes.is_empty() {
+            return Err(CurrencyGroupError::EmptyGroup(name.to_string()));
+        }
+        let currencies = codes
+            .iter()
+            .map(|code| {
+                CurrencyAlpha3::from_str(code.as_ref().trim()).map_err(|_| {
+                    CurrencyGroupError::UnknownCurrency {
+                        group: name.to_string(),
+                        code: code.as_ref().to_string(),
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(CurrencyGroup::Custom {
+            name: name.to_string(),
+            currencies,
+        })
+    }
+
+    /// Parses user-defined groups from TOML such as:
+    ///
+    /// ```toml
+    /// [[group]]
+    /// name = "Nordics + EUR"
+    /// currencies = ["DKK", "NOK", "SEK", "EUR"]
+    /// ```
+    pub fn parse_custom(content: &str) -> Result<Vec<CurrencyGroup>, CurrencyGroupError> {
+        let file: GroupsFile =
+            toml::from_str(content).map_err(|e| CurrencyGroupError::Parse(e.to_string()))?;
+        file.group
+            .iter()
+            .map(|entry| CurrencyGroup::custom(&entry.name, &entry.currencies))
+            .collect()
+    }
+
+    /// Loads user-defined groups from a TOML file (see [`CurrencyGroup::parse_custom`]).
+    pub fn load_custom<P: AsRef<Path>>(path: P) -> Result<Vec<CurrencyGroup>, CurrencyGroupError> {
+        let content =
+            fs::read_to_string(path).map_err(|e| CurrencyGroupError::Io(e.to_string()))?;
+        CurrencyGroup::parse_custom(&content)
+    }
+
     /// Checks whether the given currency code belongs to the selected group.
     ///
+    /// Built-in groups answer in constant time from a precomputed bitmask.
+    ///
     /// # Arguments
     ///
     /// * `currency` - A reference to a CurrencyAlpha3 instance to check.
@@ -27,20 +235,40 @@ impl CurrencyGroup {
     ///
     /// `true` if the currency is part of the group; `false` otherwise.
     pub fn contains(&self, currency: &CurrencyAlpha3) -> bool {
-        let code = currency.code();
         match self {
-            CurrencyGroup::G3 => ["USD", "EUR", "JPY"].contains(&code),
-            CurrencyGroup::G10 => [
-                "USD", "EUR", "JPY", "GBP", "CHF", "CAD", "AUD", "NZD", "SEK", "NOK",
-            ]
-            .contains(&code),
-            CurrencyGroup::All => Currency::iter()
-                .map(|c| CurrencyAlpha3::from_str(c.code()).unwrap())
-                .collect::<Vec<_>>()
-                .contains(currency),
+            CurrencyGroup::All => CurrencyRegistry::global().index_of(currency).is_some(),
+            CurrencyGroup::Custom { currencies, .. } => currencies.contains(currency),
+            _ => CurrencyRegistry::global()
+                .index_of(currency)
+                .is_some_and(|index| self.table().mask.contains(index)),
         }
     }
 
+    /// Iterates over the currencies of the group without allocating.
+    ///
+    /// See [`CurrencyGroup::list`] for the members of each built-in group.
+    pub fn currencies(&self) -> impl Iterator<Item = &CurrencyAlpha3> + '_ {
+        let members: &[CurrencyAlpha3] = match self {
+            CurrencyGroup::All => CurrencyRegistry::global().all(),
+            CurrencyGroup::Custom { currencies, .. } => currencies,
+            _ => &self.table().members,
+        };
+        members.iter()
+    }
+
+    /// Iterates over the group's currencies of the given kind, e.g. fiat only.
+    pub fn currencies_of_kind(
+        &self,
+        kind: CurrencyKind,
+    ) -> impl Iterator<Item = &CurrencyAlpha3> + '_ {
+        self.currencies().filter(move |c| c.kind() == kind)
+    }
+
+    /// Iterates over the ISO codes of the group's currencies.
+    pub fn codes(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.currencies().map(CurrencyAlpha3::code)
+    }
+
     /// Returns a list of currency codes based on the selected group.
     ///
     /// - G3 includes the three most globally influential currencies: USD, EUR, and JPY.
@@ -49,23 +277,17 @@ impl CurrencyGroup {
     ///   These currencies are regularly exchanged in high volumes and have deep markets
     ///   with minimal impact from individual trades.
     ///   Source: <https://en.wikipedia.org/wiki/G10_currencies>
+    /// - G20 includes the currencies of the G20 member economies (the EU counted as EUR).
+    /// - EM includes the currencies of the major emerging markets.
+    /// - LATAM, Nordics and APAC include the currencies of each region.
+    /// - Fiat includes every ISO 4217 code for circulating money, leaving out precious
+    ///   metals, supranational and fund codes, and testing/no-currency codes.
     /// - All includes every ISO 4217 currency defined in the `iso_currency` crate.
+    /// - Custom groups list their currencies in the order they were defined.
+    ///
+    /// Allocates a new `Vec`; prefer [`CurrencyGroup::codes`] in hot paths.
     pub fn list(&self) -> Vec<String> {
-        match self {
-            CurrencyGroup::G3 => vec!["USD", "EUR", "JPY"]
-                .into_iter()
-                .map(|s| s.to_string())
-                .collect(),
-
-            CurrencyGroup::G10 => vec![
-                "USD", "EUR", "JPY", "GBP", "CHF", "CAD", "AUD", "NZD", "SEK", "NOK",
-            ]
-            .into_iter()
-            .map(|s| s.to_string())
-            .collect(),
-
-            CurrencyGroup::All => Currency::iter().map(|c| c.code().to_string()).collect(),
-        }
+        self.codes().map(str::to_string).collect()
     }
 
     /// Determines whether the given currency code is part of the G10 group.
@@ -78,7 +300,9 @@ impl CurrencyGroup {
     ///
     /// `true` if the code belongs to the G10 set; `false` otherwise.
     pub fn is_g10_currency(code: &str) -> bool {
-        CurrencyGroup::G10.list().iter().any(|c| c == code)
+        CurrencyRegistry::global()
+            .get(code)
+            .is_some_and(|c| CurrencyGroup::G10.contains(c))
     }
 
     /// Determines whether the given currency code is part of the G3 group.
@@ -91,6 +315,46 @@ impl CurrencyGroup {
     ///
     /// `true` if the code belongs to the G3 set; `false` otherwise.
     pub fn is_g3_currency(code: &str) -> bool {
-        CurrencyGroup::G3.list().iter().any(|c| c == code)
+        CurrencyRegistry::global()
+            .get(code)
+            .is_some_and(|c| CurrencyGroup::G3.contains(c))
+    }
+
+    /// Precomputed table of a fixed built-in group.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `All` and `Custom`, which are not backed by a static table.
+    fn table(&self) -> &'static GroupTable {
+        static G3_TABLE: LazyLock<GroupTable> = LazyLock::new(|| GroupTable::new(G3));
+        static G10_TABLE: LazyLock<GroupTable> = LazyLock::new(|| GroupTable::new(G10));
+        static G20_TABLE: LazyLock<GroupTable> = LazyLock::new(|| GroupTable::new(G20));
+        static EMERGING_TABLE: LazyLock<GroupTable> = LazyLock::new(|| GroupTable::new(EMERGING));
+        static LATAM_TABLE: LazyLock<GroupTable> = LazyLock::new(|| GroupTable::new(LATAM));
+        static NORDICS_TABLE: LazyLock<GroupTable> = LazyLock::new(|| GroupTable::new(NORDICS));
+        static ASIA_PACIFIC_TABLE: LazyLock<GroupTable> =
+            LazyLock::new(|| GroupTable::new(ASIA_PACIFIC));
+        static FIAT_TABLE: LazyLock<GroupTable> = LazyLock::new(|| {
+            GroupTable::from_currencies(
+                CurrencyRegistry::global()
+                    .all()
+                    .iter()
+                    .filter(|c| c.is_fiat()),
+            )
+        });
+
+        match self {
+            CurrencyGroup::G3 => &G3_TABLE,
+            CurrencyGroup::G10 => &G10_TABLE,
+            CurrencyGroup::G20 => &G20_TABLE,
+            CurrencyGroup::Emerging => &EMERGING_TABLE,
+            CurrencyGroup::Latam => &LATAM_TABLE,
+            CurrencyGroup::Nordics => &NORDICS_TABLE,
+            CurrencyGroup::AsiaPacific => &ASIA_PACIFIC_TABLE,
+            CurrencyGroup::Fiat => &FIAT_TABLE,
+            CurrencyGroup::All | CurrencyGroup::Custom { .. } => {
+                unreachable!("only fixed built-in groups have a static table")
+            }
+        }
     }
 }
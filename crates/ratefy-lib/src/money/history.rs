@@ -0,0 +1,359 @@
+//! # history.rs – Currency validity windows and redenominations
+//!
+//! Old records carry codes that are no longer in circulation: the legacy euro
+//! currencies (DEM, FRF, ITL…), the Croatian kuna replaced by the euro in 2023,
+//! or the successive Venezuelan bolívars (VEB → VEF → VES). This module keeps,
+//! for such codes, the dates they were legal tender and the currency that
+//! replaced them together with the fixed legal conversion factor.
+//!
+//! Codes withdrawn before the `iso_currency` data was compiled (DEM, FRF, VEF…)
+//! are also described here, so that they parse as [`CurrencyAlpha3`]. Usage
+//! examples are available in [`docs/types/currency_history.md`](../../docs/types/currency_history.md).
+//!
+//! [`CurrencyAlpha3`]: super::CurrencyAlpha3
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+/// Legacy code metadata: name, ISO numeric code and minor units.
+type Legacy = (&'static str, u16, u16);
+
+/// Code, legacy metadata (for codes unknown to `iso_currency`), introduction date,
+/// withdrawal date, then successor, effective date and conversion factor.
+type Entry = (
+    &'static str,
+    Option<Legacy>,
+    Option<&'static str>,
+    Option<&'static str>,
+    Option<(&'static str, &'static str, &'static str)>,
+);
+
+const HISTORY_TABLE: &[Entry] = &[
+    // Legacy euro currencies, at the irrevocably fixed conversion rates.
+    (
+        "ATS",
+        Some(("Austrian schilling", 40, 2)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "13.7603")),
+    ),
+    (
+        "BEF",
+        Some(("Belgian franc", 56, 0)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "40.3399")),
+    ),
+    (
+        "DEM",
+        Some(("Deutsche Mark", 276, 2)),
+        Some("1948-06-21"),
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "1.95583")),
+    ),
+    (
+        "ESP",
+        Some(("Spanish peseta", 724, 0)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "166.386")),
+    ),
+    (
+        "FIM",
+        Some(("Finnish markka", 246, 2)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "5.94573")),
+    ),
+    (
+        "FRF",
+        Some(("French franc", 250, 2)),
+        Some("1960-01-01"),
+        Some("2002-02-18"),
+        Some(("EUR", "1999-01-01", "6.55957")),
+    ),
+    (
+        "IEP",
+        Some(("Irish pound", 372, 2)),
+        None,
+        Some("2002-02-10"),
+        Some(("EUR", "1999-01-01", "0.787564")),
+    ),
+    (
+        "ITL",
+        Some(("Italian lira", 380, 0)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "1936.27")),
+    ),
+    (
+        "LUF",
+        Some(("Luxembourg franc", 442, 0)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "40.3399")),
+    ),
+    (
+        "NLG",
+        Some(("Dutch guilder", 528, 2)),
+        None,
+        Some("2002-01-28"),
+        Some(("EUR", "1999-01-01", "2.20371")),
+    ),
+    (
+        "PTE",
+        Some(("Portuguese escudo", 620, 0)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "1999-01-01", "200.482")),
+    ),
+    (
+        "GRD",
+        Some(("Greek drachma", 300, 0)),
+        None,
+        Some("2002-03-01"),
+        Some(("EUR", "2001-01-01", "340.750")),
+    ),
+    (
+        "SIT",
+        Some(("Slovenian tolar", 705, 2)),
+        None,
+        Some("2007-01-15"),
+        Some(("EUR", "2007-01-01", "239.640")),
+    ),
+    (
+        "CYP",
+        Some(("Cypriot pound", 196, 2)),
+        None,
+        Some("2008-02-01"),
+        Some(("EUR", "2008-01-01", "0.585274")),
+    ),
+    (
+        "MTL",
+        Some(("Maltese lira", 470, 2)),
+        None,
+        Some("2008-02-01"),
+        Some(("EUR", "2008-01-01", "0.429300")),
+    ),
+    (
+        "SKK",
+        Some(("Slovak koruna", 703, 2)),
+        None,
+        Some("2009-01-17"),
+        Some(("EUR", "2009-01-01", "30.1260")),
+    ),
+    (
+        "EEK",
+        Some(("Estonian kroon", 233, 2)),
+        None,
+        Some("2011-01-15"),
+        Some(("EUR", "2011-01-01", "15.6466")),
+    ),
+    (
+        "LVL",
+        Some(("Latvian lats", 428, 2)),
+        None,
+        Some("2014-01-15"),
+        Some(("EUR", "2014-01-01", "0.702804")),
+    ),
+    (
+        "LTL",
+        Some(("Lithuanian litas", 440, 2)),
+        None,
+        Some("2015-01-16"),
+        Some(("EUR", "2015-01-01", "3.45280")),
+    ),
+    (
+        "HRK",
+        None,
+        Some("1994-05-30"),
+        Some("2023-01-15"),
+        Some(("EUR", "2023-01-01", "7.53450")),
+    ),
+    ("EUR", None, Some("1999-01-01"), None, None),
+    // Venezuelan redenominations.
+    (
+        "VEB",
+        Some(("Venezuelan bolívar", 862, 2)),
+        None,
+        Some("2008-01-01"),
+        Some(("VEF", "2008-01-01", "1000")),
+    ),
+    (
+        "VEF",
+        Some(("Venezuelan bolívar fuerte", 937, 2)),
+        Some("2008-01-01"),
+        Some("2018-08-20"),
+        Some(("VES", "2018-08-20", "100000")),
+    ),
+    (
+        "VES",
+        None,
+        Some("2018-08-20"),
+        None,
+        Some(("VED", "2021-10-01", "1000000")),
+    ),
+    ("VED", None, Some("2021-10-01"), None, None),
+    // Sierra Leonean leone redenomination.
+    ("SLL", None, None, None, Some(("SLE", "2022-07-01", "1000"))),
+    ("SLE", None, Some("2022-07-01"), None, None),
+];
+
+static HISTORY: LazyLock<HashMap<&'static str, CurrencyHistory>> = LazyLock::new(|| {
+    let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").expect("valid history date");
+    HISTORY_TABLE
+        .iter()
+        .map(|&(code, legacy, introduced, withdrawn, successor)| {
+            let history = CurrencyHistory {
+                code,
+                legacy,
+                introduced: introduced.map(date),
+                withdrawn: withdrawn.map(date),
+                successor: successor.map(|(code, effective, factor)| Redenomination {
+                    successor: code,
+                    effective: date(effective),
+                    factor: Decimal::from_str(factor).expect("valid conversion factor"),
+                }),
+            };
+            (code, history)
+        })
+        .collect()
+});
+
+/// Replacement of a currency by its successor at a fixed legal rate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Redenomination {
+    /// Code of the replacing currency (e.g., "EUR").
+    pub successor: &'static str,
+    /// Date from which the successor replaced the currency.
+    pub effective: NaiveDate,
+    /// Units of the old currency per unit of the successor (e.g., 1.95583 DEM per EUR).
+    pub factor: Decimal,
+}
+
+#[doc = include_str!("../../docs/types/currency_history.md")]
+/// Validity window and successor of a currency code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurrencyHistory {
+    code: &'static str,
+    legacy: Option<Legacy>,
+    introduced: Option<NaiveDate>,
+    withdrawn: Option<NaiveDate>,
+    successor: Option<Redenomination>,
+}
+
+impl CurrencyHistory {
+    /// Looks up the history of an upper-case code, if it is known.
+    pub fn of(code: &str) -> Option<&'static CurrencyHistory> {
+        HISTORY.get(code)
+    }
+
+    /// Returns the currency code.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// First day the currency was legal tender, if known.
+    pub fn introduced(&self) -> Option<NaiveDate> {
+        self.introduced
+    }
+
+    /// First day the currency was no longer legal tender, if it was withdrawn.
+    pub fn withdrawn(&self) -> Option<NaiveDate> {
+        self.withdrawn
+    }
+
+    /// Returns the currency that replaced this one and the legal conversion factor.
+    pub fn successor(&self) -> Option<&Redenomination> {
+        self.successor.as_ref()
+    }
+
+    /// Returns `true` if the currency was legal tender on `date`.
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        self.introduced.is_none_or(|from| date >= from)
+            && self.withdrawn.is_none_or(|until| date < until)
+    }
+
+    /// Returns a warning if the currency was not legal tender on `date`.
+    pub fn check(&self, date: NaiveDate) -> Option<ValidityWarning> {
+        if let Some(introduced) = self.introduced
+            && date < introduced
+        {
+            return Some(ValidityWarning::NotYetIntroduced {
+                code: self.code,
+                date,
+                introduced,
+            });
+        }
+        if let Some(withdrawn) = self.withdrawn
+            && date >= withdrawn
+        {
+            return Some(ValidityWarning::Withdrawn {
+                code: self.code,
+                date,
+                withdrawn,
+                successor: self.successor.as_ref().map(|r| r.successor),
+            });
+        }
+        None
+    }
+
+    /// Name, numeric code and minor units of codes unknown to `iso_currency`.
+    pub(crate) fn legacy(&self) -> Option<Legacy> {
+        self.legacy
+    }
+}
+
+/// Raised when a currency is used on a date outside its validity window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidityWarning {
+    /// The date is before the currency was introduced.
+    NotYetIntroduced {
+        code: &'static str,
+        date: NaiveDate,
+        introduced: NaiveDate,
+    },
+    /// The date is on or after the currency was withdrawn.
+    Withdrawn {
+        code: &'static str,
+        date: NaiveDate,
+        withdrawn: NaiveDate,
+        successor: Option<&'static str>,
+    },
+}
+
+impl fmt::Display for ValidityWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidityWarning::NotYetIntroduced {
+                code,
+                date,
+                introduced,
+            } => write!(
+                f,
+                "{} used on {} but only introduced on {}",
+                code, date, introduced
+            ),
+            ValidityWarning::Withdrawn {
+                code,
+                date,
+                withdrawn,
+                successor,
+            } => {
+                write!(
+                    f,
+                    "{} used on {} but withdrawn on {}",
+                    code, date, withdrawn
+                )?;
+                if let Some(successor) = successor {
+                    write!(f, " (replaced by {})", successor)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! # kind.rs – CurrencyKind
+//!
+//! Classifies currency codes by what they represent, so that screens and APIs
+//! can leave out codes that are not circulating money (gold, SDRs, test codes,
+//! crypto assets…).
+
+use iso_currency::Currency;
+use std::fmt;
+
+/// What a currency code stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CurrencyKind {
+    /// Money issued by a country or monetary union (USD, EUR, XOF…).
+    Fiat,
+    /// One troy ounce of gold, silver, platinum or palladium (XAU, XAG, XPT, XPD).
+    PreciousMetal,
+    /// Supranational units of account (XDR, XUA, XSU, XBA–XBD) and fund or
+    /// index codes (CLF, MXV, BOV…).
+    Fund,
+    /// Code reserved for testing (XTS).
+    Testing,
+    /// Transactions with no currency involved (XXX).
+    NoCurrency,
+    /// Registered crypto asset outside ISO 4217 (BTC, ETH, USDT…).
+    Crypto,
+    /// Registered user-defined unit outside ISO 4217 (loyalty points, internal tokens…).
+    Custom,
+}
+
+impl CurrencyKind {
+    /// Classifies an `iso_currency::Currency`.
+    pub(crate) fn of(currency: Currency) -> Self {
+        match currency {
+            Currency::XAU | Currency::XAG | Currency::XPT | Currency::XPD => {
+                CurrencyKind::PreciousMetal
+            }
+            Currency::XTS => CurrencyKind::Testing,
+            Currency::XXX => CurrencyKind::NoCurrency,
+            c if c.is_fund() || c.is_special() => CurrencyKind::Fund,
+            _ => CurrencyKind::Fiat,
+        }
+    }
+}
+
+/// Formats the kind as a short lower-case label (e.g., "precious metal").
+impl fmt::Display for CurrencyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            CurrencyKind::Fiat => "fiat",
+            CurrencyKind::PreciousMetal => "precious metal",
+            CurrencyKind::Fund => "supranational/fund",
+            CurrencyKind::Testing => "testing",
+            CurrencyKind::NoCurrency => "no currency",
+            CurrencyKind::Crypto => "crypto",
+            CurrencyKind::Custom => "custom",
+        };
+        write!(f, "{}", label)
+    }
+}
@@ -1,5 +1,21 @@
+mod asset;
 mod currency_group;
+mod history;
+mod kind;
 pub mod model;
+mod price;
+pub mod registry;
 
-pub use currency_group::CurrencyGroup;
+pub use asset::{
+    AssetDefinition, AssetError, register_asset, register_assets_from_path,
+    register_assets_from_toml, registered_assets,
+};
+pub use currency_group::{CurrencyGroup, CurrencyGroupError};
+pub use history::{CurrencyHistory, Redenomination, ValidityWarning};
+pub use kind::CurrencyKind;
 pub use model::{CurrencyAlpha3, Money};
+pub use price::{PriceRule, RoundingDirection};
+pub use registry::CurrencyRegistry;
+
+/// ISO 3166 country, as returned by [`CurrencyAlpha3::countries`].
+pub use iso_country::Country;
@@ -2,7 +2,9 @@
 //!
 //! This module provides core types used across the Ratefy system:
 //!
-//! - [`CurrencyAlpha3`] wraps ISO 4217 currencies with parsing, display, and fallible conversion support.
+//! - [`CurrencyAlpha3`] wraps ISO 4217 currencies with parsing, display, fallible conversion support,
+//!   and metadata (name, symbol, numeric code, minor units, and using countries). It also
+//!   carries registered non-ISO assets such as BTC.
 //! - [`Money`] represents an amount tied to a specific currency and supports metadata,
 //!   percentage rate application, and reversal.
 //!
@@ -10,9 +12,13 @@
 //! - [`docs/types/currency_alpha3.md`](../../docs/types/currency_alpha3.md)
 //! - [`docs/types/money.md`](../../docs/types/money.md)
 
+use super::asset::{self, AssetDefinition};
+use super::history::{CurrencyHistory, ValidityWarning};
+use super::kind::CurrencyKind;
 use chrono::NaiveDate;
+use iso_country::Country;
 use iso_currency::Currency;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::fmt;
 // Trait used for parsing CurrencyAlpha3 from a &str.
 use std::str::FromStr;
@@ -20,28 +26,173 @@ use std::str::FromStr;
 #[doc = include_str!("../../docs/types/currency_alpha3.md")]
 /// Wrapper around iso_currency::Currency to ensure consistent formatting and parsing.
 /// Used throughout the system as the standard currency representation.
+///
+/// It also accepts withdrawn ISO codes such as DEM or VEF (see [`CurrencyHistory`]), and
+/// registered non-ISO assets (see [`register_asset`](super::register_asset)), for which
+/// [`CurrencyAlpha3::is_iso`] returns `false`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurrencyAlpha3(Inner);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CurrencyAlpha3(Currency);
+enum Inner {
+    Iso(Currency),
+    Legacy(&'static CurrencyHistory),
+    Asset(&'static AssetDefinition),
+}
 
 impl CurrencyAlpha3 {
-    /// Returns the 3-letter ISO currency code (e.g., "USD").
-    pub fn code(&self) -> &str {
-        self.0.code()
+    /// Returns the currency code (e.g., "USD", or "BTC" for a registered asset).
+    pub fn code(&self) -> &'static str {
+        match &self.0 {
+            Inner::Iso(currency) => currency.code(),
+            Inner::Legacy(history) => history.code(),
+            Inner::Asset(asset) => &asset.code,
+        }
+    }
+
+    /// Returns the English name of the currency (e.g., "Swiss franc").
+    pub fn name(&self) -> &str {
+        match &self.0 {
+            Inner::Iso(currency) => currency.name(),
+            Inner::Legacy(history) => history.legacy().map_or(history.code(), |l| l.0),
+            Inner::Asset(asset) => &asset.name,
+        }
+    }
+
+    /// Returns the commonly used symbol (e.g., "$" or "€").
+    pub fn symbol(&self) -> String {
+        match &self.0 {
+            Inner::Iso(currency) => currency.symbol().symbol,
+            Inner::Legacy(history) => history.code().to_string(),
+            Inner::Asset(asset) => asset.symbol.clone(),
+        }
+    }
+
+    /// Returns the ISO 4217 numeric code (e.g., 978 for EUR).
+    ///
+    /// Returns `None` for registered non-ISO assets.
+    pub fn numeric(&self) -> Option<u16> {
+        match &self.0 {
+            Inner::Iso(currency) => Some(currency.numeric()),
+            Inner::Legacy(history) => history.legacy().map(|l| l.1),
+            Inner::Asset(_) => None,
+        }
+    }
+
+    /// Returns the number of minor-unit digits (e.g., 2 for EUR, 0 for JPY, 8 for BTC).
+    ///
+    /// Returns `None` for codes without minor units, such as precious metals (XAU).
+    pub fn exponent(&self) -> Option<u16> {
+        match &self.0 {
+            Inner::Iso(currency) => currency.exponent(),
+            Inner::Legacy(history) => history.legacy().map(|l| l.2),
+            Inner::Asset(asset) => Some(asset.precision),
+        }
+    }
+
+    /// Returns the countries using the currency (always empty for withdrawn codes
+    /// unknown to `iso_currency` and for non-ISO assets).
+    pub fn countries(&self) -> Vec<Country> {
+        self.iso()
+            .map(|currency| currency.used_by())
+            .unwrap_or_default()
+    }
+
+    /// Classifies the code as fiat money, precious metal, fund, testing, no currency,
+    /// crypto or custom unit.
+    pub fn kind(&self) -> CurrencyKind {
+        match &self.0 {
+            Inner::Iso(currency) => CurrencyKind::of(*currency),
+            Inner::Legacy(_) => CurrencyKind::Fiat,
+            Inner::Asset(asset) => asset.kind,
+        }
+    }
+
+    /// Returns `true` for currencies issued as money by a country or monetary union.
+    pub fn is_fiat(&self) -> bool {
+        self.kind() == CurrencyKind::Fiat
+    }
+
+    /// Returns `true` for current and withdrawn ISO 4217 currencies and `false` for
+    /// registered assets.
+    pub fn is_iso(&self) -> bool {
+        !matches!(self.0, Inner::Asset(_))
+    }
+
+    /// Returns the validity window and successor of the currency, when known.
+    pub fn history(&self) -> Option<&'static CurrencyHistory> {
+        CurrencyHistory::of(self.code())
+    }
+
+    /// Returns `true` unless the currency is known not to be legal tender on `date`.
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        self.history()
+            .is_none_or(|history| history.is_valid_on(date))
+    }
+
+    /// Looks up a currency by its ISO 4217 numeric code (e.g., 840 for USD).
+    pub fn from_numeric(numeric: u16) -> Option<Self> {
+        Currency::from_numeric(numeric).map(Self::from)
+    }
+
+    /// Returns the currencies used in a country, given its ISO 3166 alpha-2 code (e.g., "CH").
+    ///
+    /// Returns an empty list for unknown country codes.
+    pub fn from_country(alpha2: &str) -> Vec<Self> {
+        Country::from_str(&alpha2.trim().to_uppercase())
+            .map(|country| {
+                Currency::from_country(country)
+                    .into_iter()
+                    .map(Self::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the wrapped `iso_currency::Currency`, or `None` for registered assets.
+    pub(crate) fn iso(&self) -> Option<Currency> {
+        match self.0 {
+            Inner::Iso(currency) => Some(currency),
+            Inner::Legacy(_) | Inner::Asset(_) => None,
+        }
+    }
+}
+
+/// Wraps an `iso_currency::Currency` directly, without parsing.
+impl From<Currency> for CurrencyAlpha3 {
+    fn from(currency: Currency) -> Self {
+        CurrencyAlpha3(Inner::Iso(currency))
+    }
+}
+
+/// Wraps a registered asset definition.
+impl From<&'static AssetDefinition> for CurrencyAlpha3 {
+    fn from(asset: &'static AssetDefinition) -> Self {
+        CurrencyAlpha3(Inner::Asset(asset))
     }
 }
 
 /// Enables parsing a `CurrencyAlpha3` from a string like "usd" or "EUR".
+///
+/// Current ISO 4217 codes take precedence, then withdrawn ones (e.g., "DEM"), then
+/// registered assets.
 impl FromStr for CurrencyAlpha3 {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Currency::from_str(&s.to_uppercase())
-            .map(CurrencyAlpha3)
-            .map_err(|_| ())
+        let code = s.trim().to_uppercase();
+        match Currency::from_str(&code) {
+            Ok(currency) => Ok(Self::from(currency)),
+            Err(_) => CurrencyHistory::of(&code)
+                .filter(|history| history.legacy().is_some())
+                .map(|history| CurrencyAlpha3(Inner::Legacy(history)))
+                .or_else(|| asset::lookup(&code).map(Self::from))
+                .ok_or(()),
+        }
     }
 }
 
-/// Formats the currency using its ISO 4217 alpha-3 code (e.g., "USD").
+/// Formats the currency using its code (e.g., "USD").
 impl fmt::Display for CurrencyAlpha3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.code())
@@ -96,6 +247,103 @@ impl Money {
         &self.currency
     }
 
+    /// Returns the date the amount refers to, if any.
+    pub fn timestamp(&self) -> Option<NaiveDate> {
+        self.timestamp
+    }
+
+    /// Returns a copy of this Money dated at `date`.
+    pub fn with_timestamp(&self, date: NaiveDate) -> Self {
+        Self {
+            timestamp: Some(date),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the percentage rate last applied with `apply_rate()`, if any.
+    pub fn rate(&self) -> Option<Decimal> {
+        self.rate
+    }
+
+    /// Returns a copy recording `rate` as the percentage already applied to the amount,
+    /// so that `revert_rate()` can recover the original (e.g., a price including VAT).
+    pub fn with_rate(&self, rate: Decimal) -> Self {
+        Self {
+            rate: Some(rate),
+            ..self.clone()
+        }
+    }
+
+    /// Returns where the amount comes from (e.g., a bank account or rate provider).
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the descriptive tags attached to the amount.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns a copy of this Money with `source` recorded as its origin.
+    pub fn with_source(&self, source: &str) -> Self {
+        Self {
+            source: Some(source.to_string()),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this Money with `tag` added, unless already present.
+    pub fn with_tag(&self, tag: &str) -> Self {
+        let mut money = self.clone();
+        if !money.tags.iter().any(|t| t == tag) {
+            money.tags.push(tag.to_string());
+        }
+        money
+    }
+
+    /// Returns a warning if the currency was not legal tender on the Money's timestamp.
+    ///
+    /// Undated amounts and currencies without known history never warn.
+    pub fn validity_warning(&self) -> Option<ValidityWarning> {
+        let date = self.timestamp?;
+        self.currency.history()?.check(date)
+    }
+
+    /// Converts the amount into the currency that replaced its currency, at the fixed
+    /// legal conversion factor (e.g., 1.95583 DEM per EUR).
+    ///
+    /// Other metadata, including the timestamp, is kept. Returns `None` if the currency
+    /// has no successor. Call repeatedly to follow chains such as VEB → VEF → VES.
+    pub fn redenominate(&self) -> Option<Self> {
+        let redenomination = self.currency.history()?.successor()?;
+        let successor = CurrencyAlpha3::from_str(redenomination.successor).ok()?;
+        Some(self.with_amount_in(self.amount / redenomination.factor, successor))
+    }
+
+    /// Returns a copy holding `amount` in `currency`, keeping the other metadata.
+    pub(crate) fn with_amount_in(&self, amount: Decimal, currency: CurrencyAlpha3) -> Self {
+        Self {
+            amount,
+            currency,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy rounded to the currency's minor units (e.g., 2 for EUR, 8 for BTC).
+    ///
+    /// Midpoints round away from zero. Codes without minor units are left unchanged.
+    pub fn rounded(&self) -> Self {
+        match self.currency.exponent() {
+            Some(dp) => Self {
+                amount: self
+                    .amount
+                    .round_dp_with_strategy(dp.into(), RoundingStrategy::MidpointAwayFromZero),
+                ..self.clone()
+            },
+            None => self.clone(),
+        }
+    }
+
     /// Applies a percentage rate to the amount (positive or negative).
     ///
     /// For example, a rate of 15 applied to 200 becomes 230.00.
@@ -110,6 +358,17 @@ impl Money {
         }
     }
 
+    /// Like `apply_rate()`, but returns `None` instead of panicking if the
+    /// result does not fit in a `Decimal`.
+    pub fn checked_apply_rate(&self, rate: Decimal) -> Option<Self> {
+        let factor = Decimal::ONE.checked_add(rate.checked_div(Decimal::ONE_HUNDRED)?)?;
+        Some(Self {
+            amount: self.amount.checked_mul(factor)?,
+            rate: Some(rate),
+            ..self.clone()
+        })
+    }
+
     /// Constructs a `Money` instance from a string representation of the amount and a currency.
     ///
     /// Returns `None` if the string cannot be parsed as a valid Decimal.
@@ -122,12 +381,13 @@ impl Money {
 
     /// Attempts to revert a previously applied percentage rate to recover the original amount.
     ///
-    /// Returns `None` if no rate is present or if the rate is zero.
+    /// Returns `None` if no rate is present, or if the rate is zero or -100
+    /// (which leaves nothing to revert from).
     pub fn revert_rate(&self) -> Option<Self> {
         match self.rate {
             Some(r) if !r.is_zero() => {
                 let factor = Decimal::ONE + r / Decimal::ONE_HUNDRED;
-                let original_amount = self.amount / factor;
+                let original_amount = self.amount.checked_div(factor)?;
                 Some(Self {
                     amount: original_amount,
                     rate: None,
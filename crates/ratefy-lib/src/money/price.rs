@@ -0,0 +1,208 @@
+//! # price.rs – Price endings and cash rounding
+//!
+//! Retail prices rarely stay at the amount a markup produces: they are moved
+//! to a psychological ending (19.99, 4.95), to a round step (nearest 5), or to
+//! the smallest coin in circulation for cash payments (0.05 in Switzerland,
+//! 0.10 elsewhere, whole units for the yen). A [`PriceRule`] describes one
+//! such adjustment and applies to any [`Money`], so it composes with
+//! `apply_rate`:
+//!
+//! ```text
+//! 17.40 EUR  ─apply_rate(15)→  20.01 EUR  ─".99 up"→  20.99 EUR
+//! ```
+//!
+//! Rules parse from short strings (`.99`, `.95 down`, `5`, `0.05 up`, `cash`),
+//! so they can be typed in the TUI or given to bulk repricing. Usage examples
+//! are available in [`docs/types/price_rule.md`](../../docs/types/price_rule.md).
+
+use super::model::{CurrencyAlpha3, Money};
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::fmt;
+use std::str::FromStr;
+
+/// Direction in which a [`PriceRule`] moves an amount.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RoundingDirection {
+    /// The closest candidate; halfway amounts go up.
+    #[default]
+    Nearest,
+    /// The closest candidate not below the amount.
+    Up,
+    /// The closest candidate not above the amount.
+    Down,
+}
+
+impl fmt::Display for RoundingDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundingDirection::Nearest => write!(f, "nearest"),
+            RoundingDirection::Up => write!(f, "up"),
+            RoundingDirection::Down => write!(f, "down"),
+        }
+    }
+}
+
+impl FromStr for RoundingDirection {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "nearest" => Ok(RoundingDirection::Nearest),
+            "up" | "ceil" => Ok(RoundingDirection::Up),
+            "down" | "floor" => Ok(RoundingDirection::Down),
+            _ => Err(()),
+        }
+    }
+}
+
+#[doc = include_str!("../../docs/types/price_rule.md")]
+/// An adjustment of a price to an ending, a step, or the smallest coin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceRule {
+    /// Whole units followed by a fixed fraction, e.g. `0.99` for 19.99.
+    Ending {
+        ending: Decimal,
+        direction: RoundingDirection,
+    },
+    /// A multiple of `step`, e.g. 5 for 20, 25, 30…
+    Multiple {
+        step: Decimal,
+        direction: RoundingDirection,
+    },
+    /// The nearest amount payable in coins (see [`PriceRule::cash_step`]).
+    Cash,
+}
+
+impl PriceRule {
+    /// Returns the smallest cash step of a currency: 0.05 for CHF, 0.10 otherwise,
+    /// but never less than one minor unit (1 for JPY, KRW and other currencies
+    /// without decimals).
+    pub fn cash_step(currency: &CurrencyAlpha3) -> Decimal {
+        let step = match currency.code() {
+            "CHF" => Decimal::new(5, 2),
+            _ => Decimal::new(10, 2),
+        };
+        match currency.exponent() {
+            Some(exponent) => step.max(Decimal::new(1, exponent.into())),
+            None => step,
+        }
+    }
+
+    /// Returns `money` moved according to the rule, keeping its metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `Decimal`; see [`PriceRule::checked_apply`].
+    pub fn apply(&self, money: &Money) -> Money {
+        self.checked_apply(money)
+            .expect("price rule result out of range")
+    }
+
+    /// Like [`PriceRule::apply`], but returns `None` if the result does not fit
+    /// in a `Decimal` (e.g. a tiny step on a huge amount).
+    pub fn checked_apply(&self, money: &Money) -> Option<Money> {
+        let amount = money.amount();
+        let adjusted = match *self {
+            PriceRule::Ending { ending, direction } => {
+                // Highest amount with this ending not above the amount.
+                let below = amount.checked_sub(ending)?.floor().checked_add(ending)?;
+                let above = if below == amount {
+                    below
+                } else {
+                    below.checked_add(Decimal::ONE)?
+                };
+                match direction {
+                    RoundingDirection::Down => below,
+                    RoundingDirection::Up => above,
+                    RoundingDirection::Nearest if amount - below < above - amount => below,
+                    RoundingDirection::Nearest => above,
+                }
+            }
+            PriceRule::Multiple { step, direction } => to_multiple(amount, step, direction)?,
+            PriceRule::Cash => to_multiple(
+                amount,
+                Self::cash_step(money.currency()),
+                RoundingDirection::Nearest,
+            )?,
+        };
+        Some(money.with_amount_in(adjusted, money.currency().clone()))
+    }
+}
+
+/// Rounds `amount` to a multiple of `step`; non-positive steps leave it unchanged.
+fn to_multiple(amount: Decimal, step: Decimal, direction: RoundingDirection) -> Option<Decimal> {
+    if step <= Decimal::ZERO {
+        return Some(amount);
+    }
+    let strategy = match direction {
+        RoundingDirection::Nearest => RoundingStrategy::MidpointAwayFromZero,
+        RoundingDirection::Up => RoundingStrategy::ToPositiveInfinity,
+        RoundingDirection::Down => RoundingStrategy::ToNegativeInfinity,
+    };
+    amount
+        .checked_div(step)?
+        .round_dp_with_strategy(0, strategy)
+        .checked_mul(step)
+}
+
+impl fmt::Display for PriceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rule, direction) = match self {
+            PriceRule::Ending { ending, direction } => {
+                let ending = ending.to_string();
+                (ending.trim_start_matches('0').to_string(), direction)
+            }
+            PriceRule::Multiple { step, direction } => (step.to_string(), direction),
+            PriceRule::Cash => return write!(f, "cash"),
+        };
+        match direction {
+            RoundingDirection::Nearest => write!(f, "{}", rule),
+            _ => write!(f, "{} {}", rule, direction),
+        }
+    }
+}
+
+impl FromStr for PriceRule {
+    type Err = ();
+
+    /// Parses `cash`, an ending such as `.99`, or a step such as `5` or `0.05`,
+    /// optionally followed by `up`, `down` or `nearest`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let rule = words.next().ok_or(())?;
+        let direction = match words.next() {
+            Some(word) => RoundingDirection::from_str(word)?,
+            None => RoundingDirection::Nearest,
+        };
+        if words.next().is_some() {
+            return Err(());
+        }
+        if rule.eq_ignore_ascii_case("cash") {
+            return match direction {
+                RoundingDirection::Nearest => Ok(PriceRule::Cash),
+                _ => Err(()),
+            };
+        }
+        if let Some(fraction) = rule.strip_prefix('.') {
+            if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+                return Err(());
+            }
+            let ending = Decimal::from_str(&format!("0.{}", fraction)).map_err(|_| ())?;
+            return Ok(PriceRule::Ending { ending, direction });
+        }
+        let step = Decimal::from_str(rule).map_err(|_| ())?;
+        if step <= Decimal::ZERO {
+            return Err(());
+        }
+        Ok(PriceRule::Multiple { step, direction })
+    }
+}
+
+impl Money {
+    /// Returns a copy moved to a price ending, step or cash amount (see [`PriceRule`]).
+    ///
+    /// The applied rate and other metadata are kept, so rules chain after `apply_rate()`.
+    pub fn with_price_rule(&self, rule: &PriceRule) -> Self {
+        rule.apply(self)
+    }
+}
@@ -0,0 +1,101 @@
+//! # registry.rs – CurrencyRegistry
+//!
+//! A process-wide table of every ISO 4217 currency, built once on first use.
+//! It gives each currency a stable index so that currency sets can be stored as
+//! bitmasks with O(1) membership, and hands out `&'static` references so callers
+//! never need to re-parse or allocate currency codes.
+
+use super::model::CurrencyAlpha3;
+use iso_currency::Currency;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use strum::IntoEnumIterator;
+
+static REGISTRY: LazyLock<CurrencyRegistry> = LazyLock::new(CurrencyRegistry::build);
+
+/// Precomputed table of all ISO 4217 currencies.
+#[derive(Debug)]
+pub struct CurrencyRegistry {
+    currencies: Vec<CurrencyAlpha3>,
+    by_code: HashMap<&'static str, usize>,
+}
+
+impl CurrencyRegistry {
+    /// Returns the shared registry.
+    pub fn global() -> &'static CurrencyRegistry {
+        &REGISTRY
+    }
+
+    fn build() -> Self {
+        let currencies: Vec<CurrencyAlpha3> = Currency::iter().map(CurrencyAlpha3::from).collect();
+        assert!(
+            currencies.len() <= CurrencyMask::CAPACITY,
+            "CurrencyMask is too small for the ISO 4217 table"
+        );
+        let by_code = currencies
+            .iter()
+            .enumerate()
+            .map(|(index, c)| (c.code(), index))
+            .collect();
+        // `index_of` relies on the enum discriminant matching the iteration order.
+        debug_assert!(
+            currencies
+                .iter()
+                .enumerate()
+                .all(|(index, c)| c.iso().map(|iso| iso as usize) == Some(index))
+        );
+        Self {
+            currencies,
+            by_code,
+        }
+    }
+
+    /// Returns every currency, ordered by ISO code.
+    pub fn all(&'static self) -> &'static [CurrencyAlpha3] {
+        &self.currencies
+    }
+
+    /// Looks up a currency by its exact (upper-case) ISO code.
+    pub fn get(&'static self, code: &str) -> Option<&'static CurrencyAlpha3> {
+        self.by_code.get(code).map(|index| &self.currencies[*index])
+    }
+
+    /// Returns the position of `currency` in [`CurrencyRegistry::all`].
+    ///
+    /// Returns `None` for registered non-ISO assets, which are not part of the table.
+    pub fn index_of(&self, currency: &CurrencyAlpha3) -> Option<usize> {
+        currency.iso().map(|iso| iso as usize)
+    }
+
+    /// Builds a mask from ISO codes, ignoring unknown ones.
+    pub fn mask_of(&'static self, codes: &[&str]) -> CurrencyMask {
+        let mut mask = CurrencyMask::default();
+        for index in codes
+            .iter()
+            .filter_map(|code| self.get(code))
+            .filter_map(|currency| self.index_of(currency))
+        {
+            mask.insert(index);
+        }
+        mask
+    }
+}
+
+/// Fixed-size bitset of registry indexes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CurrencyMask([u64; 4]);
+
+impl CurrencyMask {
+    /// Maximum number of currencies a mask can hold.
+    pub const CAPACITY: usize = 256;
+
+    /// Adds the currency at `index`.
+    pub fn insert(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    /// Returns `true` if the currency at `index` is in the mask.
+    pub fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+}
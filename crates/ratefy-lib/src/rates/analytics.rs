@@ -0,0 +1,210 @@
+//! # analytics.rs – Rate series analytics
+//!
+//! Statistics over a [`RateSeries`]. Returns, volatilities and drawdowns are
+//! percentages, consistently with the rest of the crate; rolling windows count
+//! observations, not calendar days.
+//!
+//! Everything is computed with `Decimal`. Logarithms and square roots come from
+//! `rust_decimal`'s mathematical extensions and are accurate to its precision.
+
+use super::RateSeries;
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, MathematicalOps};
+
+/// Largest peak-to-trough fall of a series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drawdown {
+    pub peak_date: NaiveDate,
+    pub trough_date: NaiveDate,
+    /// Fall from peak to trough as a (positive) percentage of the peak.
+    pub percent: Decimal,
+}
+
+/// Minimum, maximum and mean of the rates within a date window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowStats {
+    pub min: Decimal,
+    pub max: Decimal,
+    pub mean: Decimal,
+}
+
+impl RateSeries {
+    /// Simple returns between consecutive observations, as percentages.
+    ///
+    /// Each return is dated at the later observation.
+    pub fn returns(&self) -> Vec<(NaiveDate, Decimal)> {
+        self.pairs()
+            .map(|(_, prev, date, rate)| {
+                (date, (rate / prev - Decimal::ONE) * Decimal::ONE_HUNDRED)
+            })
+            .collect()
+    }
+
+    /// Logarithmic returns between consecutive observations, as percentages.
+    pub fn log_returns(&self) -> Vec<(NaiveDate, Decimal)> {
+        self.pairs()
+            .map(|(_, prev, date, rate)| (date, (rate / prev).ln() * Decimal::ONE_HUNDRED))
+            .collect()
+    }
+
+    /// Sample standard deviation of the log returns (per observation period), as a percentage.
+    ///
+    /// Returns `None` with fewer than three observations.
+    pub fn volatility(&self) -> Option<Decimal> {
+        let returns: Vec<Decimal> = self.log_returns().into_iter().map(|(_, r)| r).collect();
+        std_dev(&returns)
+    }
+
+    /// Volatility scaled to a year of `periods_per_year` observations (e.g. 252 for daily data).
+    pub fn annualized_volatility(&self, periods_per_year: u32) -> Option<Decimal> {
+        let scale = Decimal::from(periods_per_year).sqrt()?;
+        self.volatility().map(|v| v * scale)
+    }
+
+    /// Volatility of the log returns over a rolling window of `window` returns.
+    pub fn rolling_volatility(&self, window: usize) -> Vec<(NaiveDate, Decimal)> {
+        let returns = self.log_returns();
+        if window < 2 || returns.len() < window {
+            return Vec::new();
+        }
+        returns
+            .windows(window)
+            .filter_map(|w| {
+                let values: Vec<Decimal> = w.iter().map(|(_, r)| *r).collect();
+                std_dev(&values).map(|v| (w[window - 1].0, v))
+            })
+            .collect()
+    }
+
+    /// Simple moving average over `window` observations.
+    pub fn sma(&self, window: usize) -> Vec<(NaiveDate, Decimal)> {
+        let points: Vec<(NaiveDate, Decimal)> = self.iter().collect();
+        if window == 0 || points.len() < window {
+            return Vec::new();
+        }
+        points
+            .windows(window)
+            .map(|w| {
+                let sum: Decimal = w.iter().map(|(_, r)| *r).sum();
+                (w[window - 1].0, sum / Decimal::from(window))
+            })
+            .collect()
+    }
+
+    /// Exponential moving average with smoothing `2 / (window + 1)`,
+    /// seeded with the simple average of the first `window` observations.
+    pub fn ema(&self, window: usize) -> Vec<(NaiveDate, Decimal)> {
+        let points: Vec<(NaiveDate, Decimal)> = self.iter().collect();
+        if window == 0 || points.len() < window {
+            return Vec::new();
+        }
+        let alpha = Decimal::TWO / Decimal::from(window + 1);
+        let seed: Decimal =
+            points[..window].iter().map(|(_, r)| *r).sum::<Decimal>() / Decimal::from(window);
+        let mut current = seed;
+        let mut result = vec![(points[window - 1].0, seed)];
+        for (date, rate) in &points[window..] {
+            current = alpha * rate + (Decimal::ONE - alpha) * current;
+            result.push((*date, current));
+        }
+        result
+    }
+
+    /// Largest peak-to-trough decline of the rate.
+    ///
+    /// Returns `None` if the rate never falls below a previous peak.
+    pub fn max_drawdown(&self) -> Option<Drawdown> {
+        let mut peak: Option<(NaiveDate, Decimal)> = None;
+        let mut worst: Option<Drawdown> = None;
+        for (date, rate) in self.iter() {
+            let (peak_date, peak_rate) = match peak {
+                Some(p) if rate <= p.1 => p,
+                _ => {
+                    peak = Some((date, rate));
+                    continue;
+                }
+            };
+            let percent = (peak_rate - rate) / peak_rate * Decimal::ONE_HUNDRED;
+            if percent > Decimal::ZERO && worst.as_ref().is_none_or(|w| percent > w.percent) {
+                worst = Some(Drawdown {
+                    peak_date,
+                    trough_date: date,
+                    percent,
+                });
+            }
+        }
+        worst
+    }
+
+    /// Minimum, maximum and mean of the rates observed between `from` and `to` (inclusive).
+    pub fn window_stats(&self, from: NaiveDate, to: NaiveDate) -> Option<WindowStats> {
+        if from > to {
+            return None;
+        }
+        let values: Vec<Decimal> = self
+            .observations
+            .range(from..=to)
+            .map(|(_, r)| *r)
+            .collect();
+        let min = values.iter().min()?;
+        let max = values.iter().max()?;
+        let mean = values.iter().sum::<Decimal>() / Decimal::from(values.len());
+        Some(WindowStats {
+            min: *min,
+            max: *max,
+            mean,
+        })
+    }
+
+    /// Pearson correlation of the log returns of two series over their common dates.
+    ///
+    /// Returns `None` with fewer than three common returns or if either series is flat.
+    pub fn correlation(&self, other: &RateSeries) -> Option<Decimal> {
+        let common: Vec<(NaiveDate, Decimal, Decimal)> = self
+            .iter()
+            .filter_map(|(date, rate)| other.get(date).map(|o| (date, rate, o)))
+            .collect();
+        let (xs, ys): (Vec<Decimal>, Vec<Decimal>) = common
+            .windows(2)
+            .map(|w| ((w[1].1 / w[0].1).ln(), (w[1].2 / w[0].2).ln()))
+            .unzip();
+        if xs.len() < 3 {
+            return None;
+        }
+        let mean_x = mean(&xs);
+        let mean_y = mean(&ys);
+        let mut covariance = Decimal::ZERO;
+        let mut var_x = Decimal::ZERO;
+        let mut var_y = Decimal::ZERO;
+        for (x, y) in xs.iter().zip(&ys) {
+            covariance += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x) * (x - mean_x);
+            var_y += (y - mean_y) * (y - mean_y);
+        }
+        let denominator = (var_x * var_y).sqrt()?;
+        if denominator.is_zero() {
+            return None;
+        }
+        Some(covariance / denominator)
+    }
+
+    /// Consecutive observations as `(previous date, previous rate, date, rate)`.
+    fn pairs(&self) -> impl Iterator<Item = (NaiveDate, Decimal, NaiveDate, Decimal)> + '_ {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .map(|((d0, r0), (d1, r1))| (d0, r0, d1, r1))
+    }
+}
+
+fn mean(values: &[Decimal]) -> Decimal {
+    values.iter().sum::<Decimal>() / Decimal::from(values.len())
+}
+
+fn std_dev(values: &[Decimal]) -> Option<Decimal> {
+    if values.len() < 2 {
+        return None;
+    }
+    let avg = mean(values);
+    let sum_sq: Decimal = values.iter().map(|v| (v - avg) * (v - avg)).sum();
+    (sum_sq / Decimal::from(values.len() - 1)).sqrt()
+}
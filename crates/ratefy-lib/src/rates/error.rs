@@ -0,0 +1,27 @@
+use chrono::NaiveDate;
+use std::fmt;
+
+/// Errors raised while building or loading a rate series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateSeriesError {
+    /// A rate is zero or negative.
+    NonPositiveRate(NaiveDate),
+    /// A CSV record could not be parsed (1-based line number and reason).
+    Parse { line: usize, message: String },
+    /// The CSV source could not be read.
+    Io(String),
+}
+
+impl fmt::Display for RateSeriesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateSeriesError::NonPositiveRate(date) => {
+                write!(f, "rate for {} is not positive", date)
+            }
+            RateSeriesError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            RateSeriesError::Io(message) => write!(f, "could not read rate series: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RateSeriesError {}
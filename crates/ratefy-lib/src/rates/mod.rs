@@ -0,0 +1,7 @@
+mod analytics;
+mod error;
+pub mod series;
+
+pub use analytics::{Drawdown, WindowStats};
+pub use error::RateSeriesError;
+pub use series::RateSeries;
@@ -0,0 +1,121 @@
+//! # series.rs – RateSeries
+//!
+//! A dated series of exchange rates (one observation per date), the input of the
+//! analytics in [`crate::rates`]: returns, volatility, moving averages, drawdown,
+//! window statistics and correlation.
+//!
+//! Usage examples are available in
+//! [`docs/types/rate_series.md`](../../docs/types/rate_series.md).
+
+use super::RateSeriesError;
+use crate::csv_lines::{self, LineTracker};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct RateRecord {
+    date: String,
+    rate: String,
+}
+
+#[doc = include_str!("../../docs/types/rate_series.md")]
+/// Exchange rate observations ordered by date.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateSeries {
+    pub(crate) observations: BTreeMap<NaiveDate, Decimal>,
+}
+
+impl RateSeries {
+    /// Create an empty series.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the rate observed on `date`.
+    ///
+    /// Returns an error if the rate is zero or negative.
+    pub fn insert(&mut self, date: NaiveDate, rate: Decimal) -> Result<(), RateSeriesError> {
+        if rate <= Decimal::ZERO {
+            return Err(RateSeriesError::NonPositiveRate(date));
+        }
+        self.observations.insert(date, rate);
+        Ok(())
+    }
+
+    /// Reads a series from CSV with a `date,rate` header and `YYYY-MM-DD` dates.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, RateSeriesError> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(LineTracker::new(reader));
+        let headers = csv_reader
+            .headers()
+            .map_err(|e| RateSeriesError::Parse {
+                line: 1,
+                message: e.to_string(),
+            })?
+            .clone();
+        let mut series = Self::new();
+        let mut row = csv::StringRecord::new();
+        while csv_reader
+            .read_record(&mut row)
+            .map_err(|e| RateSeriesError::Parse {
+                line: csv_lines::line(&mut csv_reader, e.position()),
+                message: e.to_string(),
+            })?
+        {
+            let line = csv_lines::line(&mut csv_reader, row.position());
+            let record: RateRecord =
+                row.deserialize(Some(&headers))
+                    .map_err(|e| RateSeriesError::Parse {
+                        line,
+                        message: e.to_string(),
+                    })?;
+            let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d").map_err(|_| {
+                RateSeriesError::Parse {
+                    line,
+                    message: format!("invalid date '{}'", record.date),
+                }
+            })?;
+            let rate = record
+                .rate
+                .parse::<Decimal>()
+                .map_err(|_| RateSeriesError::Parse {
+                    line,
+                    message: format!("invalid rate '{}'", record.rate),
+                })?;
+            series.insert(date, rate)?;
+        }
+        Ok(series)
+    }
+
+    /// Reads a series from a CSV file on disk.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, RateSeriesError> {
+        let file = File::open(path).map_err(|e| RateSeriesError::Io(e.to_string()))?;
+        Self::from_reader(file)
+    }
+
+    /// Returns the number of observations.
+    pub fn len(&self) -> usize {
+        self.observations.len()
+    }
+
+    /// Returns `true` if the series has no observations.
+    pub fn is_empty(&self) -> bool {
+        self.observations.is_empty()
+    }
+
+    /// Returns the rate observed on `date`, if any.
+    pub fn get(&self, date: NaiveDate) -> Option<Decimal> {
+        self.observations.get(&date).copied()
+    }
+
+    /// Iterates over `(date, rate)` observations in date order.
+    pub fn iter(&self) -> impl Iterator<Item = (NaiveDate, Decimal)> + '_ {
+        self.observations.iter().map(|(d, r)| (*d, *r))
+    }
+}
@@ -0,0 +1,73 @@
+//! # compare.rs – Ranking of remittance offers
+//!
+//! Sends the same amount through every offer of a corridor and ranks the offers
+//! by what the recipient receives.
+
+use super::{RemittanceError, RemittanceOffer};
+use crate::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+
+/// Outcome of sending an amount through one offer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemittanceResult {
+    /// Provider name.
+    pub provider: String,
+    /// Units of the receiving currency per unit sent, before fees.
+    pub rate: Decimal,
+    /// Amount the recipient gets.
+    pub received: Money,
+    /// Units received per unit sent, fees included.
+    pub effective_rate: Decimal,
+    /// How much less than the best offer the recipient gets.
+    pub shortfall: Money,
+}
+
+/// Ranks the offers converting `amount` into `to`, best first.
+///
+/// Offers for other currencies are ignored; ties are ordered by provider name.
+pub fn compare(
+    amount: &Money,
+    to: &CurrencyAlpha3,
+    offers: &[RemittanceOffer],
+) -> Result<Vec<RemittanceResult>, RemittanceError> {
+    let mut delivered = offers
+        .iter()
+        .filter(|offer| offer.serves(amount.currency(), to))
+        .map(|offer| Ok((offer, offer.deliver(amount)?)))
+        .collect::<Result<Vec<_>, RemittanceError>>()?;
+    if delivered.is_empty() {
+        return Err(RemittanceError::NoOffers {
+            from: amount.currency().to_string(),
+            to: to.to_string(),
+        });
+    }
+    delivered.sort_by(|(a, a_received), (b, b_received)| {
+        b_received
+            .amount()
+            .cmp(&a_received.amount())
+            .then_with(|| a.provider().cmp(b.provider()))
+    });
+
+    let best = delivered[0].1.amount();
+    Ok(delivered
+        .into_iter()
+        .map(|(offer, received)| {
+            let rate = if offer.rate().base() == amount.currency() {
+                offer.rate().rate()
+            } else {
+                Decimal::ONE / offer.rate().rate()
+            };
+            RemittanceResult {
+                provider: offer.provider().to_string(),
+                rate,
+                effective_rate: if amount.amount().is_zero() {
+                    Decimal::ZERO
+                } else {
+                    received.amount() / amount.amount()
+                },
+                shortfall: Money::new(best - received.amount(), to.clone()),
+                received,
+            }
+        })
+        .collect())
+}
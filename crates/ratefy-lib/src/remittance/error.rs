@@ -0,0 +1,46 @@
+use crate::fx::FxError;
+use std::fmt;
+
+/// Errors raised while loading or comparing remittance offers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemittanceError {
+    /// No offer converts between the requested currencies.
+    NoOffers { from: String, to: String },
+    /// The percentage fee must be at least 0% and below 100%.
+    InvalidPercentFee(String),
+    /// The fixed fee must not be negative.
+    NegativeFixedFee(String),
+    /// The offer's rate or fixed fee does not match its currencies.
+    Fx(FxError),
+    /// A CSV record could not be parsed (1-based line number and reason).
+    Parse { line: usize, message: String },
+    /// The CSV source could not be read.
+    Io(String),
+}
+
+impl fmt::Display for RemittanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemittanceError::NoOffers { from, to } => {
+                write!(f, "no offer converts {} to {}", from, to)
+            }
+            RemittanceError::InvalidPercentFee(fee) => {
+                write!(f, "percentage fee must be between 0 and 100, got {}", fee)
+            }
+            RemittanceError::NegativeFixedFee(fee) => {
+                write!(f, "fixed fee must not be negative, got {}", fee)
+            }
+            RemittanceError::Fx(error) => write!(f, "{}", error),
+            RemittanceError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            RemittanceError::Io(message) => write!(f, "could not read offers: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RemittanceError {}
+
+impl From<FxError> for RemittanceError {
+    fn from(error: FxError) -> Self {
+        RemittanceError::Fx(error)
+    }
+}
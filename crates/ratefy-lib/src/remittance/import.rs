@@ -0,0 +1,88 @@
+//! # import.rs – CSV import of remittance offers
+//!
+//! Reads offers from CSV with a
+//! `provider,pair,rate,fixed_fee,percent_fee,fee_currency,date` header. The rate
+//! is quoted for the pair as written, the percentage fee is in percent, and the
+//! date is in `YYYY-MM-DD` format:
+//!
+//! ```text
+//! provider,pair,rate,fixed_fee,percent_fee,fee_currency,date
+//! Bank,EUR/USD,1.0550,15.00,0,EUR,2024-03-01
+//! Wise,EUR/USD,1.0790,0.85,0.45,EUR,2024-03-01
+//! ```
+
+use super::{RemittanceError, RemittanceOffer};
+use crate::csv_lines::{self, LineTracker};
+use crate::fx::{CurrencyPair, ExchangeRate};
+use crate::money::{CurrencyAlpha3, Money};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize)]
+struct OfferRecord {
+    provider: String,
+    pair: String,
+    rate: String,
+    fixed_fee: String,
+    percent_fee: String,
+    fee_currency: String,
+    date: String,
+}
+
+/// Reads offers from any CSV source.
+pub fn from_reader<R: Read>(reader: R) -> Result<Vec<RemittanceOffer>, RemittanceError> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(LineTracker::new(reader));
+    let headers = csv_reader
+        .headers()
+        .map_err(|e| RemittanceError::Parse {
+            line: 1,
+            message: e.to_string(),
+        })?
+        .clone();
+    let mut offers = Vec::new();
+    let mut row = csv::StringRecord::new();
+    while csv_reader
+        .read_record(&mut row)
+        .map_err(|e| RemittanceError::Parse {
+            line: csv_lines::line(&mut csv_reader, e.position()),
+            message: e.to_string(),
+        })?
+    {
+        let line = csv_lines::line(&mut csv_reader, row.position());
+        let parse_error = |message: String| RemittanceError::Parse { line, message };
+        let record: OfferRecord = row
+            .deserialize(Some(&headers))
+            .map_err(|e| parse_error(e.to_string()))?;
+        let decimal = |field: &str, value: &str| {
+            Decimal::from_str(value)
+                .map_err(|_| parse_error(format!("invalid {} '{}'", field, value)))
+        };
+
+        let pair = CurrencyPair::from_str(&record.pair).map_err(|e| parse_error(e.to_string()))?;
+        let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+            .map_err(|_| parse_error(format!("invalid date '{}'", record.date)))?;
+        let rate = ExchangeRate::new(pair, decimal("rate", &record.rate)?, date)
+            .map_err(|e| parse_error(e.to_string()))?;
+        let fee_currency = CurrencyAlpha3::from_str(&record.fee_currency)
+            .map_err(|_| parse_error(format!("unknown currency '{}'", record.fee_currency)))?;
+        let fixed_fee = Money::new(decimal("fixed fee", &record.fixed_fee)?, fee_currency);
+        let percent_fee = decimal("percentage fee", &record.percent_fee)?;
+        let offer = RemittanceOffer::new(&record.provider, rate, fixed_fee, percent_fee)
+            .map_err(|e| parse_error(e.to_string()))?;
+        offers.push(offer);
+    }
+    Ok(offers)
+}
+
+/// Reads offers from a CSV file on disk.
+pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Vec<RemittanceOffer>, RemittanceError> {
+    let file = File::open(path).map_err(|e| RemittanceError::Io(e.to_string()))?;
+    from_reader(file)
+}
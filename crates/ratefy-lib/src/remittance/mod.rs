@@ -0,0 +1,8 @@
+mod compare;
+mod error;
+pub mod import;
+pub mod offer;
+
+pub use compare::{RemittanceResult, compare};
+pub use error::RemittanceError;
+pub use offer::RemittanceOffer;
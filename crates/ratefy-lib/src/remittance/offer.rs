@@ -0,0 +1,105 @@
+//! # offer.rs – RemittanceOffer
+//!
+//! A [`RemittanceOffer`] is what a money transfer provider charges to send money
+//! from one currency to another: its exchange rate, a fixed fee (in either
+//! currency) and a percentage fee on the amount sent.
+//!
+//! Usage examples are available in
+//! [`docs/types/remittance_offer.md`](../../docs/types/remittance_offer.md).
+
+use super::RemittanceError;
+use crate::fx::{ExchangeRate, FxError};
+use crate::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+
+#[doc = include_str!("../../docs/types/remittance_offer.md")]
+/// A provider's price for converting and sending money.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemittanceOffer {
+    provider: String,
+    rate: ExchangeRate,
+    fixed_fee: Money,
+    percent_fee: Decimal,
+}
+
+impl RemittanceOffer {
+    /// Create an offer; the fixed fee must be in one of the rate's currencies and
+    /// not negative, and the percentage fee between 0 (included) and 100 (excluded).
+    pub fn new(
+        provider: &str,
+        rate: ExchangeRate,
+        fixed_fee: Money,
+        percent_fee: Decimal,
+    ) -> Result<Self, RemittanceError> {
+        if !rate.pair().contains(fixed_fee.currency()) {
+            return Err(FxError::CurrencyMismatch {
+                pair: rate.pair().to_string(),
+                currency: fixed_fee.currency().to_string(),
+            }
+            .into());
+        }
+        if fixed_fee.amount() < Decimal::ZERO {
+            return Err(RemittanceError::NegativeFixedFee(
+                fixed_fee.amount().to_string(),
+            ));
+        }
+        if percent_fee < Decimal::ZERO || percent_fee >= Decimal::ONE_HUNDRED {
+            return Err(RemittanceError::InvalidPercentFee(percent_fee.to_string()));
+        }
+        Ok(Self {
+            provider: provider.trim().to_string(),
+            rate,
+            fixed_fee,
+            percent_fee,
+        })
+    }
+
+    /// Returns the provider name.
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Returns the provider's exchange rate.
+    pub fn rate(&self) -> &ExchangeRate {
+        &self.rate
+    }
+
+    /// Returns the fixed fee.
+    pub fn fixed_fee(&self) -> &Money {
+        &self.fixed_fee
+    }
+
+    /// Returns the percentage fee charged on the amount sent.
+    pub fn percent_fee(&self) -> Decimal {
+        self.percent_fee
+    }
+
+    /// Returns `true` if the offer converts between `from` and `to`.
+    pub fn serves(&self, from: &CurrencyAlpha3, to: &CurrencyAlpha3) -> bool {
+        from != to && self.rate.pair().contains(from) && self.rate.pair().contains(to)
+    }
+
+    /// Returns what the recipient gets for `amount`, never less than zero.
+    ///
+    /// The percentage fee is taken from the amount sent with
+    /// [`Money::apply_rate`]; the fixed fee is taken before conversion when it is
+    /// in the sending currency and from the proceeds otherwise.
+    pub fn deliver(&self, amount: &Money) -> Result<Money, RemittanceError> {
+        let mut net = amount.apply_rate(-self.percent_fee);
+        if self.fixed_fee.currency() == amount.currency() {
+            net = net.with_amount_in(
+                net.amount() - self.fixed_fee.amount(),
+                net.currency().clone(),
+            );
+        }
+        let mut received = self.rate.convert(&net)?;
+        if self.fixed_fee.currency() == received.currency() {
+            received = received.with_amount_in(
+                received.amount() - self.fixed_fee.amount(),
+                received.currency().clone(),
+            );
+        }
+        let floor = received.amount().max(Decimal::ZERO);
+        Ok(received.with_amount_in(floor, received.currency().clone()))
+    }
+}
@@ -0,0 +1,258 @@
+//! # batch.rs – Streaming repricing of price lists
+//!
+//! Applies a percentage change to every price of a CSV price list and writes a
+//! new CSV keeping the original columns followed by `old_amount`, `new_amount`,
+//! `delta` and `error`. Rows are read, repriced and written one at a time, so
+//! memory stays bounded whatever the size of the list.
+//!
+//! A row that cannot be repriced (bad amount, unknown currency, invalid rate,
+//! a result out of range, invalid UTF-8) does not stop the run: it is written
+//! with an empty new amount and the reason in the `error` column, and counted
+//! in the [`RepriceSummary`].
+//!
+//! ```text
+//! sku,amount,currency          sku,amount,currency,old_amount,new_amount,delta,error
+//! A-1,19.90,EUR           →    A-1,19.90,EUR,19.90,21.89,1.99,
+//! A-2,n/a,EUR                  A-2,n/a,EUR,,,,invalid amount 'n/a'
+//! ```
+//!
+//! Usage examples are available in
+//! [`docs/types/reprice_options.md`](../../docs/types/reprice_options.md).
+
+use super::error::RepriceError;
+use crate::money::{CurrencyAlpha3, Money, PriceRule};
+use rust_decimal::Decimal;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+/// Number of row errors kept in a [`RepriceSummary`]; further errors are only counted.
+pub const MAX_REPORTED_ERRORS: usize = 100;
+
+/// Rounding applied to the repriced amounts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Amounts are kept with full precision.
+    None,
+    /// Amounts are rounded to the currency's minor units (e.g. cents).
+    #[default]
+    MinorUnits,
+    /// Amounts are moved to a price ending, step or cash amount.
+    Price(PriceRule),
+}
+
+impl Rounding {
+    /// Applies the rounding to a repriced amount, or returns `None` if a price
+    /// rule takes it out of the range of a `Decimal`.
+    pub fn apply(&self, money: &Money) -> Option<Money> {
+        match self {
+            Rounding::None => Some(money.clone()),
+            Rounding::MinorUnits => Some(money.rounded()),
+            Rounding::Price(rule) => rule.checked_apply(money),
+        }
+    }
+}
+
+#[doc = include_str!("../../docs/types/reprice_options.md")]
+/// Columns to read and how to reprice them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepriceOptions {
+    /// Percentage applied to rows without a rate of their own.
+    pub rate: Option<Decimal>,
+    /// Column of the prices (default `amount`).
+    pub amount_column: String,
+    /// Column of the currency codes (default `currency`); may be absent if
+    /// `default_currency` is set.
+    pub currency_column: String,
+    /// Column of per-row percentages overriding `rate` when not empty.
+    pub rate_column: Option<String>,
+    /// Currency of rows without a currency column.
+    pub default_currency: Option<CurrencyAlpha3>,
+    pub rounding: Rounding,
+    /// Field delimiter of both input and output (default `,`).
+    pub delimiter: u8,
+}
+
+impl RepriceOptions {
+    /// Create options applying `rate` percent to the `amount` column, with the
+    /// currency in the `currency` column and amounts rounded to minor units.
+    pub fn new(rate: Decimal) -> Self {
+        Self {
+            rate: Some(rate),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for RepriceOptions {
+    fn default() -> Self {
+        Self {
+            rate: None,
+            amount_column: "amount".to_string(),
+            currency_column: "currency".to_string(),
+            rate_column: None,
+            default_currency: None,
+            rounding: Rounding::default(),
+            delimiter: b',',
+        }
+    }
+}
+
+/// A row that could not be repriced (1-based line number and reason).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    pub line: usize,
+    pub message: String,
+}
+
+/// Counts of a repricing run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RepriceSummary {
+    /// Data rows read.
+    pub rows: usize,
+    /// Rows written with a new price.
+    pub repriced: usize,
+    /// Rows written with an error.
+    pub failed: usize,
+    /// The first [`MAX_REPORTED_ERRORS`] row errors.
+    pub errors: Vec<RowError>,
+}
+
+/// Column positions resolved from the header.
+struct Columns {
+    amount: usize,
+    currency: Option<usize>,
+    rate: Option<usize>,
+}
+
+/// Reprices every row of `reader` and writes the result to `writer`.
+///
+/// Fails only if the header does not match the options or on I/O errors; rows
+/// that cannot be repriced are reported in the output and the summary.
+pub fn reprice<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    options: &RepriceOptions,
+) -> Result<RepriceSummary, RepriceError> {
+    let io_error = |e: csv::Error| RepriceError::Io(e.to_string());
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(reader);
+    let mut csv_writer = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .from_writer(writer);
+
+    let header = csv_reader.headers().map_err(io_error)?.clone();
+    let find = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+    let columns = Columns {
+        amount: find(&options.amount_column).ok_or_else(|| {
+            RepriceError::Config(format!("no column named '{}'", options.amount_column))
+        })?,
+        currency: find(&options.currency_column),
+        rate: match &options.rate_column {
+            Some(name) => Some(
+                find(name)
+                    .ok_or_else(|| RepriceError::Config(format!("no column named '{}'", name)))?,
+            ),
+            None => None,
+        },
+    };
+    if columns.currency.is_none() && options.default_currency.is_none() {
+        return Err(RepriceError::Config(format!(
+            "no column named '{}' and no default currency",
+            options.currency_column
+        )));
+    }
+    if columns.rate.is_none() && options.rate.is_none() {
+        return Err(RepriceError::Config(
+            "a rate or a rate column is required".to_string(),
+        ));
+    }
+
+    let mut output_header = header.clone();
+    for name in ["old_amount", "new_amount", "delta", "error"] {
+        output_header.push_field(name);
+    }
+    csv_writer.write_record(&output_header).map_err(io_error)?;
+
+    let mut summary = RepriceSummary::default();
+    // Rows are read as bytes so that a row with invalid UTF-8 is reported on
+    // its own instead of stopping the run; its fields are copied unchanged.
+    let mut row = csv::ByteRecord::new();
+    let mut out = csv::ByteRecord::new();
+    while csv_reader.read_byte_record(&mut row).map_err(io_error)? {
+        summary.rows += 1;
+        // Quoted fields may span lines, so the line is taken from the reader.
+        let line = row
+            .position()
+            .map_or(summary.rows + 1, |p| p.line() as usize);
+        out.clear();
+        for field in row.iter() {
+            out.push_field(field);
+        }
+        let repriced = csv::StringRecord::from_byte_record(row.clone())
+            .map_err(|e| format!("invalid UTF-8 in field {}", e.utf8_error().field() + 1))
+            .and_then(|record| reprice_row(&record, &columns, options));
+        match repriced {
+            Ok((old, new, delta)) => {
+                summary.repriced += 1;
+                out.push_field(old.amount().to_string().as_bytes());
+                out.push_field(new.amount().to_string().as_bytes());
+                out.push_field(delta.to_string().as_bytes());
+                out.push_field(b"");
+            }
+            Err(message) => {
+                summary.failed += 1;
+                for _ in 0..3 {
+                    out.push_field(b"");
+                }
+                out.push_field(message.as_bytes());
+                if summary.errors.len() < MAX_REPORTED_ERRORS {
+                    summary.errors.push(RowError { line, message });
+                }
+            }
+        }
+        csv_writer.write_record(&out).map_err(io_error)?;
+    }
+    csv_writer
+        .flush()
+        .map_err(|e| RepriceError::Io(e.to_string()))?;
+    Ok(summary)
+}
+
+/// Returns the old and new price of a row and their difference, or why the
+/// row cannot be repriced.
+fn reprice_row(
+    row: &csv::StringRecord,
+    columns: &Columns,
+    options: &RepriceOptions,
+) -> Result<(Money, Money, Decimal), String> {
+    let cell = |column: usize| row.get(column).unwrap_or_default();
+    let currency = match columns.currency.map(cell).filter(|c| !c.is_empty()) {
+        Some(code) => {
+            CurrencyAlpha3::from_str(code).map_err(|_| format!("unknown currency '{}'", code))?
+        }
+        None => options
+            .default_currency
+            .clone()
+            .ok_or_else(|| "no currency".to_string())?,
+    };
+    let amount = cell(columns.amount);
+    let old =
+        Money::from_str(amount, currency).ok_or_else(|| format!("invalid amount '{}'", amount))?;
+    let rate = match columns.rate.map(cell).filter(|r| !r.is_empty()) {
+        Some(rate) => Decimal::from_str(rate).map_err(|_| format!("invalid rate '{}'", rate))?,
+        None => options.rate.ok_or_else(|| "no rate".to_string())?,
+    };
+    let overflow = || format!("{} at {}% is out of range", amount, rate);
+    let new = old
+        .checked_apply_rate(rate)
+        .and_then(|applied| options.rounding.apply(&applied))
+        .ok_or_else(overflow)?;
+    let delta = new
+        .amount()
+        .checked_sub(old.amount())
+        .ok_or_else(overflow)?;
+    Ok((old, new, delta))
+}
@@ -0,0 +1,21 @@
+use std::fmt;
+
+/// Errors stopping a repricing run; errors on single rows are reported per row instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepriceError {
+    /// The options do not match the input (missing column, no rate, no currency).
+    Config(String),
+    /// The input could not be read or the output could not be written.
+    Io(String),
+}
+
+impl fmt::Display for RepriceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepriceError::Config(message) => write!(f, "invalid repricing options: {}", message),
+            RepriceError::Io(message) => write!(f, "repricing failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RepriceError {}
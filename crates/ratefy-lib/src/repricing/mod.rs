@@ -0,0 +1,5 @@
+pub mod batch;
+mod error;
+
+pub use batch::{MAX_REPORTED_ERRORS, RepriceOptions, RepriceSummary, Rounding, RowError, reprice};
+pub use error::RepriceError;
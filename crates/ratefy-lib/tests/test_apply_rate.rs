@@ -1,4 +1,6 @@
+use ratefy_lib::apply_percentage_str;
 use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use std::str::FromStr;
 
@@ -22,3 +24,28 @@ fn test_apply_negative_rate() {
     let result = money.apply_rate(dec!(-20.0));
     assert_eq!(result.amount(), dec!(80.00));
 }
+
+#[test]
+fn test_checked_apply_rate() {
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    let money = Money::new(dec!(200), eur.clone());
+    let applied = money.checked_apply_rate(dec!(15)).unwrap();
+    assert_eq!(applied, money.apply_rate(dec!(15)));
+    assert!(
+        Money::new(Decimal::MAX, eur)
+            .checked_apply_rate(dec!(10))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_apply_percentage_str_out_of_range() {
+    assert_eq!(
+        apply_percentage_str("200", "15", "eur"),
+        Some((dec!(230), "EUR".to_string()))
+    );
+    assert_eq!(
+        apply_percentage_str(&Decimal::MAX.to_string(), "10", "EUR"),
+        None
+    );
+}
@@ -0,0 +1,80 @@
+use ratefy_lib::money::{
+    AssetDefinition, AssetError, CurrencyAlpha3, CurrencyGroup, CurrencyKind, Money,
+    register_asset, register_assets_from_toml, registered_assets,
+};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+#[test]
+fn test_builtin_crypto_assets() {
+    let codes: Vec<&str> = registered_assets().iter().map(|a| a.code()).collect();
+    for code in ["BTC", "ETH", "USDT"] {
+        assert!(codes.contains(&code));
+    }
+    let eth = CurrencyAlpha3::from_str("eth").unwrap();
+    assert_eq!(eth.code(), "ETH");
+    assert_eq!(eth.exponent(), Some(18));
+    assert!(eth.countries().is_empty());
+    assert!(!eth.is_fiat());
+}
+
+#[test]
+fn test_money_rounds_to_asset_precision() {
+    let btc = CurrencyAlpha3::from_str("BTC").unwrap();
+    let money = Money::new(dec!(1.000000005), btc);
+    assert_eq!(money.rounded().amount(), dec!(1.00000001));
+
+    let jpy = CurrencyAlpha3::from_str("JPY").unwrap();
+    assert_eq!(Money::new(dec!(100.5), jpy).rounded().amount(), dec!(101));
+}
+
+#[test]
+fn test_register_asset_validation() {
+    let tok = AssetDefinition::new("tok1", "Test token", "T", 4, CurrencyKind::Custom);
+    let registered = register_asset(tok.clone()).unwrap();
+    assert_eq!(registered.code(), "TOK1");
+    assert_eq!(registered.kind(), CurrencyKind::Custom);
+    assert_eq!(register_asset(tok), Ok(registered));
+
+    let changed = AssetDefinition::new("TOK1", "Test token", "T", 2, CurrencyKind::Custom);
+    assert_eq!(
+        register_asset(changed),
+        Err(AssetError::AlreadyRegistered("TOK1".into()))
+    );
+    let iso = AssetDefinition::new("usd", "Dollar", "$", 2, CurrencyKind::Crypto);
+    assert_eq!(register_asset(iso), Err(AssetError::IsoCode("USD".into())));
+    let bad = AssetDefinition::new("A-B", "Bad", "?", 2, CurrencyKind::Custom);
+    assert_eq!(
+        register_asset(bad),
+        Err(AssetError::InvalidCode("A-B".into()))
+    );
+    let fiat = AssetDefinition::new("FIATX", "Fiat", "F", 2, CurrencyKind::Fiat);
+    assert_eq!(
+        register_asset(fiat),
+        Err(AssetError::InvalidKind(CurrencyKind::Fiat))
+    );
+}
+
+#[test]
+fn test_assets_from_toml_in_groups() {
+    let toml = r#"
+[[asset]]
+code = "LOY"
+name = "Loyalty units"
+symbol = "L"
+precision = 2
+kind = "custom"
+"#;
+    let assets = register_assets_from_toml(toml).unwrap();
+    assert_eq!(assets[0].symbol(), "L");
+
+    let group = CurrencyGroup::custom("Mixed", &["EUR", "LOY"]).unwrap();
+    assert!(group.contains(&assets[0]));
+    assert!(!CurrencyGroup::G10.contains(&assets[0]));
+    assert!(!CurrencyGroup::Fiat.contains(&assets[0]));
+
+    assert!(matches!(
+        register_assets_from_toml("[[asset]]\ncode = 1"),
+        Err(AssetError::Parse(_))
+    ));
+}
@@ -0,0 +1,143 @@
+use chrono::NaiveDate;
+use ratefy_lib::bank::{self, BankError, CsvMapping, Deduplicator};
+use rust_decimal_macros::dec;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/bank")
+        .join(name)
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_csv_with_mapping() {
+    let mapping = CsvMapping::from_path(fixture("mapping.toml")).unwrap();
+    let records = bank::csv::from_path(fixture("statement.csv"), &mapping, "ubs:checking").unwrap();
+    assert_eq!(records.len(), 5);
+
+    let salary = &records[0];
+    assert_eq!(salary.date, date(2024, 1, 2));
+    assert_eq!(salary.money.amount(), dec!(6250.00));
+    assert_eq!(salary.money.currency().code(), "CHF");
+    assert_eq!(salary.money.timestamp(), Some(date(2024, 1, 2)));
+    assert_eq!(salary.money.source(), Some("ubs:checking"));
+    assert_eq!(salary.money.tags(), ["Income"]);
+    assert_eq!(salary.description, "Employer AG / Salary January");
+    assert_eq!(salary.reference.as_deref(), Some("REF-0001"));
+
+    assert_eq!(records[1].money.amount(), dec!(-84.35));
+    assert_eq!(records[1].reference, None);
+    assert_eq!(records[3].money.amount(), dec!(-2150.00));
+    // Debit and credit both filled, with 0,00 on the unused side.
+    assert_eq!(records[4].money.amount(), dec!(84.35));
+
+    let missing = CsvMapping::new("Date", "Amount");
+    assert!(matches!(
+        bank::csv::from_path(fixture("statement.csv"), &missing, ""),
+        Err(BankError::Mapping(_))
+    ));
+    let mut bad = CsvMapping::new("date", "amount");
+    bad.default_currency = Some("EUR".into());
+    let csv = "date,amount\n2024-01-02,12.50\n2024-13-01,1\n";
+    assert_eq!(
+        bank::csv::from_reader(csv.as_bytes(), &bad, ""),
+        Err(BankError::Parse {
+            line: 3,
+            message: "invalid date '2024-13-01'".into()
+        })
+    );
+    let spread = "date,amount\n\n2024-01-02,\"12.50\"\n\n2024-13-01,1\n";
+    assert!(matches!(
+        bank::csv::from_reader(spread.as_bytes(), &bad, ""),
+        Err(BankError::Parse { line: 5, .. })
+    ));
+}
+
+#[test]
+fn test_ofx_sgml_and_qfx_xml() {
+    let records = bank::ofx::from_path(fixture("statement.ofx")).unwrap();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].date, date(2024, 1, 2));
+    assert_eq!(records[0].money.amount(), dec!(3200.00));
+    assert_eq!(records[0].money.currency().code(), "USD");
+    assert_eq!(records[0].money.source(), Some("ofx:123456789"));
+    assert_eq!(records[0].money.tags(), ["credit"]);
+    assert_eq!(records[0].description, "ACME PAYROLL / Direct deposit");
+    assert_eq!(records[1].description, "SAFEWAY & CO");
+    assert_eq!(records[2].money.amount(), dec!(-100.00));
+    assert_eq!(records[2].reference.as_deref(), Some("202401100001"));
+    // CHARSET:1252 header: é is byte 0xE9 and the dash 0x96.
+    assert_eq!(
+        records[2].description,
+        "ATM WITHDRAWAL / Café du Pont – Main St"
+    );
+
+    let card = bank::ofx::from_path(fixture("statement.qfx")).unwrap();
+    assert_eq!(card.len(), 2);
+    assert_eq!(card[0].money.amount(), dec!(-18.75));
+    assert_eq!(card[0].money.currency().code(), "CAD");
+    assert_eq!(card[1].date, date(2024, 2, 15));
+    assert_eq!(card[1].money.tags(), ["payment"]);
+
+    let broken = "<CURDEF>USD<STMTTRN><DTPOSTED>2024<TRNAMT>1</STMTTRN>";
+    assert!(matches!(
+        bank::ofx::parse(broken),
+        Err(BankError::Parse { line: 1, .. })
+    ));
+}
+
+#[test]
+fn test_camt053() {
+    let records = bank::camt::from_path(fixture("camt053.xml")).unwrap();
+    assert_eq!(records.len(), 2, "pending entries are skipped");
+
+    let credit = &records[0];
+    assert_eq!(credit.date, date(2024, 1, 15));
+    assert_eq!(credit.money.amount(), dec!(1500.00));
+    assert_eq!(
+        credit.money.source(),
+        Some("camt053:DE89370400440532013000")
+    );
+    assert_eq!(credit.money.tags(), ["PMNT/RCDT/ESCT"]);
+    assert_eq!(credit.description, "Invoice 2024-001");
+    assert_eq!(credit.reference.as_deref(), Some("20240115-000123"));
+
+    let debit = &records[1];
+    assert_eq!(debit.date, date(2024, 1, 20));
+    assert_eq!(debit.money.amount(), dec!(-89.90));
+    assert_eq!(debit.money.tags(), ["NDDT"]);
+    assert_eq!(debit.description, "SEPA direct debit Telekom");
+
+    assert!(matches!(
+        bank::camt::parse("<Document>"),
+        Err(BankError::Parse { .. })
+    ));
+}
+
+#[test]
+fn test_reimport_detects_duplicates() {
+    let mapping = CsvMapping::from_path(fixture("mapping.toml")).unwrap();
+    let records = bank::csv::from_path(fixture("statement.csv"), &mapping, "ubs:checking").unwrap();
+
+    let mut deduplicator = Deduplicator::new();
+    let first = deduplicator.import(records.clone());
+    assert_eq!(first.new.len(), 5, "identical card payments are both kept");
+    assert!(first.duplicates.is_empty());
+
+    let again = deduplicator.import(records.clone());
+    assert!(again.new.is_empty());
+    assert_eq!(again.duplicates.len(), 5);
+
+    // A later export overlapping the first one only adds what is new.
+    let mut overlapping = records[2..].to_vec();
+    overlapping.extend(bank::ofx::from_path(fixture("statement.ofx")).unwrap());
+    let mut resumed = Deduplicator::from_records(&records);
+    let outcome = resumed.import(overlapping);
+    assert_eq!(outcome.duplicates.len(), 3);
+    assert_eq!(outcome.new.len(), 3);
+    assert_eq!(resumed.len(), 8);
+}
@@ -0,0 +1,122 @@
+use ratefy_lib::brackets::{Bracket, BracketError, BracketSchedule};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn chf(amount: Decimal) -> Money {
+    Money::new(amount, CurrencyAlpha3::from_str("CHF").unwrap())
+}
+
+fn commission() -> BracketSchedule {
+    let brackets = vec![
+        Bracket::new(dec!(1000), dec!(5)),
+        Bracket::new(dec!(10000), dec!(3.5)),
+        Bracket::new(dec!(100000), dec!(1.25)),
+    ];
+    BracketSchedule::new(
+        "Commission",
+        CurrencyAlpha3::from_str("CHF").unwrap(),
+        brackets,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_breakdown_and_rates() {
+    let result = commission().apply(&chf(dec!(150000))).unwrap();
+    assert_eq!(result.lines.len(), 3);
+    assert_eq!(result.lines[0].base.amount(), dec!(9000));
+    assert_eq!(result.lines[0].amount.amount(), dec!(450));
+    assert_eq!(result.lines[1].to.as_ref().unwrap().amount(), dec!(100000));
+    assert_eq!(result.lines[1].amount.amount(), dec!(3150));
+    assert_eq!(result.lines[2].to, None);
+    assert_eq!(result.lines[2].amount.amount(), dec!(625));
+    assert_eq!(result.total.amount(), dec!(4225));
+    assert_eq!(result.total.currency().code(), "CHF");
+    assert_eq!(result.effective_rate.round_dp(4), dec!(2.8167));
+    assert_eq!(result.marginal_rate, dec!(1.25));
+}
+
+#[test]
+fn test_amounts_at_and_below_thresholds() {
+    let below = commission().apply(&chf(dec!(999))).unwrap();
+    assert!(below.lines.is_empty());
+    assert_eq!(below.total.amount(), dec!(0));
+    assert_eq!(below.marginal_rate, dec!(0));
+
+    let at = commission().apply(&chf(dec!(10000))).unwrap();
+    assert_eq!(at.total.amount(), dec!(450));
+    assert_eq!(at.marginal_rate, dec!(3.5));
+
+    let zero = commission().apply(&chf(dec!(0))).unwrap();
+    assert_eq!(zero.effective_rate, dec!(0));
+}
+
+#[test]
+fn test_volume_discount_from_toml() {
+    let toml = r#"
+        [[schedule]]
+        name = "Volume discount"
+        currency = "USD"
+        brackets = [{ from = 0, rate = 0 }, { from = 500, rate = -2.5 }, { from = 2000, rate = -7.5 }]
+    "#;
+    let schedules = BracketSchedule::from_toml(toml).unwrap();
+    assert_eq!(schedules[0].name(), "Volume discount");
+    let order = Money::new(dec!(3000), CurrencyAlpha3::from_str("USD").unwrap());
+    let result = schedules[0].apply(&order).unwrap();
+    assert_eq!(result.total.amount(), dec!(-112.5));
+    assert_eq!(order.amount() + result.total.amount(), dec!(2887.5));
+}
+
+#[test]
+fn test_invalid_schedules_and_amounts() {
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    assert_eq!(
+        BracketSchedule::new("x", eur.clone(), vec![]),
+        Err(BracketError::Empty("x".into()))
+    );
+    let unordered = vec![
+        Bracket::new(dec!(10), dec!(1)),
+        Bracket::new(dec!(10), dec!(2)),
+    ];
+    assert!(matches!(
+        BracketSchedule::new("x", eur.clone(), unordered),
+        Err(BracketError::InvalidThreshold { .. })
+    ));
+    assert!(matches!(
+        commission().apply(&Money::new(dec!(5), eur)),
+        Err(BracketError::CurrencyMismatch { .. })
+    ));
+    assert_eq!(
+        commission().apply(&chf(dec!(-1))),
+        Err(BracketError::NegativeAmount(dec!(-1)))
+    );
+    assert!(matches!(
+        BracketSchedule::from_toml("[[schedule]]\nname = \"x\"\ncurrency = \"XYZ\"\nbrackets = []"),
+        Err(BracketError::UnknownCurrency(_))
+    ));
+    assert!(matches!(
+        BracketSchedule::load("missing.toml"),
+        Err(BracketError::Io(_))
+    ));
+}
+
+#[test]
+fn test_rates_are_bounded_and_large_amounts_fail() {
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    let surcharge = vec![Bracket::new(dec!(0), dec!(150))];
+    assert_eq!(
+        BracketSchedule::new("x", eur.clone(), surcharge),
+        Err(BracketError::InvalidRate {
+            schedule: "x".into(),
+            rate: dec!(150)
+        })
+    );
+    let tax =
+        BracketSchedule::new("tax", eur.clone(), vec![Bracket::new(dec!(0), dec!(20))]).unwrap();
+    assert_eq!(
+        tax.apply(&Money::new(Decimal::MAX, eur)),
+        Err(BracketError::Overflow(Decimal::MAX))
+    );
+}
@@ -0,0 +1,98 @@
+use chrono::NaiveDate;
+use ratefy_lib::cashflow::{CashFlow, CashFlowError, import, irr, npv, xirr, xnpv};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn flow(y: i32, m: u32, d: u32, amount: rust_decimal::Decimal, code: &str) -> CashFlow {
+    CashFlow::new(
+        NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+        Money::new(amount, CurrencyAlpha3::from_str(code).unwrap()),
+    )
+}
+
+#[test]
+fn test_npv_periodic() {
+    let flows = vec![
+        flow(2024, 1, 1, dec!(-1000), "EUR"),
+        flow(2025, 1, 1, dec!(500), "EUR"),
+        flow(2026, 1, 1, dec!(660), "EUR"),
+    ];
+    let value = npv(&flows, dec!(10)).unwrap();
+    assert_eq!(value.amount().round_dp(2), dec!(0.00));
+    assert_eq!(value.currency().code(), "EUR");
+}
+
+#[test]
+fn test_irr_matches_npv_root() {
+    let flows = vec![
+        flow(2024, 1, 1, dec!(-1000), "EUR"),
+        flow(2025, 1, 1, dec!(500), "EUR"),
+        flow(2026, 1, 1, dec!(660), "EUR"),
+    ];
+    assert_eq!(irr(&flows).unwrap().round_dp(6), dec!(10.000000));
+}
+
+#[test]
+fn test_xirr_irregular_dates() {
+    let flows = vec![
+        flow(2008, 1, 1, dec!(-10000), "USD"),
+        flow(2008, 3, 1, dec!(2750), "USD"),
+        flow(2008, 10, 30, dec!(4250), "USD"),
+        flow(2009, 2, 15, dec!(3250), "USD"),
+        flow(2009, 4, 1, dec!(2750), "USD"),
+    ];
+    let rate = xirr(&flows).unwrap();
+    assert_eq!(rate.round_dp(2), dec!(37.34));
+    let value = xnpv(&flows, rate).unwrap();
+    assert!(value.amount().abs() < dec!(0.001));
+}
+
+#[test]
+fn test_irr_errors() {
+    assert_eq!(irr(&[]), Err(CashFlowError::Empty));
+    let only_inflows = vec![flow(2024, 1, 1, dec!(10), "EUR")];
+    assert_eq!(irr(&only_inflows), Err(CashFlowError::NoSignChange));
+    let mixed = vec![
+        flow(2024, 1, 1, dec!(-10), "EUR"),
+        flow(2025, 1, 1, dec!(11), "USD"),
+    ];
+    assert_eq!(xirr(&mixed), Err(CashFlowError::MixedCurrencies));
+    assert_eq!(
+        npv(&only_inflows, dec!(-100)),
+        Err(CashFlowError::InvalidRate)
+    );
+}
+
+#[test]
+fn test_import_reports_line_of_bad_record() {
+    let data = "date,amount,currency\n2024-01-01,-500,EUR\n2024-13-01,520,EUR\n";
+    match import::from_reader(data.as_bytes()) {
+        Err(CashFlowError::Parse { line, .. }) => assert_eq!(line, 3),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_import_line_after_blank_and_multiline_rows() {
+    let data = "date,amount,currency\n\n\"2024-01-01\",\"-500\",EUR\n\n2024-13-01,520,EUR\n";
+    match import::from_reader(data.as_bytes()) {
+        Err(CashFlowError::Parse { line, .. }) => assert_eq!(line, 5),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    let multiline = "date,amount,currency\n2024-01-01,-500,\"EUR\n\"\nbad,520,EUR\n";
+    match import::from_reader(multiline.as_bytes()) {
+        Err(CashFlowError::Parse { line, .. }) => assert_eq!(line, 4),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_npv_sum_overflows() {
+    let flows = vec![
+        flow(2024, 1, 1, dec!(5e28), "EUR"),
+        flow(2025, 1, 1, dec!(5e28), "EUR"),
+    ];
+    assert_eq!(npv(&flows, dec!(0)), Err(CashFlowError::Overflow));
+    assert_eq!(xnpv(&flows, dec!(0)), Err(CashFlowError::Overflow));
+}
@@ -18,3 +18,27 @@ fn test_currency_alpha3_display() {
     let currency = CurrencyAlpha3::from_str("EUR").unwrap();
     assert_eq!(currency.to_string(), "EUR");
 }
+
+#[test]
+fn test_currency_alpha3_metadata() {
+    let jpy = CurrencyAlpha3::from_str("JPY").unwrap();
+    assert_eq!(jpy.name(), "Japanese yen");
+    assert_eq!(jpy.symbol(), "¥");
+    assert_eq!(jpy.numeric(), Some(392));
+    assert_eq!(jpy.exponent(), Some(0));
+    assert_eq!(jpy.countries().len(), 1);
+
+    let gold = CurrencyAlpha3::from_str("XAU").unwrap();
+    assert_eq!(gold.exponent(), None);
+    assert!(gold.countries().is_empty());
+}
+
+#[test]
+fn test_currency_alpha3_lookups() {
+    assert_eq!(CurrencyAlpha3::from_numeric(978).unwrap().code(), "EUR");
+    assert!(CurrencyAlpha3::from_numeric(1).is_none());
+
+    let spain = CurrencyAlpha3::from_country("ES");
+    assert_eq!(spain.iter().map(|c| c.code()).collect::<Vec<_>>(), ["EUR"]);
+    assert!(CurrencyAlpha3::from_country("ZZ").is_empty());
+}
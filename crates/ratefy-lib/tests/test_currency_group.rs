@@ -1,4 +1,4 @@
-use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup};
+use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup, CurrencyGroupError};
 use std::str::FromStr;
 
 #[test]
@@ -21,3 +21,54 @@ fn test_currency_group_list_lengths() {
     assert_eq!(g10.len(), 10);
     assert!(all.len() > g10.len());
 }
+
+#[test]
+fn test_builtin_regional_groups() {
+    let nok = CurrencyAlpha3::from_str("NOK").unwrap();
+    let brl = CurrencyAlpha3::from_str("BRL").unwrap();
+
+    assert!(CurrencyGroup::Nordics.contains(&nok));
+    assert!(CurrencyGroup::Latam.contains(&brl));
+    assert!(CurrencyGroup::Emerging.contains(&brl));
+    assert!(!CurrencyGroup::AsiaPacific.contains(&brl));
+    assert_eq!(CurrencyGroup::G20.list().len(), 17);
+}
+
+#[test]
+fn test_custom_groups_from_toml() {
+    let config = r#"
+[[group]]
+name = "LATAM core"
+currencies = ["brl", "MXN", "CLP"]
+
+[[group]]
+name = "Majors"
+currencies = ["EUR", "USD"]
+"#;
+    let groups = CurrencyGroup::parse_custom(config).unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name(), "LATAM core");
+    assert_eq!(groups[0].list(), vec!["BRL", "MXN", "CLP"]);
+    assert!(!groups[1].contains(&CurrencyAlpha3::from_str("GBP").unwrap()));
+}
+
+#[test]
+fn test_custom_group_errors() {
+    let unknown = "[[group]]\nname = \"Bad\"\ncurrencies = [\"EUR\", \"ABC\"]\n";
+    assert_eq!(
+        CurrencyGroup::parse_custom(unknown),
+        Err(CurrencyGroupError::UnknownCurrency {
+            group: "Bad".to_string(),
+            code: "ABC".to_string()
+        })
+    );
+    let empty = "[[group]]\nname = \"Empty\"\ncurrencies = []\n";
+    assert_eq!(
+        CurrencyGroup::parse_custom(empty),
+        Err(CurrencyGroupError::EmptyGroup("Empty".to_string()))
+    );
+    assert!(matches!(
+        CurrencyGroup::parse_custom("not toml ["),
+        Err(CurrencyGroupError::Parse(_))
+    ));
+}
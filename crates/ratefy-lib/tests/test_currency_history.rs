@@ -0,0 +1,92 @@
+use chrono::NaiveDate;
+use ratefy_lib::money::{
+    AssetDefinition, AssetError, CurrencyAlpha3, CurrencyGroup, CurrencyHistory, CurrencyKind,
+    Money, ValidityWarning, register_asset,
+};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_legacy_codes_parse() {
+    let dem = CurrencyAlpha3::from_str("dem").unwrap();
+    assert_eq!(dem.code(), "DEM");
+    assert_eq!(dem.name(), "Deutsche Mark");
+    assert_eq!(dem.numeric(), Some(276));
+    assert_eq!(dem.exponent(), Some(2));
+    assert_eq!(dem.kind(), CurrencyKind::Fiat);
+    assert!(dem.is_iso());
+    assert!(!CurrencyGroup::All.contains(&dem));
+
+    let itl = CurrencyAlpha3::from_str("ITL").unwrap();
+    assert_eq!(itl.exponent(), Some(0));
+    assert!(CurrencyHistory::of("USD").is_none());
+
+    let clash = AssetDefinition::new("FRF", "Franc token", "F", 2, CurrencyKind::Custom);
+    assert_eq!(
+        register_asset(clash),
+        Err(AssetError::IsoCode("FRF".into()))
+    );
+}
+
+#[test]
+fn test_redenominate_at_fixed_rate() {
+    let hrk = CurrencyAlpha3::from_str("HRK").unwrap();
+    let money = Money::new(dec!(753.45), hrk).with_timestamp(date(2022, 12, 31));
+    let eur = money.redenominate().unwrap();
+    assert_eq!(eur.currency().code(), "EUR");
+    assert_eq!(eur.amount(), dec!(100));
+    assert_eq!(eur.timestamp(), Some(date(2022, 12, 31)));
+
+    let usd = Money::new(dec!(1), CurrencyAlpha3::from_str("USD").unwrap());
+    assert!(usd.redenominate().is_none());
+}
+
+#[test]
+fn test_venezuelan_chain() {
+    let veb = CurrencyAlpha3::from_str("VEB").unwrap();
+    let money = Money::new(dec!(100000000000), veb);
+    let vef = money.redenominate().unwrap();
+    assert_eq!(vef.currency().code(), "VEF");
+    assert_eq!(vef.amount(), dec!(100000000));
+    let ves = vef.redenominate().unwrap();
+    assert_eq!(ves.currency().code(), "VES");
+    assert_eq!(ves.amount(), dec!(1000));
+}
+
+#[test]
+fn test_validity_warnings() {
+    let vef = CurrencyAlpha3::from_str("VEF").unwrap();
+    assert!(
+        Money::new(dec!(1), vef.clone())
+            .validity_warning()
+            .is_none()
+    );
+
+    let valid = Money::new(dec!(1), vef.clone()).with_timestamp(date(2010, 1, 1));
+    assert!(valid.validity_warning().is_none());
+
+    let early = Money::new(dec!(1), vef.clone()).with_timestamp(date(2007, 12, 31));
+    assert_eq!(
+        early.validity_warning(),
+        Some(ValidityWarning::NotYetIntroduced {
+            code: "VEF",
+            date: date(2007, 12, 31),
+            introduced: date(2008, 1, 1),
+        })
+    );
+
+    let late = Money::new(dec!(1), vef).with_timestamp(date(2018, 8, 20));
+    let warning = late.validity_warning().unwrap();
+    assert_eq!(
+        warning.to_string(),
+        "VEF used on 2018-08-20 but withdrawn on 2018-08-20 (replaced by VES)"
+    );
+
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    assert!(!eur.is_valid_on(date(1998, 12, 31)));
+    assert!(eur.is_valid_on(date(2023, 1, 1)));
+}
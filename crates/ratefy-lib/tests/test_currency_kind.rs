@@ -0,0 +1,43 @@
+use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup, CurrencyKind};
+use std::str::FromStr;
+
+fn kind(code: &str) -> CurrencyKind {
+    CurrencyAlpha3::from_str(code).unwrap().kind()
+}
+
+#[test]
+fn test_currency_kind_classification() {
+    assert_eq!(kind("EUR"), CurrencyKind::Fiat);
+    assert_eq!(kind("XOF"), CurrencyKind::Fiat);
+    assert_eq!(kind("XAG"), CurrencyKind::PreciousMetal);
+    assert_eq!(kind("XDR"), CurrencyKind::Fund);
+    assert_eq!(kind("CLF"), CurrencyKind::Fund);
+    assert_eq!(kind("XTS"), CurrencyKind::Testing);
+    assert_eq!(kind("XXX"), CurrencyKind::NoCurrency);
+    assert_eq!(CurrencyKind::PreciousMetal.to_string(), "precious metal");
+}
+
+#[test]
+fn test_fiat_group_excludes_non_money_codes() {
+    let fiat: Vec<&str> = CurrencyGroup::Fiat.codes().collect();
+    for code in ["XAU", "XAG", "XPT", "XPD", "XDR", "XTS", "XXX", "MXV"] {
+        assert!(!fiat.contains(&code), "{} should not be fiat", code);
+    }
+    assert!(fiat.contains(&"USD"));
+    assert!(fiat.len() < CurrencyGroup::All.codes().count());
+}
+
+#[test]
+fn test_currencies_of_kind_filters_groups() {
+    let metals: Vec<&str> = CurrencyGroup::All
+        .currencies_of_kind(CurrencyKind::PreciousMetal)
+        .map(|c| c.code())
+        .collect();
+    assert_eq!(metals, ["XAG", "XAU", "XPD", "XPT"]);
+    assert_eq!(
+        CurrencyGroup::G10
+            .currencies_of_kind(CurrencyKind::Fiat)
+            .count(),
+        10
+    );
+}
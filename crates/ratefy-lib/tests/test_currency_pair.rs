@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{CurrencyPair, ExchangeRate, FxError};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn pair(text: &str) -> CurrencyPair {
+    CurrencyPair::from_str(text).unwrap()
+}
+
+#[test]
+fn test_parse_notations() {
+    assert_eq!(pair("gbpjpy"), pair("GBP/JPY"));
+    assert_eq!(pair(" GBP - JPY "), pair("GBP/JPY"));
+    assert_eq!(pair("BTC/USDT").quote().code(), "USDT");
+    assert_eq!(
+        CurrencyPair::from_str("EURUS"),
+        Err(FxError::InvalidPair("EURUS".into()))
+    );
+    assert_eq!(
+        CurrencyPair::from_str("EUR/ABC"),
+        Err(FxError::UnknownCurrency("ABC".into()))
+    );
+    assert_eq!(
+        CurrencyPair::from_str("usdusd"),
+        Err(FxError::SameCurrency("USD".into()))
+    );
+}
+
+#[test]
+fn test_market_convention_order() {
+    for market in [
+        "EUR/GBP", "EUR/USD", "GBP/AUD", "AUD/NZD", "NZD/USD", "USD/CAD", "USD/CHF", "CAD/CHF",
+        "USD/MXN", "CHF/NOK", "NOK/SEK", "MXN/JPY", "USD/JPY",
+    ] {
+        assert!(pair(market).is_market_convention(), "{}", market);
+        assert!(!pair(market).inverse().is_market_convention(), "{}", market);
+        assert_eq!(pair(market).inverse().to_market_convention(), pair(market));
+    }
+    let usd = CurrencyAlpha3::from_str("USD").unwrap();
+    let gbp = CurrencyAlpha3::from_str("GBP").unwrap();
+    assert_eq!(CurrencyPair::market(usd, gbp).unwrap(), pair("GBPUSD"));
+}
+
+#[test]
+fn test_pip_size() {
+    assert_eq!(pair("EURJPY").pip_size(), dec!(0.01));
+    assert_eq!(pair("JPYUSD").pip_size(), dec!(0.0001));
+    assert_eq!(pair("GBPUSD").pip_size(), dec!(0.0001));
+}
+
+#[test]
+fn test_exchange_rate_conversion() {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    let rate = ExchangeRate::new(pair("JPY/USD"), dec!(0.0080), date).unwrap();
+    let market = rate.to_market_convention();
+    assert_eq!(market.pair(), &pair("USD/JPY"));
+    assert_eq!(market.rate(), dec!(125));
+
+    let usd = Money::new(dec!(10), CurrencyAlpha3::from_str("USD").unwrap());
+    assert_eq!(market.convert(&usd).unwrap().amount(), dec!(1250));
+    assert_eq!(rate.convert(&usd).unwrap().amount(), dec!(1250));
+
+    let chf = Money::new(dec!(10), CurrencyAlpha3::from_str("CHF").unwrap());
+    assert_eq!(
+        rate.convert(&chf),
+        Err(FxError::CurrencyMismatch {
+            pair: "JPY/USD".into(),
+            currency: "CHF".into()
+        })
+    );
+    assert_eq!(
+        ExchangeRate::new(pair("EURUSD"), dec!(0), date),
+        Err(FxError::NonPositiveRate)
+    );
+}
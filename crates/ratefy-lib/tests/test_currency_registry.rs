@@ -0,0 +1,32 @@
+use ratefy_lib::money::{CurrencyAlpha3, CurrencyGroup, CurrencyRegistry};
+use std::str::FromStr;
+
+#[test]
+fn test_registry_lookup() {
+    let registry = CurrencyRegistry::global();
+    let eur = registry.get("EUR").unwrap();
+    assert_eq!(eur, &CurrencyAlpha3::from_str("eur").unwrap());
+    assert_eq!(registry.all()[registry.index_of(eur).unwrap()], *eur);
+    assert!(registry.get("eur").is_none());
+    assert!(registry.get("ABC").is_none());
+}
+
+#[test]
+fn test_group_iterators_match_list() {
+    for group in CurrencyGroup::builtin() {
+        let codes: Vec<&str> = group.codes().collect();
+        assert_eq!(codes, group.list());
+        assert!(group.currencies().all(|c| group.contains(c)));
+    }
+    assert_eq!(
+        CurrencyGroup::All.currencies().count(),
+        CurrencyRegistry::global().all().len()
+    );
+}
+
+#[test]
+fn test_static_membership_by_code() {
+    assert!(CurrencyGroup::is_g10_currency("SEK"));
+    assert!(!CurrencyGroup::is_g10_currency("PLN"));
+    assert!(!CurrencyGroup::is_g3_currency("XYZ"));
+}
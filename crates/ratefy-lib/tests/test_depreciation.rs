@@ -0,0 +1,156 @@
+use ratefy_lib::depreciation::{
+    DepreciationError, DepreciationMethod, DepreciationSchedule, MAX_LIFE,
+};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn money(amount: Decimal, code: &str) -> Money {
+    Money::new(amount, CurrencyAlpha3::from_str(code).unwrap())
+}
+
+fn charges(schedule: &DepreciationSchedule) -> Vec<Decimal> {
+    schedule
+        .periods
+        .iter()
+        .map(|p| p.depreciation.amount())
+        .collect()
+}
+
+#[test]
+fn test_methods_end_at_salvage() {
+    let cost = money(dec!(10000), "EUR");
+    let salvage = money(dec!(1000), "EUR");
+    let methods = [
+        (DepreciationMethod::StraightLine, vec![dec!(2250); 4]),
+        (
+            DepreciationMethod::SumOfYearsDigits,
+            vec![dec!(3600), dec!(2700), dec!(1800), dec!(900)],
+        ),
+        (
+            DepreciationMethod::DecliningBalance { rate: dec!(30) },
+            vec![dec!(3000), dec!(2100), dec!(1470), dec!(2430)],
+        ),
+        (
+            DepreciationMethod::DoubleDeclining,
+            vec![dec!(5000), dec!(2500), dec!(1250), dec!(250)],
+        ),
+    ];
+    for (method, expected) in methods {
+        let schedule = DepreciationSchedule::new(&cost, &salvage, 4, method.clone()).unwrap();
+        assert_eq!(charges(&schedule), expected, "{}", method);
+        let last = schedule.periods.last().unwrap();
+        assert_eq!(last.closing, salvage, "{}", method);
+        assert_eq!(last.accumulated.amount(), dec!(9000));
+        assert_eq!(schedule.total().amount(), dec!(9000));
+    }
+}
+
+#[test]
+fn test_currency_aware_rounding() {
+    let yen = DepreciationSchedule::new(
+        &money(dec!(100000), "JPY"),
+        &money(dec!(0), "JPY"),
+        3,
+        DepreciationMethod::StraightLine,
+    )
+    .unwrap();
+    assert_eq!(charges(&yen), vec![dec!(33333), dec!(33333), dec!(33334)]);
+
+    let dinar = DepreciationSchedule::new(
+        &money(dec!(1000), "KWD"),
+        &money(dec!(0), "KWD"),
+        6,
+        DepreciationMethod::SumOfYearsDigits,
+    )
+    .unwrap();
+    assert_eq!(dinar.periods[0].depreciation.amount(), dec!(285.714));
+    assert_eq!(dinar.periods[5].depreciation.amount(), dec!(47.620));
+    assert_eq!(dinar.total().amount(), dec!(1000));
+}
+
+#[test]
+fn test_units_of_production_short_of_total() {
+    let method = DepreciationMethod::UnitsOfProduction {
+        total_units: dec!(1000),
+        units: vec![dec!(300), dec!(300)],
+    };
+    let schedule = DepreciationSchedule::new(
+        &money(dec!(999.99), "USD"),
+        &money(dec!(0), "USD"),
+        2,
+        method,
+    )
+    .unwrap();
+    assert_eq!(charges(&schedule), vec![dec!(300.00), dec!(300.00)]);
+    assert_eq!(schedule.periods[1].closing.amount(), dec!(399.99));
+}
+
+#[test]
+fn test_export_and_errors() {
+    let schedule = DepreciationSchedule::new(
+        &money(dec!(1000), "EUR"),
+        &money(dec!(100), "EUR"),
+        2,
+        DepreciationMethod::StraightLine,
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    schedule.write_csv(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "period,opening,depreciation,accumulated,closing,currency\n\
+         1,1000,450,450,550,EUR\n\
+         2,550,450,900,100,EUR\n"
+    );
+
+    let eur = money(dec!(1000), "EUR");
+    let zero = money(dec!(0), "EUR");
+    assert_eq!(
+        DepreciationSchedule::new(&eur, &zero, 0, DepreciationMethod::StraightLine),
+        Err(DepreciationError::InvalidLife)
+    );
+    assert_eq!(
+        DepreciationSchedule::new(&eur, &zero, 4_000_000_000, DepreciationMethod::StraightLine),
+        Err(DepreciationError::InvalidLife)
+    );
+    let longest =
+        DepreciationSchedule::new(&eur, &zero, MAX_LIFE, DepreciationMethod::StraightLine);
+    assert_eq!(longest.unwrap().periods.len(), MAX_LIFE as usize);
+    assert!(matches!(
+        DepreciationSchedule::new(
+            &eur,
+            &money(dec!(0), "USD"),
+            3,
+            DepreciationMethod::StraightLine
+        ),
+        Err(DepreciationError::CurrencyMismatch { .. })
+    ));
+    assert_eq!(
+        DepreciationSchedule::new(
+            &eur,
+            &money(dec!(2000), "EUR"),
+            3,
+            DepreciationMethod::StraightLine
+        ),
+        Err(DepreciationError::InvalidSalvage(dec!(2000)))
+    );
+    assert_eq!(
+        DepreciationSchedule::new(
+            &eur,
+            &zero,
+            3,
+            DepreciationMethod::DecliningBalance { rate: dec!(0) }
+        ),
+        Err(DepreciationError::InvalidRate(dec!(0)))
+    );
+    let short = DepreciationMethod::UnitsOfProduction {
+        total_units: dec!(10),
+        units: vec![dec!(1)],
+    };
+    assert!(matches!(
+        DepreciationSchedule::new(&eur, &zero, 3, short),
+        Err(DepreciationError::InvalidUnits(_))
+    ));
+}
@@ -0,0 +1,124 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{
+    CurrencyPair, DayCount, ExchangeRate, Forward, FxError, Tenor, implied_differential,
+};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn spot(pair: &str, rate: rust_decimal::Decimal) -> ExchangeRate {
+    ExchangeRate::new(
+        CurrencyPair::from_str(pair).unwrap(),
+        rate,
+        date(2024, 1, 2),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_tenor_parsing_and_maturity() {
+    assert_eq!(Tenor::from_str("1w"), Ok(Tenor::Weeks(1)));
+    assert_eq!(Tenor::from_str("3M"), Ok(Tenor::Months(3)));
+    assert_eq!(
+        Tenor::from_str("2024-06-28"),
+        Ok(Tenor::Date(date(2024, 6, 28)))
+    );
+    assert_eq!(
+        Tenor::from_str("0M"),
+        Err(FxError::InvalidTenor("0M".into()))
+    );
+    assert_eq!(
+        Tenor::from_str("1Q"),
+        Err(FxError::InvalidTenor("1Q".into()))
+    );
+
+    assert_eq!(
+        Tenor::Weeks(1).maturity(date(2024, 1, 2)),
+        Ok(date(2024, 1, 9))
+    );
+    assert_eq!(
+        Tenor::Months(1).maturity(date(2024, 1, 31)),
+        Ok(date(2024, 2, 29))
+    );
+    assert_eq!(
+        Tenor::Years(1).maturity(date(2024, 2, 29)),
+        Ok(date(2025, 2, 28))
+    );
+    assert_eq!(
+        Tenor::Date(date(2023, 1, 1)).maturity(date(2024, 1, 2)),
+        Err(FxError::InvalidMaturity {
+            spot: date(2024, 1, 2)
+        })
+    );
+}
+
+#[test]
+fn test_day_counts() {
+    let (start, end) = (date(2024, 1, 31), date(2024, 3, 31));
+    assert_eq!(
+        DayCount::Act360.year_fraction(start, end),
+        dec!(60) / dec!(360)
+    );
+    assert_eq!(
+        DayCount::Act365Fixed.year_fraction(start, end),
+        dec!(60) / dec!(365)
+    );
+    assert_eq!(
+        DayCount::Thirty360.year_fraction(start, end),
+        dec!(60) / dec!(360)
+    );
+    let gbp = ratefy_lib::money::CurrencyAlpha3::from_str("GBP").unwrap();
+    assert_eq!(DayCount::money_market(&gbp), DayCount::Act365Fixed);
+}
+
+#[test]
+fn test_forward_with_mixed_day_counts() {
+    // USD accrues ACT/360 and JPY ACT/365 over the 366 days of 2024.
+    let forward = Forward::new(
+        &spot("USDJPY", dec!(150)),
+        dec!(5.5),
+        dec!(0.1),
+        &Tenor::Years(1),
+    )
+    .unwrap();
+    assert_eq!(forward.days, 366);
+    assert_eq!(forward.outright.rate().round_dp(4), dec!(142.1991));
+    assert_eq!(forward.pips.round_dp(2), dec!(-780.09));
+    assert_eq!(forward.outright.date(), date(2025, 1, 2));
+
+    let same = Forward::with_day_counts(
+        &spot("USDJPY", dec!(150)),
+        dec!(2),
+        DayCount::Act360,
+        dec!(2),
+        DayCount::Act360,
+        &Tenor::Months(6),
+    )
+    .unwrap();
+    assert_eq!(same.points, dec!(0));
+}
+
+#[test]
+fn test_implied_differential_round_trip() {
+    let spot = spot("GBPUSD", dec!(1.27));
+    let tenor = Tenor::Date(date(2024, 9, 30));
+    let forward = Forward::new(&spot, dec!(5.2), dec!(5.4), &tenor).unwrap();
+    let differential = implied_differential(&spot, forward.points, &tenor, dec!(5.2)).unwrap();
+    assert_eq!(differential.round_dp(10), dec!(0.2));
+}
+
+#[test]
+fn test_out_of_range_interest_rates() {
+    let spot = spot("EURUSD", dec!(100000));
+    assert_eq!(
+        Forward::new(&spot, dec!(1), dec!(1e26), &Tenor::Years(1)),
+        Err(FxError::InvalidInterestRate(dec!(1e26)))
+    );
+    assert!(matches!(
+        implied_differential(&spot, dec!(1e28), &Tenor::Years(1), dec!(1e20)),
+        Err(FxError::AmountOutOfRange { .. })
+    ));
+}
@@ -0,0 +1,128 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{CurrencyPair, ExchangeRate, FxError, FxOption, OptionKind, OptionMarket};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn market(volatility: Decimal) -> OptionMarket {
+    let pair = CurrencyPair::from_str("EURUSD").unwrap();
+    OptionMarket {
+        spot: ExchangeRate::new(pair, dec!(1.08), date(2024, 1, 2)).unwrap(),
+        domestic_rate: dec!(5.5),
+        foreign_rate: dec!(4.0),
+        volatility,
+    }
+}
+
+#[test]
+fn test_call_and_put_greeks() {
+    let expiry = date(2024, 7, 1);
+    let call = FxOption::new(OptionKind::Call, dec!(1.10), expiry).unwrap();
+    let put = FxOption::new(OptionKind::Put, dec!(1.10), expiry).unwrap();
+    let c = call.value(&market(dec!(8))).unwrap();
+    let p = put.value(&market(dec!(8))).unwrap();
+
+    assert_eq!(c.premium.round_dp(5), dec!(0.01856));
+    assert_eq!(p.premium.round_dp(5), dec!(0.03018));
+    assert_eq!(p.delta.round_dp(4), dec!(-0.5546));
+    assert_eq!(c.gamma.round_dp(2), dec!(6.34));
+    assert_eq!(c.gamma, p.gamma);
+    assert_eq!(c.vega, p.vega);
+    assert_eq!(c.theta.round_dp(6), dec!(-0.000081));
+    assert_eq!(c.rho_domestic.round_dp(5), dec!(0.00219));
+    assert_eq!(c.rho_foreign.round_dp(5), dec!(-0.00228));
+
+    // Put-call parity: C - P = S·e^(-rf·T) - K·e^(-rd·T)
+    let t = dec!(181) / dec!(365);
+    let parity = dec!(1.08) * (dec!(-0.04) * t).exp() - dec!(1.10) * (dec!(-0.055) * t).exp();
+    assert!((c.premium - p.premium - parity).abs() < dec!(0.000001));
+}
+
+#[test]
+fn test_premium_in_either_currency_and_pips() {
+    let option = FxOption::new(OptionKind::Put, dec!(1.05), date(2024, 4, 1)).unwrap();
+    let valuation = option.value(&market(dec!(10))).unwrap();
+    let eur = Money::new(dec!(100000), CurrencyAlpha3::from_str("EUR").unwrap());
+    let usd = Money::new(dec!(108000), CurrencyAlpha3::from_str("USD").unwrap());
+
+    let from_eur = valuation.premium_in_quote(&eur).unwrap();
+    let from_usd = valuation.premium_in_quote(&usd).unwrap();
+    assert_eq!(from_eur.amount(), from_usd.amount());
+    assert_eq!(from_eur.currency().code(), "USD");
+    assert_eq!(
+        valuation.premium_in_base(&eur).unwrap().amount(),
+        from_eur.amount() / dec!(1.08)
+    );
+    assert_eq!(valuation.premium_pips(), valuation.premium * dec!(10000));
+    let gbp = Money::new(dec!(1), CurrencyAlpha3::from_str("GBP").unwrap());
+    assert!(valuation.premium_in_quote(&gbp).is_err());
+}
+
+#[test]
+fn test_implied_volatility_round_trip() {
+    let option = FxOption::new(OptionKind::Call, dec!(1.02), date(2025, 1, 2)).unwrap();
+    let premium = option.value(&market(dec!(12.5))).unwrap().premium;
+    let implied = option
+        .implied_volatility(&market(dec!(1)), premium)
+        .unwrap();
+    assert_eq!(implied.round_dp(4), dec!(12.5));
+
+    assert_eq!(
+        option.implied_volatility(&market(dec!(1)), dec!(2)),
+        Err(FxError::NoImpliedVolatility(dec!(2)))
+    );
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let expired = FxOption::new(OptionKind::Call, dec!(1.1), date(2024, 1, 2)).unwrap();
+    assert_eq!(
+        expired.value(&market(dec!(8))),
+        Err(FxError::InvalidMaturity {
+            spot: date(2024, 1, 2)
+        })
+    );
+    let option = FxOption::new(OptionKind::Call, dec!(1.1), date(2024, 6, 1)).unwrap();
+    assert_eq!(
+        option.value(&market(dec!(0))),
+        Err(FxError::InvalidVolatility(dec!(0)))
+    );
+    assert_eq!(
+        option.value(&market(dec!(600))),
+        Err(FxError::InvalidVolatility(dec!(600)))
+    );
+    assert!(FxOption::new(OptionKind::Put, dec!(0), date(2024, 6, 1)).is_err());
+
+    // A strike this large overflows the pricing formulas instead of panicking.
+    let huge = FxOption::new(OptionKind::Put, Decimal::MAX, date(2024, 6, 1)).unwrap();
+    assert_eq!(
+        huge.value(&market(dec!(8))),
+        Err(FxError::ValuationOutOfRange)
+    );
+    assert_eq!(
+        huge.implied_volatility(&market(dec!(8)), dec!(1)),
+        Err(FxError::ValuationOutOfRange)
+    );
+
+    // exp(20 × 4 years) does not fit in a Decimal.
+    let long = FxOption::new(OptionKind::Call, dec!(1.1), date(2028, 1, 2)).unwrap();
+    let extreme = OptionMarket {
+        domestic_rate: dec!(-2000),
+        ..market(dec!(8))
+    };
+    assert_eq!(
+        long.value(&extreme),
+        Err(FxError::InvalidInterestRate(dec!(-2000)))
+    );
+
+    // Deep in the money, far from the strike: no overflow in the normal CDF.
+    let deep = FxOption::new(OptionKind::Call, dec!(0.2), date(2024, 1, 3)).unwrap();
+    let valuation = deep.value(&market(dec!(5))).unwrap();
+    assert_eq!(valuation.delta.round_dp(3), dec!(1.000));
+}
@@ -0,0 +1,117 @@
+use chrono::NaiveDate;
+use ratefy_lib::inflation::{CpiSeries, InflationError};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn series() -> CpiSeries {
+    let data = "date,value\n2010-01-01,80\n2015-01-01,90\n2024-01-01,120\n";
+    CpiSeries::from_reader(data.as_bytes()).unwrap()
+}
+
+#[test]
+fn test_value_uses_latest_observation_before_date() {
+    let cpi = series();
+    assert_eq!(cpi.value_at(date(2014, 12, 31)).unwrap(), dec!(80));
+    assert_eq!(cpi.value_at(date(2015, 1, 1)).unwrap(), dec!(90));
+    assert_eq!(
+        cpi.value_at(date(2009, 1, 1)),
+        Err(InflationError::OutOfRange(date(2009, 1, 1)))
+    );
+}
+
+#[test]
+fn test_adjust_forward_and_backward() {
+    let cpi = series();
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    let past = Money::new(dec!(100), eur.clone()).with_timestamp(date(2010, 3, 1));
+    let today = cpi.adjust(&past, cpi.latest_date().unwrap()).unwrap();
+    assert_eq!(today.amount(), dec!(150));
+
+    let back = cpi.adjust(&today, date(2010, 3, 1)).unwrap();
+    assert_eq!(back.amount(), dec!(100));
+
+    let undated = Money::new(dec!(100), eur);
+    assert_eq!(
+        cpi.adjust(&undated, date(2024, 1, 1)),
+        Err(InflationError::MissingTimestamp)
+    );
+}
+
+#[test]
+fn test_cumulative_and_annualized_inflation() {
+    let cpi = series();
+    let cumulative = cpi
+        .cumulative_inflation(date(2015, 1, 1), date(2024, 1, 1))
+        .unwrap();
+    assert_eq!(cumulative.round_dp(4), dec!(33.3333));
+    let annual = cpi
+        .annualized_inflation(date(2010, 1, 1), date(2024, 1, 1))
+        .unwrap();
+    assert_eq!(annual.round_dp(2), dec!(2.94));
+}
+
+#[test]
+fn test_rejects_non_positive_values() {
+    let data = "date,value\n2010-01-01,0\n";
+    assert_eq!(
+        CpiSeries::from_reader(data.as_bytes()),
+        Err(InflationError::NonPositiveIndex(date(2010, 1, 1)))
+    );
+}
+
+#[test]
+fn test_annualizing_a_short_span_overflows() {
+    let data = "date,value\n2023-12-31,90\n2024-01-01,120\n";
+    let cpi = CpiSeries::from_reader(data.as_bytes()).unwrap();
+    let (from, to) = (date(2023, 12, 31), date(2024, 1, 1));
+    assert_eq!(
+        cpi.annualized_inflation(from, to),
+        Err(InflationError::Overflow { from, to })
+    );
+}
+
+#[test]
+fn test_one_day_span_overflows() {
+    let data = "date,value\n2020-01-01,100\n2020-01-02,119\n";
+    let cpi = CpiSeries::from_reader(data.as_bytes()).unwrap();
+    let (from, to) = (date(2020, 1, 1), date(2020, 1, 2));
+    assert_eq!(
+        cpi.annualized_inflation(from, to),
+        Err(InflationError::Overflow { from, to })
+    );
+    assert_eq!(cpi.cumulative_inflation(from, to), Ok(dec!(19)));
+
+    let tiny = "date,value\n2020-01-01,0.0000000000000000000000000001\n2020-01-02,10\n";
+    let cpi = CpiSeries::from_reader(tiny.as_bytes()).unwrap();
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    let money = Money::new(dec!(1000), eur).with_timestamp(from);
+    assert_eq!(
+        cpi.adjust(&money, to),
+        Err(InflationError::Overflow { from, to })
+    );
+    assert_eq!(
+        cpi.cumulative_inflation(from, to),
+        Err(InflationError::Overflow { from, to })
+    );
+}
+
+#[test]
+fn test_adjust_keeps_metadata() {
+    let eur = CurrencyAlpha3::from_str("EUR").unwrap();
+    let past = Money::new(dec!(100), eur)
+        .apply_rate(dec!(10))
+        .with_source("payroll")
+        .with_tag("salary")
+        .with_timestamp(date(2010, 3, 1));
+    let today = series().adjust(&past, date(2024, 1, 1)).unwrap();
+    assert_eq!(today.amount(), dec!(165));
+    assert_eq!(today.source(), Some("payroll"));
+    assert_eq!(today.tags(), ["salary".to_string()]);
+    assert_eq!(today.rate(), Some(dec!(10)));
+    assert_eq!(today.timestamp(), Some(date(2024, 1, 1)));
+}
@@ -0,0 +1,165 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{FxError, RateStore};
+use ratefy_lib::ledger::{Account, AccountKind, Journal, LedgerError, Posting, Transaction};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn money(amount: Decimal, code: &str) -> Money {
+    Money::new(amount, CurrencyAlpha3::from_str(code).unwrap())
+}
+
+fn date(m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, m, d).unwrap()
+}
+
+fn journal() -> Journal {
+    let mut journal = Journal::new();
+    for (name, kind) in [
+        ("Assets:Bank:CHF", AccountKind::Asset),
+        ("Assets:Bank:USD", AccountKind::Asset),
+        ("Equity:Capital", AccountKind::Equity),
+        ("Equity:Conversion", AccountKind::Equity),
+        ("Expenses:Rent", AccountKind::Expense),
+        ("Income:FX", AccountKind::Income),
+    ] {
+        journal.open(Account::new(name, kind).unwrap()).unwrap();
+    }
+    let entries = [
+        (
+            date(1, 2),
+            vec![
+                Posting::new("Assets:Bank:CHF", money(dec!(10000), "CHF")),
+                Posting::new("Equity:Capital", money(dec!(-10000), "CHF")),
+            ],
+        ),
+        (
+            date(1, 10),
+            vec![
+                Posting::new("Assets:Bank:CHF", money(dec!(-4500), "CHF")),
+                Posting::new("Equity:Conversion", money(dec!(4500), "CHF")),
+                Posting::new("Equity:Conversion", money(dec!(-5000), "USD")),
+                Posting::new("Assets:Bank:USD", money(dec!(5000), "USD")),
+            ],
+        ),
+        (
+            date(2, 1),
+            vec![
+                Posting::new("Expenses:Rent", money(dec!(1200), "USD")),
+                Posting::new("Assets:Bank:USD", money(dec!(-1200), "USD")),
+            ],
+        ),
+    ];
+    for (day, postings) in entries {
+        journal
+            .post(Transaction::new(day, "entry", postings).unwrap())
+            .unwrap();
+    }
+    journal
+}
+
+#[test]
+fn test_transactions_must_balance_per_currency() {
+    let mixed = vec![
+        Posting::new("Assets:Bank:CHF", money(dec!(-100), "CHF")),
+        Posting::new("Assets:Bank:USD", money(dec!(100), "USD")),
+    ];
+    assert_eq!(
+        Transaction::new(date(1, 5), "no conversion account", mixed),
+        Err(LedgerError::Unbalanced {
+            currency: "CHF".into(),
+            difference: dec!(-100)
+        })
+    );
+    let single = vec![Posting::new("Assets:Bank:CHF", money(dec!(0), "CHF"))];
+    assert_eq!(
+        Transaction::new(date(1, 5), "", single),
+        Err(LedgerError::TooFewPostings)
+    );
+
+    let mut journal = journal();
+    let unknown = vec![
+        Posting::new("Assets:Cash", money(dec!(5), "CHF")),
+        Posting::new("Assets:Bank:CHF", money(dec!(-5), "CHF")),
+    ];
+    let transaction = Transaction::new(date(1, 5), "", unknown).unwrap();
+    assert_eq!(
+        journal.post(transaction),
+        Err(LedgerError::UnknownAccount("Assets:Cash".into()))
+    );
+    assert_eq!(
+        journal.open(Account::new("Income:FX", AccountKind::Income).unwrap()),
+        Err(LedgerError::DuplicateAccount("Income:FX".into()))
+    );
+    assert!(Account::new("Assets::Bank", AccountKind::Asset).is_err());
+}
+
+#[test]
+fn test_balances_and_trial_balance() {
+    let journal = journal();
+    assert_eq!(
+        journal.balance("Equity:Conversion", None),
+        vec![money(dec!(4500), "CHF"), money(dec!(-5000), "USD")]
+    );
+    assert_eq!(
+        journal.balance("Assets:Bank:USD", Some(date(1, 31))),
+        vec![money(dec!(5000), "USD")]
+    );
+
+    let trial = journal.trial_balance(None);
+    assert!(trial.is_balanced());
+    assert_eq!(trial.lines.len(), 6);
+    let totals = trial.totals();
+    assert_eq!(
+        totals[0],
+        (money(dec!(10000), "CHF"), money(dec!(10000), "CHF"))
+    );
+    assert_eq!(
+        totals[1],
+        (money(dec!(5000), "USD"), money(dec!(5000), "USD"))
+    );
+    let report = trial.to_string();
+    assert!(report.contains("Expenses:Rent"));
+    assert!(report.contains("Total USD"));
+}
+
+#[test]
+fn test_revaluation_entry() {
+    let mut journal = journal();
+    let rates =
+        RateStore::from_reader("date,pair,rate\n2024-01-31,USD/CHF,0.86\n".as_bytes()).unwrap();
+    let chf = CurrencyAlpha3::from_str("CHF").unwrap();
+    let revaluation = journal.revalue(date(1, 31), &chf, &rates).unwrap();
+    assert_eq!(
+        revaluation.account_value("Assets:Bank:USD").amount(),
+        dec!(4300)
+    );
+    assert_eq!(
+        revaluation.kind_value(AccountKind::Asset).amount(),
+        dec!(9800)
+    );
+    assert_eq!(
+        revaluation.fx_gain("Equity:Conversion").amount(),
+        dec!(-200)
+    );
+
+    let entry = revaluation
+        .entry("Equity:Conversion", "Income:FX")
+        .unwrap()
+        .unwrap();
+    journal.post(entry).unwrap();
+    assert_eq!(
+        journal.balance("Income:FX", None),
+        vec![money(dec!(200), "CHF")]
+    );
+
+    let again = journal.revalue(date(1, 31), &chf, &rates).unwrap();
+    assert_eq!(again.entry("Equity:Conversion", "Income:FX"), Ok(None));
+
+    let before = journal.revalue(date(1, 15), &chf, &RateStore::new());
+    assert!(matches!(
+        before,
+        Err(LedgerError::Fx(FxError::MissingRate { .. }))
+    ));
+}
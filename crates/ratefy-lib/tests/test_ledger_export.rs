@@ -0,0 +1,163 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::RateStore;
+use ratefy_lib::ledger::{
+    Account, AccountKind, ExportFormat, Journal, LedgerError, Posting, Transaction,
+    export_conversion, export_journal, export_prices,
+};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn money(amount: Decimal, code: &str) -> Money {
+    Money::new(amount, CurrencyAlpha3::from_str(code).unwrap())
+}
+
+fn date(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, d).unwrap()
+}
+
+fn rates() -> RateStore {
+    let csv = "date,pair,rate\n2024-01-03,USD/CHF,0.85\n2024-01-02,EUR/USD,1.10\n2024-01-03,EUR/USD,1.09\n";
+    RateStore::from_reader(csv.as_bytes()).unwrap()
+}
+
+fn journal() -> Journal {
+    let mut journal = Journal::new();
+    for (name, kind) in [
+        ("Assets:Bank", AccountKind::Asset),
+        ("Groceries", AccountKind::Expense),
+        ("Equity:Opening balances", AccountKind::Equity),
+    ] {
+        journal.open(Account::new(name, kind).unwrap()).unwrap();
+    }
+    let opening = vec![
+        Posting::new("Assets:Bank", money(dec!(1000), "CHF")),
+        Posting::new("Equity:Opening balances", money(dec!(-1000), "CHF")),
+    ];
+    let shopping = vec![
+        Posting::new(
+            "Groceries",
+            money(dec!(84.35), "CHF")
+                .with_tag("food")
+                .with_tag("migros"),
+        ),
+        Posting::new("Assets:Bank", money(dec!(-84.35), "CHF")),
+    ];
+    journal
+        .post(Transaction::new(date(3), "Migros \"Zurich\"", shopping).unwrap())
+        .unwrap();
+    journal
+        .post(Transaction::new(date(1), "Opening", opening).unwrap())
+        .unwrap();
+    journal
+}
+
+#[test]
+fn test_price_directives() {
+    assert_eq!(
+        export_prices(&rates(), ExportFormat::Ledger),
+        "P 2024-01-02 EUR 1.10 USD\nP 2024-01-03 EUR 1.09 USD\nP 2024-01-03 USD 0.85 CHF\n"
+    );
+    assert_eq!(
+        export_prices(&rates(), ExportFormat::Beancount)
+            .lines()
+            .next(),
+        Some("2024-01-02 price EUR 1.10 USD")
+    );
+}
+
+#[test]
+fn test_ledger_and_hledger_journal() {
+    let ledger = export_journal(&journal(), None, ExportFormat::Ledger);
+    assert_eq!(
+        ledger,
+        "2024-01-01 Opening
+    Assets:Bank  1000 CHF
+    Equity:Opening balances  -1000 CHF
+
+2024-01-03 Migros \"Zurich\"
+    Groceries  84.35 CHF  ; :food:migros:
+    Assets:Bank  -84.35 CHF
+"
+    );
+    let hledger = export_journal(&journal(), Some(&rates()), ExportFormat::Hledger);
+    assert!(hledger.starts_with("P 2024-01-02 EUR 1.10 USD\n"));
+    assert!(hledger.contains("    Groceries  84.35 CHF  ; food:, migros:\n"));
+}
+
+#[test]
+fn test_beancount_journal() {
+    let text = export_journal(&journal(), None, ExportFormat::Beancount);
+    assert_eq!(
+        text,
+        "2024-01-01 open Assets:Bank
+2024-01-01 open Equity:Opening-balances
+2024-01-03 open Expenses:Groceries
+
+2024-01-01 * \"Opening\"
+    Assets:Bank  1000 CHF
+    Equity:Opening-balances  -1000 CHF
+
+2024-01-03 * \"Migros \\\"Zurich\\\"\" #food #migros
+    Expenses:Groceries  84.35 CHF
+    Assets:Bank  -84.35 CHF
+"
+    );
+}
+
+#[test]
+fn test_conversion() {
+    let bank = Account::new("Assets:Bank", AccountKind::Asset).unwrap();
+    let travel = Account::new("expenses:travel", AccountKind::Expense).unwrap();
+    let text = export_conversion(
+        date(5),
+        "Card payment abroad",
+        &bank,
+        &money(dec!(-54.50), "CHF"),
+        &travel,
+        &money(dec!(50), "GBP"),
+        ExportFormat::Beancount,
+    )
+    .unwrap();
+    assert_eq!(
+        text,
+        "2024-01-05 open Assets:Bank
+2024-01-05 open Expenses:Travel
+
+2024-01-05 * \"Card payment abroad\"
+    Expenses:Travel  50 GBP @@ 54.50 CHF
+    Assets:Bank  -54.50 CHF
+"
+    );
+
+    // Accounts without a root are placed under the root of their kind.
+    let eur = Account::new("Bank:EUR", AccountKind::Asset).unwrap();
+    let usd = Account::new("Bank:USD", AccountKind::Asset).unwrap();
+    let text = export_conversion(
+        date(5),
+        "Buy USD",
+        &eur,
+        &money(dec!(500), "EUR"),
+        &usd,
+        &money(dec!(550), "USD"),
+        ExportFormat::Beancount,
+    )
+    .unwrap();
+    assert!(text.starts_with("2024-01-05 open Assets:Bank:EUR\n"));
+    assert!(text.contains("    Assets:Bank:USD  550 USD @@ 500 EUR\n"));
+
+    assert!(matches!(
+        export_conversion(
+            date(5),
+            "",
+            &eur,
+            &money(dec!(1), "CHF"),
+            &usd,
+            &money(dec!(1), "CHF"),
+            ExportFormat::Ledger
+        ),
+        Err(LedgerError::Fx(_))
+    ));
+    assert_eq!(ExportFormat::from_str("bean"), Ok(ExportFormat::Beancount));
+}
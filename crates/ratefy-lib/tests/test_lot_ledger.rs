@@ -0,0 +1,138 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{CostMethod, CurrencyPair, ExchangeRate, FxError, LotLedger};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn c(code: &str) -> CurrencyAlpha3 {
+    CurrencyAlpha3::from_str(code).unwrap()
+}
+
+fn eur_usd(day: u32, rate: Decimal) -> ExchangeRate {
+    let date = NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+    ExchangeRate::new(CurrencyPair::from_str("EUR/USD").unwrap(), rate, date).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, c("USD"))
+}
+
+/// Buys 1 000 USD at 1.25 and 1 000 USD at 1.00, then sells 1 500 USD at 1.20.
+fn scenario(method: CostMethod) -> LotLedger {
+    let mut ledger = LotLedger::new(c("USD"), c("EUR"), method).unwrap();
+    ledger
+        .acquire(&usd(dec!(1000)), &eur_usd(1, dec!(1.25)))
+        .unwrap();
+    ledger
+        .acquire(&usd(dec!(1000)), &eur_usd(5, dec!(1.00)))
+        .unwrap();
+    ledger
+        .dispose(&usd(dec!(1500)), &eur_usd(8, dec!(1.20)))
+        .unwrap();
+    ledger
+}
+
+#[test]
+fn test_realized_and_unrealized_by_method() {
+    let later = eur_usd(29, dec!(1.25));
+    let expected = [
+        (CostMethod::Fifo, dec!(-50), dec!(500), dec!(-100)),
+        (CostMethod::Lifo, dec!(-150), dec!(400), dec!(0)),
+        (CostMethod::Average, dec!(-100), dec!(450), dec!(-50)),
+    ];
+    for (method, realized, basis, unrealized) in expected {
+        let ledger = scenario(method);
+        let gains = ledger.realized().unwrap();
+        assert_eq!(gains.amount(), realized, "{}", method);
+        assert_eq!(gains.currency(), &c("EUR"));
+        assert_eq!(ledger.holdings().unwrap().amount(), dec!(500), "{}", method);
+        assert_eq!(ledger.cost_basis().unwrap().amount(), basis, "{}", method);
+        assert_eq!(
+            ledger.unrealized(&later).unwrap().amount(),
+            unrealized,
+            "{}",
+            method
+        );
+    }
+}
+
+#[test]
+fn test_lots_remaining() {
+    let fifo = scenario(CostMethod::Fifo);
+    assert_eq!(fifo.lots().len(), 1);
+    assert_eq!(
+        fifo.lots()[0].date,
+        NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()
+    );
+
+    let average = scenario(CostMethod::Average);
+    assert_eq!(average.lots().len(), 2);
+    assert_eq!(average.lots()[0].amount.amount(), dec!(250));
+    assert_eq!(average.lots()[0].cost.amount(), dec!(200));
+
+    let disposal = &fifo.disposals()[0];
+    assert_eq!(
+        disposal.gain.timestamp(),
+        NaiveDate::from_ymd_opt(2024, 3, 8)
+    );
+}
+
+#[test]
+fn test_average_disposal_of_the_whole_holding() {
+    let mut ledger = LotLedger::new(c("USD"), c("EUR"), CostMethod::Average).unwrap();
+    for (day, rate) in [(1, dec!(1.25)), (2, dec!(1.10)), (3, dec!(1.00))] {
+        ledger
+            .acquire(&usd(dec!(100)), &eur_usd(day, rate))
+            .unwrap();
+    }
+    // A third of the holding: the lots are scaled by a rounded 2/3.
+    ledger
+        .dispose(&usd(dec!(100)), &eur_usd(4, dec!(1.20)))
+        .unwrap();
+    let held = ledger.holdings().unwrap();
+    assert_eq!(held.amount(), dec!(200));
+    ledger.dispose(&held, &eur_usd(5, dec!(1.20))).unwrap();
+    assert!(ledger.lots().is_empty());
+    assert_eq!(ledger.cost_basis().unwrap().amount(), Decimal::ZERO);
+}
+
+#[test]
+fn test_rejected_entries() {
+    let mut ledger = LotLedger::new(c("USD"), c("EUR"), CostMethod::Fifo).unwrap();
+    ledger
+        .acquire(&usd(dec!(100)), &eur_usd(5, dec!(1.1)))
+        .unwrap();
+    assert_eq!(
+        ledger.dispose(&usd(dec!(150)), &eur_usd(6, dec!(1.1))),
+        Err(FxError::InsufficientHoldings {
+            held: dec!(100),
+            requested: dec!(150)
+        })
+    );
+    assert!(matches!(
+        ledger.acquire(&usd(dec!(10)), &eur_usd(4, dec!(1.1))),
+        Err(FxError::OutOfOrder { .. })
+    ));
+    assert!(matches!(
+        ledger.acquire(&usd(dec!(-10)), &eur_usd(6, dec!(1.1))),
+        Err(FxError::NonPositiveAmount(_))
+    ));
+    let gbp_usd = ExchangeRate::new(
+        CurrencyPair::from_str("GBP/USD").unwrap(),
+        dec!(1.25),
+        NaiveDate::from_ymd_opt(2024, 3, 6).unwrap(),
+    )
+    .unwrap();
+    assert!(matches!(
+        ledger.acquire(&usd(dec!(10)), &gbp_usd),
+        Err(FxError::CurrencyMismatch { .. })
+    ));
+    assert!(LotLedger::new(c("EUR"), c("EUR"), CostMethod::Lifo).is_err());
+
+    // Totals that no longer fit in a Decimal are rejected, not overflowed.
+    let huge = usd(Decimal::MAX);
+    ledger.acquire(&huge, &eur_usd(6, dec!(1))).unwrap_err();
+    assert_eq!(ledger.holdings().unwrap().amount(), dec!(100));
+    assert_eq!(CostMethod::from_str("avg"), Ok(CostMethod::Average));
+}
@@ -0,0 +1,100 @@
+use ratefy_lib::fx::{CurrencyPair, FxError, PositionCalculator, RateStore, STANDARD_LOT};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+const RATES: &str = "date,pair,rate
+2024-03-01,EUR/USD,1.0800
+2024-03-01,GBP/USD,1.2500
+2024-03-01,USD/JPY,150.00
+";
+
+fn calculator(pair: &str, account: &str) -> PositionCalculator {
+    let rates = RateStore::from_reader(RATES.as_bytes()).unwrap();
+    PositionCalculator::new(
+        CurrencyPair::from_str(pair).unwrap(),
+        CurrencyAlpha3::from_str(account).unwrap(),
+        &rates,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_pip_value_in_account_currency() {
+    assert_eq!(
+        calculator("EURUSD", "USD")
+            .pip_value(STANDARD_LOT)
+            .unwrap()
+            .amount(),
+        dec!(10)
+    );
+    let eur = calculator("GBPUSD", "EUR").pip_value(STANDARD_LOT).unwrap();
+    assert_eq!(eur.currency().code(), "EUR");
+    assert_eq!(eur.rounded().amount(), dec!(9.26));
+    // GBP account on USD/JPY: JPY → GBP crossed through USD.
+    let gbp = calculator("USDJPY", "GBP").pip_value(dec!(-50000)).unwrap();
+    assert_eq!(gbp.rounded().amount(), dec!(2.67));
+}
+
+#[test]
+fn test_position_size_from_risk() {
+    let calc = calculator("EURUSD", "EUR");
+    let risk = Money::new(dec!(540), CurrencyAlpha3::from_str("EUR").unwrap());
+    let size = calc.position_size(&risk, dec!(-25)).unwrap();
+    assert_eq!(size.currency().code(), "EUR");
+    assert_eq!(size.amount().round(), dec!(233280));
+
+    let usd_risk = Money::new(dec!(100), CurrencyAlpha3::from_str("USD").unwrap());
+    assert!(matches!(
+        calc.position_size(&usd_risk, dec!(10)),
+        Err(FxError::CurrencyMismatch { .. })
+    ));
+    assert_eq!(
+        calc.position_size(&risk, dec!(0)),
+        Err(FxError::InvalidStop(dec!(0)))
+    );
+}
+
+#[test]
+fn test_profit_loss_long_and_short() {
+    let calc = calculator("GBPUSD", "USD");
+    assert_eq!(calc.pips_between(dec!(1.2500), dec!(1.2450)), Ok(dec!(-50)));
+    assert_eq!(
+        calc.profit_loss(dec!(20000), dec!(1.2500), dec!(1.2450))
+            .unwrap()
+            .amount(),
+        dec!(-100)
+    );
+    assert_eq!(
+        calc.profit_loss(dec!(-20000), dec!(1.2500), dec!(1.2450))
+            .unwrap()
+            .amount(),
+        dec!(100)
+    );
+}
+
+#[test]
+fn test_out_of_range_inputs() {
+    let usd = CurrencyAlpha3::from_str("USD").unwrap();
+    let calc = calculator("EURUSD", "USD");
+    let risk = Money::new(dec!(1e20), usd.clone());
+    assert_eq!(
+        calc.position_size(&risk, dec!(1e-20)),
+        Err(FxError::AmountOutOfRange {
+            pair: "EUR/USD".into(),
+            amount: dec!(1e20)
+        })
+    );
+    assert!(matches!(
+        calc.profit_loss(dec!(1e22), dec!(1), dec!(1e8)),
+        Err(FxError::AmountOutOfRange { .. })
+    ));
+    assert!(calc.pips_between(dec!(0), dec!(1e26)).is_err());
+
+    let pair = CurrencyPair::from_str("EURUSD").unwrap();
+    assert_eq!(
+        PositionCalculator::with_conversion(pair, usd, dec!(0)),
+        Err(FxError::NonPositiveRate)
+    );
+}
@@ -0,0 +1,110 @@
+use ratefy_lib::money::{CurrencyAlpha3, Money, PriceRule, RoundingDirection};
+use ratefy_lib::repricing::{RepriceOptions, Rounding, reprice};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn eur(amount: Decimal) -> Money {
+    Money::new(amount, CurrencyAlpha3::from_str("EUR").unwrap())
+}
+
+fn priced(amount: Decimal, rule: &str) -> Decimal {
+    eur(amount)
+        .with_price_rule(&PriceRule::from_str(rule).unwrap())
+        .amount()
+}
+
+#[test]
+fn test_endings_in_each_direction() {
+    assert_eq!(priced(dec!(21.40), ".99"), dec!(20.99));
+    assert_eq!(priced(dec!(21.50), ".99"), dec!(21.99));
+    assert_eq!(priced(dec!(21.40), ".99 up"), dec!(21.99));
+    assert_eq!(priced(dec!(21.40), ".95 down"), dec!(20.95));
+    assert_eq!(priced(dec!(21.95), ".95 down"), dec!(21.95));
+    assert_eq!(priced(dec!(0.50), ".99 down"), dec!(-0.01));
+}
+
+#[test]
+fn test_multiples_and_cash() {
+    assert_eq!(priced(dec!(22.49), "5"), dec!(20));
+    assert_eq!(priced(dec!(22.50), "5"), dec!(25));
+    assert_eq!(priced(dec!(20.01), "5 up"), dec!(25));
+    assert_eq!(priced(dec!(1.04), "0.05 down"), dec!(1.00));
+    assert_eq!(priced(dec!(1.04), "cash"), dec!(1.00));
+    assert_eq!(priced(dec!(1.05), "cash"), dec!(1.10));
+    let chf = Money::new(dec!(1.024), CurrencyAlpha3::from_str("CHF").unwrap());
+    assert_eq!(chf.with_price_rule(&PriceRule::Cash).amount(), dec!(1.00));
+    assert_eq!(
+        PriceRule::cash_step(&CurrencyAlpha3::from_str("CHF").unwrap()),
+        dec!(0.05)
+    );
+    let jpy = CurrencyAlpha3::from_str("JPY").unwrap();
+    assert_eq!(PriceRule::cash_step(&jpy), dec!(1));
+    let yen = Money::new(dec!(1234.56), jpy);
+    assert_eq!(yen.with_price_rule(&PriceRule::Cash).amount(), dec!(1235));
+}
+
+#[test]
+fn test_rules_keep_the_applied_rate() {
+    let rule = PriceRule::Ending {
+        ending: dec!(0.95),
+        direction: RoundingDirection::Up,
+    };
+    let price = eur(dec!(80)).apply_rate(dec!(20)).with_price_rule(&rule);
+    assert_eq!(price.amount(), dec!(96.95));
+    assert_eq!(price.rate(), Some(dec!(20)));
+}
+
+#[test]
+fn test_rules_out_of_range() {
+    let huge = eur(Decimal::MAX);
+    let cash = PriceRule::from_str("0.05").unwrap();
+    assert_eq!(cash.checked_apply(&huge), None);
+    assert_eq!(
+        cash.checked_apply(&eur(dec!(1.04))).map(|m| m.amount()),
+        Some(dec!(1.05))
+    );
+
+    let options = RepriceOptions {
+        rounding: Rounding::Price(cash),
+        ..RepriceOptions::new(dec!(0))
+    };
+    let input = format!("amount,currency\n{},EUR\n", Decimal::MAX);
+    let summary = reprice(input.as_bytes(), std::io::sink(), &options).unwrap();
+    assert_eq!(summary.failed, 1);
+}
+
+#[test]
+fn test_invalid_rules() {
+    for text in [
+        "",
+        ".",
+        ".9x",
+        "0",
+        "abc",
+        ".99 sideways",
+        ".99 up down",
+        "cash down",
+    ] {
+        assert!(PriceRule::from_str(text).is_err(), "{:?} parsed", text);
+    }
+}
+
+#[test]
+fn test_bulk_repricing_with_price_rule() {
+    let options = RepriceOptions {
+        rounding: Rounding::Price(PriceRule::from_str(".99 up").unwrap()),
+        ..RepriceOptions::new(dec!(10))
+    };
+    let mut output = Vec::new();
+    reprice(
+        "sku,amount,currency\nA,18.00,EUR\n".as_bytes(),
+        &mut output,
+        &options,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap().lines().nth(1),
+        Some("A,18.00,EUR,18.00,19.99,1.99,")
+    );
+}
@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{CurrencyPair, ExchangeRate, FxError, Quote, conversion_cost};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()
+}
+
+fn money(amount: rust_decimal::Decimal, code: &str) -> Money {
+    Money::new(amount, CurrencyAlpha3::from_str(code).unwrap())
+}
+
+#[test]
+fn test_quote_validation_and_inverse() {
+    let pair = CurrencyPair::from_str("GBPUSD").unwrap();
+    assert_eq!(
+        Quote::new(pair.clone(), dec!(1.30), dec!(1.20), date()),
+        Err(FxError::CrossedQuote {
+            bid: dec!(1.30),
+            ask: dec!(1.20)
+        })
+    );
+    let quote = Quote::new(pair, dec!(1.25), dec!(1.28), date()).unwrap();
+    let inverse = quote.inverse();
+    assert_eq!(inverse.pair().to_string(), "USD/GBP");
+    assert_eq!(inverse.bid(), dec!(1) / dec!(1.28));
+    assert_eq!(inverse.ask(), dec!(0.8));
+    assert_eq!(quote.spread_pips(), dec!(300));
+}
+
+#[test]
+fn test_quote_convert_sides() {
+    let pair = CurrencyPair::from_str("EURCHF").unwrap();
+    let quote = Quote::new(pair, dec!(0.95), dec!(0.96), date()).unwrap();
+    let chf = quote.convert(&money(dec!(100), "EUR")).unwrap();
+    assert_eq!(chf.amount(), dec!(95.00));
+    let eur = quote.convert(&money(dec!(96), "CHF")).unwrap();
+    assert_eq!(eur.amount(), dec!(100));
+    assert_eq!(
+        quote.rate_for(&money(dec!(1), "CHF")).unwrap().rate(),
+        dec!(0.96)
+    );
+    assert!(quote.convert(&money(dec!(1), "USD")).is_err());
+}
+
+#[test]
+fn test_conversion_cost_with_fee_in_target_currency() {
+    let pair = CurrencyPair::from_str("EUR/USD").unwrap();
+    let quote = Quote::new(pair, dec!(1.07), dec!(1.09), date()).unwrap();
+    let amount = money(dec!(1090), "USD");
+    let provider = quote.rate_for(&amount).unwrap();
+    let fee = money(dec!(5), "EUR");
+
+    let cost = conversion_cost(&amount, &provider, &fee, &quote.mid_rate()).unwrap();
+    assert_eq!(cost.received.amount(), dec!(995));
+    assert_eq!(cost.received.currency().code(), "EUR");
+    assert_eq!(cost.at_mid.amount().round_dp(4), dec!(1009.2593));
+    assert_eq!(cost.fees.amount(), dec!(5));
+    assert_eq!(cost.total.amount(), cost.rate_markup.amount() + dec!(5));
+    assert_eq!(cost.effective_rate.round_dp(6), dec!(0.912844));
+}
+
+#[test]
+fn test_conversion_cost_parts_add_up_to_total() {
+    let pair = CurrencyPair::from_str("EUR/USD").unwrap();
+    let mid = ExchangeRate::new(pair.clone(), dec!(1.08), date()).unwrap();
+    let provider = ExchangeRate::new(pair, dec!(1.05), date()).unwrap();
+    let amount = money(dec!(1000), "EUR");
+    for fee in [money(dec!(10), "EUR"), money(dec!(10), "USD")] {
+        let cost = conversion_cost(&amount, &provider, &fee, &mid).unwrap();
+        assert_eq!(
+            cost.rate_markup.amount() + cost.fees.amount(),
+            cost.total.amount(),
+            "fee in {}",
+            fee.currency()
+        );
+    }
+}
+
+#[test]
+fn test_conversion_cost_rejects_foreign_fee() {
+    let pair = CurrencyPair::from_str("EUR/USD").unwrap();
+    let rate = ExchangeRate::new(pair, dec!(1.1), date()).unwrap();
+    let result = conversion_cost(
+        &money(dec!(100), "EUR"),
+        &rate,
+        &money(dec!(1), "GBP"),
+        &rate,
+    );
+    assert!(matches!(result, Err(FxError::CurrencyMismatch { .. })));
+    let negative = conversion_cost(
+        &money(dec!(100), "EUR"),
+        &rate,
+        &money(dec!(-1), "EUR"),
+        &rate,
+    );
+    assert_eq!(negative, Err(FxError::NegativeFee(dec!(-1))));
+}
+
+#[test]
+fn test_mid_of_extreme_quote() {
+    let pair = CurrencyPair::from_str("EUR/USD").unwrap();
+    let top = Quote::new(pair.clone(), Decimal::MAX, Decimal::MAX, date()).unwrap();
+    assert_eq!(top.mid_rate().rate(), Decimal::MAX);
+    let wide = Quote::new(pair, dec!(1), Decimal::MAX, date()).unwrap();
+    assert!(wide.mid() < Decimal::MAX);
+    assert_eq!(wide.mid_rate().rate(), wide.mid());
+}
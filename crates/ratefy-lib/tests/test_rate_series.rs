@@ -0,0 +1,98 @@
+use chrono::NaiveDate;
+use ratefy_lib::rates::{RateSeries, RateSeriesError};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+}
+
+fn series(rates: &[Decimal]) -> RateSeries {
+    let mut series = RateSeries::new();
+    for (index, rate) in rates.iter().enumerate() {
+        series.insert(date(index as u32 + 1), *rate).unwrap();
+    }
+    series
+}
+
+#[test]
+fn test_returns_and_log_returns() {
+    let s = series(&[dec!(100), dec!(110), dec!(99)]);
+    let returns: Vec<Decimal> = s.returns().into_iter().map(|(_, r)| r).collect();
+    assert_eq!(returns, vec![dec!(10), dec!(-10)]);
+    let log_returns = s.log_returns();
+    assert_eq!(log_returns[0].0, date(2));
+    assert_eq!(log_returns[0].1.round_dp(4), dec!(9.5310));
+}
+
+#[test]
+fn test_volatility() {
+    let s = series(&[dec!(100), dec!(110), dec!(99), dec!(108.9)]);
+    let vol = s.volatility().unwrap();
+    assert!(vol > dec!(10) && vol < dec!(13));
+    let annual = s.annualized_volatility(252).unwrap();
+    assert_eq!(
+        annual.round_dp(6),
+        (vol * dec!(252).sqrt().unwrap()).round_dp(6)
+    );
+    assert_eq!(s.rolling_volatility(2).len(), 2);
+    assert!(series(&[dec!(1), dec!(2)]).volatility().is_none());
+}
+
+#[test]
+fn test_moving_averages() {
+    let s = series(&[dec!(1), dec!(2), dec!(3), dec!(4)]);
+    let sma: Vec<Decimal> = s.sma(3).into_iter().map(|(_, v)| v).collect();
+    assert_eq!(sma, vec![dec!(2), dec!(3)]);
+    let ema = s.ema(3);
+    assert_eq!(ema[0], (date(3), dec!(2)));
+    assert_eq!(ema[1], (date(4), dec!(3)));
+    assert!(s.sma(5).is_empty());
+}
+
+#[test]
+fn test_drawdown_and_window_stats() {
+    let s = series(&[dec!(10), dec!(12), dec!(9), dec!(11), dec!(6), dec!(13)]);
+    let drawdown = s.max_drawdown().unwrap();
+    assert_eq!(drawdown.peak_date, date(2));
+    assert_eq!(drawdown.trough_date, date(5));
+    assert_eq!(drawdown.percent, dec!(50));
+
+    let stats = s.window_stats(date(2), date(4)).unwrap();
+    assert_eq!(stats.min, dec!(9));
+    assert_eq!(stats.max, dec!(12));
+    assert_eq!(stats.mean, dec!(32) / dec!(3));
+    assert!(s.window_stats(date(20), date(25)).is_none());
+}
+
+#[test]
+fn test_correlation() {
+    let a = series(&[dec!(1.0), dec!(1.1), dec!(1.05), dec!(1.2)]);
+    let b = series(&[dec!(2.0), dec!(2.2), dec!(2.1), dec!(2.4)]);
+    let inverse = series(&[
+        dec!(1.0),
+        dec!(1.0) / dec!(1.1),
+        dec!(1.0) / dec!(1.05),
+        dec!(1.0) / dec!(1.2),
+    ]);
+    assert_eq!(a.correlation(&b).unwrap().round_dp(6), dec!(1));
+    assert_eq!(a.correlation(&inverse).unwrap().round_dp(6), dec!(-1));
+}
+
+#[test]
+fn test_correlation_needs_three_common_returns() {
+    let a = series(&[dec!(1.0), dec!(1.1), dec!(1.05), dec!(1.2)]);
+    let b = series(&[dec!(2.0), dec!(2.1), dec!(2.3)]);
+    assert_eq!(a.correlation(&b), None);
+    let c = series(&[dec!(2.0), dec!(2.1), dec!(2.3), dec!(2.2)]);
+    assert!(a.correlation(&c).is_some());
+}
+
+#[test]
+fn test_rejects_non_positive_rate() {
+    let mut s = RateSeries::new();
+    assert_eq!(
+        s.insert(date(1), dec!(0)),
+        Err(RateSeriesError::NonPositiveRate(date(1)))
+    );
+}
@@ -0,0 +1,117 @@
+use chrono::NaiveDate;
+use ratefy_lib::fx::{CurrencyPair, ExchangeRate, FxError, RateStore};
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+const RATES: &str = "date,pair,rate
+2024-01-02,EUR/USD,1.10
+2024-01-02,GBP/USD,1.25
+2024-02-01,EURUSD,1.08
+2024-02-01,USD/CHF,0.86
+2024-02-01,CHF/SEK,12.00
+";
+
+fn c(code: &str) -> CurrencyAlpha3 {
+    CurrencyAlpha3::from_str(code).unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(y, m, d)
+}
+
+#[test]
+fn test_direct_and_inverse_by_date() {
+    let store = RateStore::from_reader(RATES.as_bytes()).unwrap();
+    assert_eq!(store.len(), 5);
+    assert_eq!(
+        store
+            .rate(&c("EUR"), &c("USD"), date(2024, 1, 15))
+            .unwrap()
+            .rate(),
+        dec!(1.10)
+    );
+    assert_eq!(
+        store.rate(&c("EUR"), &c("USD"), None).unwrap().rate(),
+        dec!(1.08)
+    );
+    let inverse = store.rate(&c("USD"), &c("EUR"), date(2024, 3, 1)).unwrap();
+    assert_eq!(inverse.pair().to_string(), "USD/EUR");
+    assert_eq!(inverse.date(), date(2024, 2, 1).unwrap());
+    assert_eq!(
+        store.rate(&c("EUR"), &c("USD"), date(2023, 12, 31)),
+        Err(FxError::MissingRate {
+            pair: "EUR/USD".into(),
+            date: date(2023, 12, 31)
+        })
+    );
+    let history = store.history(&CurrencyPair::from_str("USD/EUR").unwrap());
+    assert_eq!(history.len(), 2);
+}
+
+#[test]
+fn test_cross_rates_prefer_usd() {
+    let store = RateStore::from_reader(RATES.as_bytes()).unwrap();
+    let cross = store.rate(&c("EUR"), &c("GBP"), date(2024, 1, 2)).unwrap();
+    assert_eq!(cross.rate(), dec!(1.10) / dec!(1.25));
+
+    let later = store.rate(&c("GBP"), &c("CHF"), date(2024, 2, 15)).unwrap();
+    assert_eq!(later.rate(), dec!(1.25) * dec!(0.86));
+    assert_eq!(later.date(), date(2024, 1, 2).unwrap());
+
+    // USD/SEK needs two hops, which is not attempted.
+    assert!(store.rate(&c("USD"), &c("SEK"), None).is_ok());
+    assert!(store.rate(&c("EUR"), &c("SEK"), None).is_err());
+}
+
+#[test]
+fn test_convert_and_insert() {
+    let mut store = RateStore::new();
+    assert!(store.is_empty());
+    let pair = CurrencyPair::from_str("JPY/USD").unwrap();
+    store.insert(ExchangeRate::new(pair, dec!(0.008), date(2024, 1, 2).unwrap()).unwrap());
+    assert_eq!(store.pairs().next().unwrap().to_string(), "USD/JPY");
+
+    let usd = Money::new(dec!(10), c("USD"));
+    assert_eq!(
+        store.convert(&usd, &c("JPY"), None).unwrap().amount(),
+        dec!(1250)
+    );
+    assert_eq!(store.convert(&usd, &c("USD"), None).unwrap(), usd);
+}
+
+#[test]
+fn test_parse_errors() {
+    let bad = "date,pair,rate\n2024-01-02,EUR/USD,1.1\n2024-01-02,EUR/XYZ,1\n";
+    assert!(matches!(
+        RateStore::from_reader(bad.as_bytes()),
+        Err(FxError::Parse { line: 3, .. })
+    ));
+    assert!(matches!(
+        RateStore::from_path("missing.csv"),
+        Err(FxError::Io(_))
+    ));
+}
+
+#[test]
+fn test_convert_out_of_range() {
+    let store = RateStore::from_reader(RATES.as_bytes()).unwrap();
+    let eur = Money::new(Decimal::MAX, c("EUR"));
+    assert_eq!(
+        store.convert(&eur, &c("USD"), None),
+        Err(FxError::AmountOutOfRange {
+            pair: "EUR/USD".into(),
+            amount: Decimal::MAX
+        })
+    );
+}
+
+#[test]
+fn test_parse_error_lines_skip_blank_lines() {
+    let data = "date,pair,rate\r\n\r\n2024-01-02,EUR/USD,1.10\r\n\r\n\r\n2024-01-03,EUR/USD,-1\r\n";
+    assert!(matches!(
+        RateStore::from_reader(data.as_bytes()),
+        Err(FxError::Parse { line: 6, .. })
+    ));
+}
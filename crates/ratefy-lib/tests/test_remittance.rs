@@ -0,0 +1,88 @@
+use ratefy_lib::money::{CurrencyAlpha3, Money};
+use ratefy_lib::remittance::{RemittanceError, compare, import};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+const OFFERS: &str = "provider,pair,rate,fixed_fee,percent_fee,fee_currency,date
+Alpha,USD/MXN,17.00,5,0,USD,2024-05-01
+Beta,USD/MXN,16.80,0,0,USD,2024-05-01
+Gamma,MXN/USD,0.0580,20,1,MXN,2024-05-01
+Delta,EUR/USD,1.08,1,0,EUR,2024-05-01
+";
+
+fn currency(code: &str) -> CurrencyAlpha3 {
+    CurrencyAlpha3::from_str(code).unwrap()
+}
+
+#[test]
+fn test_ranking_by_received_amount() {
+    let offers = import::from_reader(OFFERS.as_bytes()).unwrap();
+    let amount = Money::new(dec!(500), currency("USD"));
+    let ranking = compare(&amount, &currency("MXN"), &offers).unwrap();
+
+    let providers: Vec<&str> = ranking.iter().map(|r| r.provider.as_str()).collect();
+    assert_eq!(providers, ["Gamma", "Alpha", "Beta"]);
+    // 500 USD - 1% = 495 USD at 1 / 0.0580, minus 20 MXN.
+    assert_eq!(ranking[0].rate.round_dp(4), dec!(17.2414));
+    assert_eq!(ranking[0].received.rounded().amount(), dec!(8514.48));
+    assert_eq!(ranking[1].received.amount(), dec!(8415.00));
+    assert_eq!(ranking[1].effective_rate, dec!(16.83));
+    assert_eq!(ranking[2].received.amount(), dec!(8400.00));
+    assert_eq!(ranking[2].shortfall.rounded().amount(), dec!(114.48));
+}
+
+#[test]
+fn test_fees_larger_than_amount() {
+    let offers = import::from_reader(OFFERS.as_bytes()).unwrap();
+    let amount = Money::new(dec!(3), currency("USD"));
+    let ranking = compare(&amount, &currency("MXN"), &offers).unwrap();
+    let alpha = ranking.iter().find(|r| r.provider == "Alpha").unwrap();
+    assert_eq!(alpha.received.amount(), dec!(0));
+}
+
+#[test]
+fn test_no_offer_for_corridor() {
+    let offers = import::from_reader(OFFERS.as_bytes()).unwrap();
+    let amount = Money::new(dec!(100), currency("GBP"));
+    assert_eq!(
+        compare(&amount, &currency("USD"), &offers),
+        Err(RemittanceError::NoOffers {
+            from: "GBP".into(),
+            to: "USD".into()
+        })
+    );
+}
+
+#[test]
+fn test_import_errors() {
+    let bad_fee = "provider,pair,rate,fixed_fee,percent_fee,fee_currency,date
+Ok,EUR/USD,1.1,1,0,EUR,2024-05-01
+Bad,EUR/USD,1.1,1,0,GBP,2024-05-01
+";
+    assert!(matches!(
+        import::from_reader(bad_fee.as_bytes()),
+        Err(RemittanceError::Parse { line: 3, .. })
+    ));
+    let bad_pct = "provider,pair,rate,fixed_fee,percent_fee,fee_currency,date
+Bad,EUR/USD,1.1,1,100,EUR,2024-05-01
+";
+    assert!(matches!(
+        import::from_reader(bad_pct.as_bytes()),
+        Err(RemittanceError::Parse { line: 2, .. })
+    ));
+    let negative_fee = "provider,pair,rate,fixed_fee,percent_fee,fee_currency,date
+Ok,EUR/USD,1.1,1,0,EUR,2024-05-01
+Bad,EUR/USD,1.1,-5,0,USD,2024-05-01
+";
+    assert_eq!(
+        import::from_reader(negative_fee.as_bytes()),
+        Err(RemittanceError::Parse {
+            line: 3,
+            message: "fixed fee must not be negative, got -5".to_string()
+        })
+    );
+    assert!(matches!(
+        import::from_path("does/not/exist.csv"),
+        Err(RemittanceError::Io(_))
+    ));
+}
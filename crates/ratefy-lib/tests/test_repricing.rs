@@ -0,0 +1,165 @@
+use ratefy_lib::money::CurrencyAlpha3;
+use ratefy_lib::repricing::{
+    MAX_REPORTED_ERRORS, RepriceError, RepriceOptions, Rounding, RowError, reprice,
+};
+use rust_decimal_macros::dec;
+use std::io::Read;
+use std::str::FromStr;
+
+fn run(input: &str, options: &RepriceOptions) -> (String, ratefy_lib::repricing::RepriceSummary) {
+    let mut output = Vec::new();
+    let summary = reprice(input.as_bytes(), &mut output, options).unwrap();
+    (String::from_utf8(output).unwrap(), summary)
+}
+
+#[test]
+fn test_reprice_keeps_columns_and_adds_delta() {
+    let input = "sku,name,amount,currency\nA-1,Mug,10.00,EUR\nA-2,\"Cup, large\",1000,JPY\n";
+    let (output, summary) = run(input, &RepriceOptions::new(dec!(-12.5)));
+    assert_eq!(
+        output,
+        "sku,name,amount,currency,old_amount,new_amount,delta,error\n\
+         A-1,Mug,10.00,EUR,10.00,8.75,-1.25,\n\
+         A-2,\"Cup, large\",1000,JPY,1000,875,-125,\n"
+    );
+    assert_eq!((summary.rows, summary.repriced, summary.failed), (2, 2, 0));
+    assert!(summary.errors.is_empty());
+}
+
+#[test]
+fn test_row_errors_do_not_stop_the_run() {
+    let options = RepriceOptions {
+        rate_column: Some("rate".to_string()),
+        ..RepriceOptions::new(dec!(10))
+    };
+    let input = "amount,currency,rate\n1.00,XXZ,\nabc,EUR,\n1.00,EUR,ten\n1.00,EUR,\n";
+    let (output, summary) = run(input, &options);
+    assert_eq!(output.lines().last(), Some("1.00,EUR,,1.00,1.10,0.10,"));
+    assert_eq!((summary.repriced, summary.failed), (1, 3));
+    assert_eq!(
+        summary.errors,
+        vec![
+            RowError {
+                line: 2,
+                message: "unknown currency 'XXZ'".to_string()
+            },
+            RowError {
+                line: 3,
+                message: "invalid amount 'abc'".to_string()
+            },
+            RowError {
+                line: 4,
+                message: "invalid rate 'ten'".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_overflow_and_invalid_utf8_are_row_errors() {
+    let mut input = b"sku,amount,currency\nBIG,79228162514264337593543950335,EUR\n".to_vec();
+    input.extend_from_slice(b"caf\xe9,1.00,EUR\nOK,1.00,EUR\n");
+    let mut output = Vec::new();
+    let summary = reprice(
+        input.as_slice(),
+        &mut output,
+        &RepriceOptions::new(dec!(10)),
+    )
+    .unwrap();
+    assert_eq!((summary.rows, summary.repriced, summary.failed), (3, 1, 2));
+    assert_eq!(
+        summary.errors,
+        vec![
+            RowError {
+                line: 2,
+                message: "79228162514264337593543950335 at 10% is out of range".to_string()
+            },
+            RowError {
+                line: 3,
+                message: "invalid UTF-8 in field 1".to_string()
+            },
+        ]
+    );
+    // The undecodable row is copied byte for byte.
+    let copied = b"caf\xe9,1.00,EUR,,,,invalid UTF-8 in field 1\n";
+    assert!(output.windows(copied.len()).any(|w| w == copied));
+    assert!(output.ends_with(b"OK,1.00,EUR,1.00,1.10,0.10,\n"));
+}
+
+#[test]
+fn test_line_numbers_follow_multiline_fields() {
+    let input = "sku,name,amount,currency\nA,\"two\nlines\",1.00,EUR\nB,plain,bad,EUR\n";
+    let (_, summary) = run(input, &RepriceOptions::new(dec!(10)));
+    assert_eq!(summary.errors[0].line, 4);
+}
+
+#[test]
+fn test_unrounded_amounts() {
+    let options = RepriceOptions {
+        rounding: Rounding::None,
+        default_currency: Some(CurrencyAlpha3::from_str("USD").unwrap()),
+        ..RepriceOptions::new(dec!(3.3))
+    };
+    let (output, _) = run("amount\n9.99\n", &options);
+    assert_eq!(output.lines().nth(1), Some("9.99,9.99,10.31967,0.32967,"));
+}
+
+#[test]
+fn test_invalid_options() {
+    let config = |input: &str, options: &RepriceOptions| {
+        reprice(input.as_bytes(), Vec::new(), options).unwrap_err()
+    };
+    assert!(matches!(
+        config("price,currency\n", &RepriceOptions::new(dec!(1))),
+        RepriceError::Config(_)
+    ));
+    assert!(matches!(
+        config("amount\n", &RepriceOptions::new(dec!(1))),
+        RepriceError::Config(_)
+    ));
+    assert!(matches!(
+        config("amount,currency\n", &RepriceOptions::default()),
+        RepriceError::Config(_)
+    ));
+}
+
+/// Generates a price list row by row, without holding it in memory.
+struct PriceList {
+    rows: usize,
+    next: usize,
+    pending: Vec<u8>,
+}
+
+impl Read for PriceList {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pending.is_empty() && self.next <= self.rows {
+            self.pending = if self.next == 0 {
+                b"sku,amount,currency\n".to_vec()
+            } else if self.next.is_multiple_of(1000) {
+                format!("S{},?,EUR\n", self.next).into_bytes()
+            } else {
+                format!("S{},{}.50,EUR\n", self.next, self.next % 100).into_bytes()
+            };
+            self.next += 1;
+        }
+        let n = buf.len().min(self.pending.len());
+        buf[..n].copy_from_slice(&self.pending[..n]);
+        self.pending.drain(..n);
+        Ok(n)
+    }
+}
+
+#[test]
+fn test_large_list_is_streamed() {
+    let input = PriceList {
+        rows: 200_000,
+        next: 0,
+        pending: Vec::new(),
+    };
+    let summary = reprice(input, std::io::sink(), &RepriceOptions::new(dec!(2))).unwrap();
+    assert_eq!(summary.rows, 200_000);
+    assert_eq!(summary.failed, 200);
+    assert_eq!(summary.repriced, 199_800);
+    assert_eq!(summary.errors.len(), MAX_REPORTED_ERRORS);
+    assert_eq!(summary.errors[0].line, 1001);
+}
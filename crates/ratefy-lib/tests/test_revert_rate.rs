@@ -22,3 +22,17 @@ fn test_revert_zero_rate() {
         "original() should return None if rate is zero"
     );
 }
+
+#[test]
+fn test_revert_recorded_rate() {
+    let gross = Money::new(dec!(230.00), CurrencyAlpha3::from_str("EUR").unwrap());
+    let net = gross.with_rate(dec!(15)).revert_rate().unwrap();
+    assert_eq!(net.amount(), dec!(200.00));
+    assert_eq!(net.rate(), None);
+}
+
+#[test]
+fn test_revert_full_discount() {
+    let money = Money::new(dec!(100.00), CurrencyAlpha3::from_str("USD").unwrap());
+    assert!(money.apply_rate(dec!(-100)).revert_rate().is_none());
+}
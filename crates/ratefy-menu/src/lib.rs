@@ -75,31 +75,31 @@ where
             f.render_stateful_widget(list, layout[0], &mut state);
         })?;
 
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Up => {
-                        selected = if selected == 0 {
-                            items.len() - 1
-                        } else {
-                            selected - 1
-                        };
-                    }
-                    KeyCode::Down => {
-                        selected = (selected + 1) % items.len();
-                    }
-                    KeyCode::Enter => return Ok(items[selected].next),
-                    KeyCode::Char(c) if c.is_ascii_digit() => {
-                        if let Some(index) = items
-                            .iter()
-                            .position(|i| i.key == c.to_digit(10).unwrap() as u8)
-                        {
-                            return Ok(items[index].next);
-                        }
+        if event::poll(Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Up => {
+                    selected = if selected == 0 {
+                        items.len() - 1
+                    } else {
+                        selected - 1
+                    };
+                }
+                KeyCode::Down => {
+                    selected = (selected + 1) % items.len();
+                }
+                KeyCode::Enter => return Ok(items[selected].next),
+                KeyCode::Char(c) if c.is_ascii_digit() => {
+                    if let Some(index) = items
+                        .iter()
+                        .position(|i| i.key == c.to_digit(10).unwrap() as u8)
+                    {
+                        return Ok(items[index].next);
                     }
-                    KeyCode::Esc => return Err("User aborted with Esc".into()),
-                    _ => {}
                 }
+                KeyCode::Esc => return Err("User aborted with Esc".into()),
+                _ => {}
             }
         }
     }